@@ -0,0 +1,52 @@
+//! 清空元素不需要`drop`的`MyVec<u64>`/`InplaceVec<N, u64>`的性能，用于检验
+//! `mem::needs_drop`快速路径的效果。
+//!
+//! 这些路径在release中通常会被优化掉，差别主要体现在debug构建中，因此
+//! 使用`cargo bench --bench clear_bench --profile dev`运行。
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::collection::vec::MyVec;
+
+const N: usize = 1_000_000;
+
+fn bench_clear(c: &mut Criterion) {
+    let data: MyVec<u64> = (0..N as u64).collect();
+
+    let mut group = c.benchmark_group("clear_u64");
+    group.bench_function("clear", |b| {
+        b.iter_batched_ref(|| data.clone(), |vec| vec.clear(), BatchSize::LargeInput)
+    });
+    group.bench_function("truncate", |b| {
+        b.iter_batched_ref(
+            || data.clone(),
+            |vec| vec.truncate(1),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("drain_drop", |b| {
+        b.iter_batched_ref(
+            || data.clone(),
+            |vec| drop(vec.drain(1..N - 1)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("into_iter_drop", |b| {
+        b.iter_batched(|| data.clone().into_iter(), drop, BatchSize::LargeInput)
+    });
+    group.finish();
+
+    let inplace: Box<InplaceVec<4096, u64>> = Box::new((0..4096).collect());
+    let mut group = c.benchmark_group("clear_inplace_u64");
+    group.bench_function("drain_drop", |b| {
+        b.iter_batched_ref(
+            || inplace.clone(),
+            |vec| drop(vec.drain(1..4095)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_clear);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+//! 比较[`MyVec::top_k`]与先对整个数组排序再截断的性能，`k`远小于`n`。
+//!
+//! 运行`cargo bench --bench top_k_bench`。
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rust_practice::collection::rng::{RngCore, XorShift64};
+use rust_practice::collection::vec::MyVec;
+
+const N: usize = 100_000;
+const KS: [usize; 3] = [10, 100, 1000];
+
+fn bench_top_k(c: &mut Criterion) {
+    let mut rng = XorShift64::seed_from_u64(42);
+    let data: MyVec<u64> = (0..N).map(|_| rng.next_u64()).collect();
+
+    let mut group = c.benchmark_group("top_k");
+    for k in KS {
+        group.bench_with_input(BenchmarkId::new("top_k", k), &k, |b, &k| {
+            b.iter(|| data.clone().into_top_k(k))
+        });
+        group.bench_with_input(BenchmarkId::new("sort_then_truncate", k), &k, |b, &k| {
+            b.iter(|| {
+                let mut vec = data.clone();
+                vec.sort_unstable_impl_by(|a, b| b.cmp(a));
+                vec.truncate(k);
+                vec
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_top_k);
+criterion_main!(benches);
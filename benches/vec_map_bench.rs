@@ -0,0 +1,90 @@
+//! 比较[`VecMap`]与[`HashMap`]在较小规模（不超过32个键值对）下的性能。
+//!
+//! 运行`cargo bench --bench vec_map_bench`。
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rust_practice::collection::vec_map::VecMap;
+
+const SIZES: [u64; 4] = [4, 8, 16, 32];
+
+/// 打乱键的插入顺序，避免总是在末尾插入。
+fn keys(n: u64) -> Vec<u64> {
+    (0..n).map(|i| (i * 7919) % n * 2).collect()
+}
+
+fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert");
+    for n in SIZES {
+        let keys = keys(n);
+        group.bench_with_input(BenchmarkId::new("VecMap", n), &keys, |b, keys| {
+            b.iter(|| {
+                let mut map = VecMap::new();
+                for &k in keys {
+                    map.insert(k, k);
+                }
+                map
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("HashMap", n), &keys, |b, keys| {
+            b.iter(|| {
+                let mut map = HashMap::new();
+                for &k in keys {
+                    map.insert(k, k);
+                }
+                map
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get");
+    for n in SIZES {
+        let keys = keys(n);
+        let vec_map: VecMap<_, _> = keys.iter().map(|&k| (k, k)).collect();
+        let hash_map: HashMap<_, _> = keys.iter().map(|&k| (k, k)).collect();
+        // 一半命中，一半不命中（键都是偶数）
+        let queries: Vec<_> = (0..2 * n).collect();
+
+        group.bench_with_input(BenchmarkId::new("VecMap", n), &queries, |b, queries| {
+            b.iter(|| {
+                queries
+                    .iter()
+                    .filter_map(|q| vec_map.get(black_box(q)))
+                    .sum::<u64>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("HashMap", n), &queries, |b, queries| {
+            b.iter(|| {
+                queries
+                    .iter()
+                    .filter_map(|q| hash_map.get(black_box(q)))
+                    .sum::<u64>()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iter");
+    for n in SIZES {
+        let vec_map: VecMap<_, _> = keys(n).into_iter().map(|k| (k, k)).collect();
+        let hash_map: HashMap<_, _> = keys(n).into_iter().map(|k| (k, k)).collect();
+
+        group.bench_function(BenchmarkId::new("VecMap", n), |b| {
+            b.iter(|| black_box(&vec_map).values().sum::<u64>())
+        });
+        group.bench_function(BenchmarkId::new("HashMap", n), |b| {
+            b.iter(|| black_box(&hash_map).values().sum::<u64>())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_insert, bench_get, bench_iter);
+criterion_main!(benches);
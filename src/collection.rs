@@ -1,3 +1,29 @@
+pub mod arc;
+pub mod bit_vec;
+pub mod boxed;
+pub mod boxed_slice;
+pub mod c_string;
+pub mod circular_buffer;
+pub mod concurrent_vec;
+pub mod fenwick;
+pub mod gap_buffer;
+pub mod grid;
+pub mod inplace_deque;
+pub mod inplace_heap;
+pub mod inplace_string;
 pub mod inplace_vec;
+pub mod interner;
+pub mod raw;
+pub mod rc;
+pub mod rng;
+pub mod segmented_vec;
+pub mod shared_vec;
+pub mod slab;
 pub mod slice;
+pub mod sorted_vec;
+pub mod string;
+pub mod tiny_map;
+pub mod union_find;
 pub mod vec;
+pub mod vec_deque;
+pub mod vec_map;
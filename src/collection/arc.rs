@@ -0,0 +1,256 @@
+use std::cmp::Ordering as CmpOrdering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::process;
+use std::ptr::NonNull;
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+
+use crate::collection::boxed::MyBox;
+
+/// 引用计数的上限，与标准库相同。
+const MAX_REFCOUNT: usize = isize::MAX as usize;
+
+struct ArcInner<T> {
+    strong: AtomicUsize,
+    value: T,
+}
+
+/// 源自The Rustonomicon
+///
+/// 线程安全的引用计数指针，与[`std::sync::Arc`]类似。内存的分配方式与
+/// [`MyRc`]相同，区别在于引用计数是原子的。
+///
+/// ## 关于内存顺序
+///
+/// - `clone`只需要`Relaxed`：新的引用是从已有的引用得到的，不需要与其
+///   他线程同步任何数据，只要计数本身是原子的即可。
+/// - `drop`使用`Release`减少计数，保证当前线程对值的所有访问都发生在
+///   计数减少之前。计数减为0的线程在释放之前需要一个`Acquire`的
+///   [`fence`](atomic::fence)，与其他线程的`Release`同步，从而保证在
+///   析构时，其他线程对值的访问都已经完成。
+/// - `get_mut`和`try_unwrap`使用`Acquire`读取计数，理由与上面相同：确认
+///   自己是唯一的引用之后，我们要访问的值不能与其他线程之前的访问冲突。
+///
+/// ## Send和Sync
+///
+/// `MyArc<T>`可以在线程之间共享`&T`，且任何一个线程都可能成为最后一个
+/// 引用而`drop`掉`T`，因此只有在`T: Send + Sync`时，`MyArc<T>`才是
+/// `Send`和`Sync`的：
+///
+/// ```rust,compile_fail
+/// use std::cell::Cell;
+/// use rust_practice::collection::arc::MyArc;
+///
+/// fn assert_send<T: Send>(_: T) {}
+/// assert_send(MyArc::new(Cell::new(1)));
+/// ```
+///
+/// 目前还没有实现弱引用（`Weak`），因此`get_mut`和`try_unwrap`只需要检查
+/// 强引用的计数。
+///
+/// ```rust
+/// use std::thread;
+/// use rust_practice::collection::arc::MyArc;
+///
+/// let a = MyArc::new(vec![1, 2, 3]);
+/// let b = MyArc::clone(&a);
+/// let sum = thread::spawn(move || b.iter().sum::<i32>()).join().unwrap();
+/// assert_eq!(sum, 6);
+/// assert_eq!(MyArc::try_unwrap(a).unwrap(), [1, 2, 3]);
+/// ```
+///
+/// [`MyRc`]: crate::collection::rc::MyRc
+pub struct MyArc<T> {
+    ptr: NonNull<ArcInner<T>>,
+    _marker: PhantomData<ArcInner<T>>,
+}
+
+unsafe impl<T: Send + Sync> Send for MyArc<T> {}
+unsafe impl<T: Send + Sync> Sync for MyArc<T> {}
+
+impl<T> MyArc<T> {
+    pub fn new(value: T) -> Self {
+        let inner = MyBox::new(ArcInner {
+            strong: AtomicUsize::new(1),
+            value,
+        });
+        Self {
+            ptr: unsafe { NonNull::new_unchecked(MyBox::into_raw(inner)) },
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn inner(&self) -> &ArcInner<T> {
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// 在多线程的环境中，返回值随时可能过期，只能作为参考。
+    #[inline]
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().strong.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+
+    /// 只有在当前`MyArc`是唯一的引用时，才能获得可变引用。
+    ///
+    /// 由于我们持有`&mut Self`，其他线程无法通过当前的引用创建新的引用，
+    /// 因此计数为1时，之后也不会再增加。
+    #[inline]
+    pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+        if this.inner().strong.load(Ordering::Acquire) == 1 {
+            unsafe { Some(&mut this.ptr.as_mut().value) }
+        } else {
+            None
+        }
+    }
+
+    /// 写时复制：如果当前`MyArc`不是唯一的引用，则先克隆其中的值，使当前
+    /// `MyArc`指向一个新的分配，然后返回可变引用。
+    ///
+    /// 如果在检查之后其他线程`drop`了它们的引用，我们可能会进行一次不必要
+    /// 的克隆，但结果仍然是正确的。
+    pub fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        if this.inner().strong.load(Ordering::Acquire) != 1 {
+            *this = Self::new((**this).clone());
+        }
+        // SAFETY: 此时计数为1，且我们持有`&mut Self`，计数不会再增加
+        unsafe { &mut this.ptr.as_mut().value }
+    }
+
+    /// 如果当前`MyArc`是唯一的引用，则取出其中的值，否则原样返还。
+    ///
+    /// 我们使用`compare_exchange`将计数从1修改为0，与其他线程的`drop`
+    /// 形成`Release`/`Acquire`同步。
+    pub fn try_unwrap(this: Self) -> Result<T, Self> {
+        if this
+            .inner()
+            .strong
+            .compare_exchange(1, 0, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return Err(this);
+        }
+
+        let this = ManuallyDrop::new(this);
+        let inner = unsafe { MyBox::from_raw(this.ptr.as_ptr()) };
+        Ok(MyBox::into_inner(inner).value)
+    }
+}
+
+impl<T> Clone for MyArc<T> {
+    /// 源自The Rustonomicon
+    ///
+    /// 如果计数超过了[`isize::MAX`]，则直接终止程序。由于计数的增加和检查
+    /// 不是一个原子操作，在检查之前，其他线程可能继续增加计数，但至少需
+    /// 要`isize::MAX`个线程同时执行才会真正溢出，这在实际中是不可能的。
+    fn clone(&self) -> Self {
+        let old = self.inner().strong.fetch_add(1, Ordering::Relaxed);
+        if old >= MAX_REFCOUNT {
+            process::abort();
+        }
+
+        Self {
+            ptr: self.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for MyArc<T> {
+    /// 源自The Rustonomicon
+    fn drop(&mut self) {
+        if self.inner().strong.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+
+        // 与其他线程`drop`时的`Release`同步
+        atomic::fence(Ordering::Acquire);
+        drop(unsafe { MyBox::from_raw(self.ptr.as_ptr()) });
+    }
+}
+
+impl<T> Deref for MyArc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+impl<T: Default> Default for MyArc<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for MyArc<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> AsRef<T> for MyArc<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyArc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MyArc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+/// 按指向的值比较。
+impl<T: PartialEq> PartialEq for MyArc<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for MyArc<T> {}
+
+impl<T: PartialOrd> PartialOrd for MyArc<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for MyArc<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash> Hash for MyArc<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
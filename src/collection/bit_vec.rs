@@ -0,0 +1,362 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::{BitAndAssign, BitOrAssign, BitXorAssign, Index};
+
+use crate::collection::vec::MyVec;
+
+const BITS: usize = usize::BITS as usize;
+
+/// 可增长的位向量，将比特紧凑地存储在[`MyVec<usize>`]中。
+///
+/// 第`i`个比特位于第`i / BITS`个字的第`i % BITS`位（从低位算起）。
+///
+/// ## 不变量
+///
+/// - `words.len() == len.div_ceil(BITS)`
+/// - 最后一个字中未使用的高位始终为0
+///
+/// 第二条保证了[`BitVec::count_ones`]可以直接统计每个字，且两个长
+/// 度相同的`BitVec`可以直接逐字比较（因此`PartialEq`和`Hash`可以直接
+/// derive）。所有可能写入高位的操作（例如[`BitVec::grow`]和
+/// [`BitVec::negate`]）最后都会调用`clear_unused_bits`。
+///
+/// ```rust
+/// use rust_practice::collection::bit_vec::BitVec;
+///
+/// let mut a: BitVec = [true, false, true].into_iter().collect();
+/// a.grow(2, true);
+/// assert_eq!(format!("{a:?}"), "10111");
+/// let b: BitVec = [false, true, true, false, true].into_iter().collect();
+/// a &= &b;
+/// assert_eq!(format!("{a:?}"), "00101");
+/// assert_eq!(a.count_ones(), 2);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: MyVec<usize>,
+    len: usize,
+}
+
+#[inline]
+const fn split(index: usize) -> (usize, usize) {
+    (index / BITS, index % BITS)
+}
+
+impl BitVec {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            words: MyVec::new(),
+            len: 0,
+        }
+    }
+
+    /// 预留至少能容纳`bits`个比特的空间。
+    #[inline]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: MyVec::with_capacity(bits.div_ceil(BITS)),
+            len: 0,
+        }
+    }
+
+    /// 长度为`len`，每个比特都为`value`。
+    #[inline]
+    pub fn from_elem(len: usize, value: bool) -> Self {
+        let mut ret = Self::with_capacity(len);
+        ret.grow(len, value);
+        ret
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 以比特为单位的容量。
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.words.capacity().saturating_mul(BITS)
+    }
+
+    /// 底层的字，最后一个字中未使用的高位为0。
+    #[inline]
+    pub fn as_words(&self) -> &[usize] {
+        &self.words
+    }
+
+    /// 将最后一个字中未使用的高位清零，以恢复不变量。
+    #[inline]
+    fn clear_unused_bits(&mut self) {
+        let (_, bit) = split(self.len);
+        if bit != 0 {
+            // 不变量保证此时最后一个字存在
+            *self.words.last_mut().unwrap() &= (1 << bit) - 1;
+        }
+    }
+
+    pub fn push(&mut self, value: bool) {
+        let (word, bit) = split(self.len);
+        if bit == 0 {
+            self.words.push(value as usize);
+        } else {
+            self.words[word] |= (value as usize) << bit;
+        }
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<bool> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let (word, bit) = split(self.len);
+        let value = self.words[word] & (1 << bit) != 0;
+        if bit == 0 {
+            self.words.pop();
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+        Some(value)
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            let (word, bit) = split(index);
+            Some(self.words[word] & (1 << bit) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// ## Panics
+    ///
+    /// `index`越界时`panic`。
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(
+            index < self.len,
+            "index out of bounds: the len is {} but the index is {index}",
+            self.len
+        );
+        let (word, bit) = split(index);
+        if value {
+            self.words[word] |= 1 << bit;
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+    }
+
+    /// 在末尾追加`n`个值为`value`的比特。
+    pub fn grow(&mut self, n: usize, value: bool) {
+        let new_len = self.len.checked_add(n).expect("capacity overflow");
+        let (_, bit) = split(self.len);
+        if value && bit != 0 {
+            *self.words.last_mut().unwrap() |= !0 << bit;
+        }
+
+        let new_words = new_len.div_ceil(BITS);
+        self.words.reserve(new_words - self.words.len());
+        let fill = if value { !0 } else { 0 };
+        while self.words.len() < new_words {
+            self.words.push(fill);
+        }
+
+        self.len = new_len;
+        self.clear_unused_bits();
+    }
+
+    /// 将长度缩短为`len`，如果`len`大于当前长度，则什么也不做。
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+            self.words.truncate(len.div_ceil(BITS));
+            self.clear_unused_bits();
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    /// 将每个比特取反。
+    pub fn negate(&mut self) {
+        for word in self.words.iter_mut() {
+            *word = !*word;
+        }
+        self.clear_unused_bits();
+    }
+
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    #[inline]
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    #[inline]
+    pub fn all(&self) -> bool {
+        self.count_ones() == self.len
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            vec: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    #[inline]
+    fn assert_same_len(&self, other: &BitVec) {
+        assert_eq!(
+            self.len, other.len,
+            "bitwise operation on BitVecs of different lengths"
+        );
+    }
+}
+
+/// 与`&[bool]`相同，返回指向`true`或者`false`的静态引用。
+impl Index<usize> for BitVec {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &bool {
+        match self.get(index) {
+            Some(true) => &true,
+            Some(false) => &false,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len
+            ),
+        }
+    }
+}
+
+/// 逐比特的按位运算，每次处理一个字。两个操作数的高位都是0，因此结果
+/// 的高位也是0，无需再清零。
+///
+/// ## Panics
+///
+/// 两者长度不同时`panic`。
+macro_rules! impl_bit_assign {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait<&BitVec> for BitVec {
+            fn $method(&mut self, rhs: &BitVec) {
+                self.assert_same_len(rhs);
+                for (a, b) in self.words.iter_mut().zip(rhs.words.iter()) {
+                    *a $op *b;
+                }
+            }
+        }
+    };
+}
+
+impl_bit_assign!(BitAndAssign, bitand_assign, &=);
+impl_bit_assign!(BitOrAssign, bitor_assign, |=);
+impl_bit_assign!(BitXorAssign, bitxor_assign, ^=);
+
+/// 以`0`和`1`组成的字符串表示，第一个比特在最左边。
+impl fmt::Debug for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
+
+impl Extend<bool> for BitVec {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let needs = (self.len + lower).div_ceil(BITS);
+        self.words.reserve(needs.saturating_sub(self.words.len()));
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<'a> IntoIterator for &'a BitVec {
+    type Item = bool;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// [`BitVec::iter`]返回的迭代器，`front..back`为尚未产生的比特。
+#[derive(Clone)]
+pub struct Iter<'a> {
+    vec: &'a BitVec,
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        let bit = self.vec.get(self.front);
+        self.front += 1;
+        bit
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.vec.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+impl fmt::Debug for Iter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
@@ -0,0 +1,271 @@
+use std::alloc::{self, Layout};
+use std::borrow::{Borrow, BorrowMut};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+
+/// 在堆上拥有一个`T`的智能指针，与[`Box<T>`]类似（只支持`Sized`的`T`）。
+///
+/// ## 成员类型选择
+///
+/// 与[`MyVec`]中的`RawBuf`相同，我们使用[`NonNull<T>`]而不是
+/// `*mut T`，使得`MyBox<T>`在`T`上是协变的，例如`MyBox<&'static str>`
+/// 可以被当作`MyBox<&'a str>`使用。同时，`NonNull`允许空指针优化，
+/// `Option<MyBox<T>>`与`MyBox<T>`大小相同。
+///
+/// `PhantomData<T>`表明我们拥有一个`T`，在`drop`检查时，编译器会认为
+/// `MyBox<T>`在析构时可能会访问`T`。
+///
+/// ## 关于ZST
+///
+/// 与`RawBuf`相同，全局分配器不允许申请0字节的空间，因此对于ZST，我们
+/// 不申请内存，直接使用[`NonNull::dangling`]，它是对齐的，因此对其进行
+/// 0字节的读写是合法的。释放时同样需要跳过`dealloc`。
+///
+/// ```rust
+/// use rust_practice::collection::boxed::MyBox;
+///
+/// let mut b = MyBox::new(vec![1, 2]);
+/// b.push(3);
+/// assert_eq!(*b, [1, 2, 3]);
+/// let raw = MyBox::into_raw(b);
+/// let b = unsafe { MyBox::from_raw(raw) };
+/// assert_eq!(MyBox::into_inner(b), [1, 2, 3]);
+/// ```
+///
+/// [`MyVec`]: crate::collection::vec::MyVec
+pub struct MyBox<T> {
+    ptr: NonNull<T>,
+    _marker: PhantomData<T>,
+}
+
+/// 与[`Box`]相同，`MyBox<T>`的`Send`和`Sync`取决于`T`。
+unsafe impl<T: Send> Send for MyBox<T> {}
+unsafe impl<T: Sync> Sync for MyBox<T> {}
+
+impl<T> MyBox<T> {
+    pub fn new(value: T) -> Self {
+        let layout = Layout::new::<T>();
+        let ptr = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            // SAFETY: `layout`的大小不为0
+            let raw = unsafe { alloc::alloc(layout) } as *mut T;
+            match NonNull::new(raw) {
+                Some(p) => p,
+                None => alloc::handle_alloc_error(layout),
+            }
+        };
+
+        unsafe {
+            ptr.as_ptr().write(value);
+        }
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// 释放内存（但不`drop`其中的值）。
+    ///
+    /// ## Safety
+    ///
+    /// `ptr`中的值必须已经被移出或者`drop`，且之后不再使用`ptr`。
+    #[inline]
+    unsafe fn dealloc(ptr: NonNull<T>) {
+        let layout = Layout::new::<T>();
+        if layout.size() != 0 {
+            unsafe {
+                alloc::dealloc(ptr.as_ptr() as *mut u8, layout);
+            }
+        }
+    }
+
+    /// 取出其中的值，并释放内存。
+    ///
+    /// 与[`Box`]相同，这是关联函数而不是方法，以避免与`T`的方法冲突。
+    pub fn into_inner(b: Self) -> T {
+        let b = ManuallyDrop::new(b);
+        unsafe {
+            let value = ptr::read(b.ptr.as_ptr());
+            Self::dealloc(b.ptr);
+            value
+        }
+    }
+
+    /// 放弃所有权，返回指向其中的值的指针。调用者需要负责之后使用
+    /// [`MyBox::from_raw`]将其重新构造为`MyBox`，否则值和内存都会泄露。
+    #[inline]
+    pub fn into_raw(b: Self) -> *mut T {
+        ManuallyDrop::new(b).ptr.as_ptr()
+    }
+
+    /// ## Safety
+    ///
+    /// `raw`必须来自[`MyBox::into_raw`]（或者[`MyBox::leak`]），且只能
+    /// 被重新构造一次。
+    #[inline]
+    pub unsafe fn from_raw(raw: *mut T) -> Self {
+        Self {
+            ptr: unsafe { NonNull::new_unchecked(raw) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// 泄露其中的值，返回一个任意生命周期的可变引用。
+    #[inline]
+    pub fn leak<'a>(b: Self) -> &'a mut T
+    where
+        T: 'a,
+    {
+        unsafe { &mut *Self::into_raw(b) }
+    }
+
+    #[inline]
+    pub fn as_ptr(b: &Self) -> *const T {
+        b.ptr.as_ptr()
+    }
+}
+
+impl<T> Drop for MyBox<T> {
+    /// 先`drop`其中的值，再释放内存。即使`T`的`drop`发生了`panic`，我们
+    /// 也会通过守卫释放内存。
+    fn drop(&mut self) {
+        struct DeallocOnDrop<T>(NonNull<T>);
+
+        impl<T> Drop for DeallocOnDrop<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    MyBox::dealloc(self.0);
+                }
+            }
+        }
+
+        let _guard = DeallocOnDrop(self.ptr);
+        if mem::needs_drop::<T>() {
+            unsafe {
+                ptr::drop_in_place(self.ptr.as_ptr());
+            }
+        }
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> DerefMut for MyBox<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T: Clone> Clone for MyBox<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new((**self).clone())
+    }
+
+    /// 复用已有的内存。
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        (**self).clone_from(&**source);
+    }
+}
+
+impl<T: Default> Default for MyBox<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for MyBox<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyBox<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MyBox<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+/// 按指向的值比较，而不是按地址比较。
+impl<T: PartialEq> PartialEq for MyBox<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for MyBox<T> {}
+
+impl<T: PartialOrd> PartialOrd for MyBox<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for MyBox<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash> Hash for MyBox<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T> AsRef<T> for MyBox<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> AsMut<T> for MyBox<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T> Borrow<T> for MyBox<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T> BorrowMut<T> for MyBox<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
@@ -0,0 +1,311 @@
+use std::borrow::{Borrow, BorrowMut};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::slice;
+
+use crate::collection::vec::{self, MyVec};
+
+/// 长度固定的堆上数组，与[`Box<[T]>`]类似。
+///
+/// 与[`MyVec`]相比，`MyBoxedSlice`只记录指针和长度，没有单独的容量字段，
+/// 因为分配的内存总是恰好容纳`len`个元素，也因此不能增长。
+///
+/// ## 内存的所有权
+///
+/// 内存总是以容量等于长度的`MyVec`的形式申请和释放：
+/// [`MyBoxedSlice::from_my_vec`]先调用[`MyVec::shrink_to_fit`]，再取得其
+/// 中的缓冲区；`drop`和[`MyBoxedSlice::into_my_vec`]则将指针和长度还原
+/// 为`MyVec`。因此内存布局的计算和ZST、空数组的处理与`MyVec`完全相同：
+/// 不申请内存时使用[`NonNull::dangling`]。
+///
+/// 注意ZST的`MyVec`的容量总是`isize::MAX`，还原时我们遵循这一点。
+///
+/// ```rust
+/// use rust_practice::collection::boxed_slice::MyBoxedSlice;
+/// use rust_practice::my_vec;
+///
+/// let mut vec = my_vec![1, 2, 3];
+/// vec.reserve(10);
+/// let mut slice = MyBoxedSlice::from_my_vec(vec);
+/// slice[0] = 4;
+/// assert_eq!(*slice, [4, 2, 3]);
+/// let vec = slice.into_my_vec();
+/// assert_eq!(vec.capacity(), 3);
+/// assert_eq!(size_of::<MyBoxedSlice<u8>>(), 2 * size_of::<usize>());
+/// ```
+pub struct MyBoxedSlice<T> {
+    ptr: NonNull<T>,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for MyBoxedSlice<T> {}
+unsafe impl<T: Sync> Sync for MyBoxedSlice<T> {}
+
+impl<T> MyBoxedSlice<T> {
+    /// 空数组，不申请内存。
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// 取得`vec`的缓冲区。如果`vec`的容量大于长度，则先将其缩小（至多一
+    /// 次重新分配），否则不会重新分配内存。
+    pub fn from_my_vec(mut vec: MyVec<T>) -> Self {
+        vec.shrink_to_fit();
+        let mut vec = ManuallyDrop::new(vec);
+        Self {
+            // SAFETY: `MyVec`的指针永远不为空
+            ptr: unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) },
+            len: vec.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// 依次使用`f(0)`, `f(1)`, ..., `f(len - 1)`构造数组，只申请一次内存。
+    ///
+    /// 如果`f`发生了`panic`，已经构造的元素会被`drop`。
+    pub fn from_fn<F: FnMut(usize) -> T>(len: usize, mut f: F) -> Self {
+        let mut vec = MyVec::with_capacity(len);
+        for i in 0..len {
+            vec.push(f(i));
+        }
+        Self::from_my_vec(vec)
+    }
+
+    /// 还原为容量等于长度的`MyVec`（ZST除外，见类型的文档），不会重新分
+    /// 配内存。
+    #[inline]
+    pub fn into_my_vec(self) -> MyVec<T> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this`不会再被使用
+        unsafe { this.to_my_vec() }
+    }
+
+    /// 使用指针和长度构造`MyVec`。
+    ///
+    /// ## Safety
+    ///
+    /// 之后不能再使用`self`中的缓冲区。
+    #[inline]
+    unsafe fn to_my_vec(&self) -> MyVec<T> {
+        let cap = if mem::size_of::<T>() == 0 {
+            isize::MAX as usize
+        } else {
+            self.len
+        };
+        // SAFETY: 缓冲区来自一个容量为`cap`的`MyVec`
+        unsafe { MyVec::from_parts(self.ptr, self.len, cap) }
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Clone> MyBoxedSlice<T> {
+    /// 长度为`len`，每个元素都是`value`的数组。`value`被克隆`len - 1`次，
+    /// 最后一个元素直接使用`value`。
+    pub fn filled(len: usize, value: T) -> Self {
+        let mut value = Some(value);
+        Self::from_fn(len, |i| {
+            if i + 1 == len {
+                value.take().unwrap()
+            } else {
+                value.clone().unwrap()
+            }
+        })
+    }
+}
+
+impl<T> Drop for MyBoxedSlice<T> {
+    /// 还原为`MyVec`，由其负责`drop`元素和释放内存。
+    fn drop(&mut self) {
+        drop(unsafe { self.to_my_vec() });
+    }
+}
+
+impl<T> Deref for MyBoxedSlice<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for MyBoxedSlice<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> Default for MyBoxedSlice<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for MyBoxedSlice<T> {
+    fn clone(&self) -> Self {
+        Self::from_fn(self.len, |i| self[i].clone())
+    }
+
+    /// 长度相同时复用已有的内存。
+    fn clone_from(&mut self, source: &Self) {
+        if self.len == source.len {
+            self.as_mut_slice().clone_from_slice(source);
+        } else {
+            *self = source.clone();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyBoxedSlice<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for MyBoxedSlice<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for MyBoxedSlice<T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for MyBoxedSlice<T> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: Eq> Eq for MyBoxedSlice<T> {}
+
+impl<T: Hash> Hash for MyBoxedSlice<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T> AsRef<[T]> for MyBoxedSlice<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsMut<[T]> for MyBoxedSlice<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Borrow<[T]> for MyBoxedSlice<T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> BorrowMut<[T]> for MyBoxedSlice<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> From<MyVec<T>> for MyBoxedSlice<T> {
+    #[inline]
+    fn from(vec: MyVec<T>) -> Self {
+        Self::from_my_vec(vec)
+    }
+}
+
+impl<T> From<MyBoxedSlice<T>> for MyVec<T> {
+    #[inline]
+    fn from(slice: MyBoxedSlice<T>) -> Self {
+        slice.into_my_vec()
+    }
+}
+
+impl<T: Clone> From<&[T]> for MyBoxedSlice<T> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        Self::from_fn(slice.len(), |i| slice[i].clone())
+    }
+}
+
+impl<T> FromIterator<T> for MyBoxedSlice<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_my_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for MyBoxedSlice<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_my_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MyBoxedSlice<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut MyBoxedSlice<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,241 @@
+mod error;
+
+pub use error::NulError;
+
+use std::borrow::Borrow;
+use std::ffi::{CStr, CString, c_char};
+use std::fmt;
+use std::ops::Deref;
+
+use crate::collection::vec::MyVec;
+
+/// 基于[`MyVec<u8>`]的以NUL结尾的字节串，与[`CString`]类似，用于向C函数
+/// 传递字符串。
+///
+/// `MyCString`始终维护一个不变式：`vec`的最后一个字节是NUL，且其余位置
+/// 都不是NUL。所有的安全方法都会保证这一点，因此[`MyCString::as_ptr`]
+/// 返回的指针可以直接传给需要`const char *`的C函数，C端看到的字符串长度
+/// 与[`MyCString::as_bytes`]相同。
+///
+/// 与`CString`不同，`MyCString`可以通过[`MyCString::push_bytes`]等方法
+/// 追加数据，追加的数据同样会被检查。
+///
+/// ```rust
+/// use rust_practice::collection::c_string::MyCString;
+///
+/// let mut s = MyCString::new(b"hello").unwrap();
+/// s.push_str(", world").unwrap();
+/// assert_eq!(s.as_bytes(), b"hello, world");
+/// assert_eq!(s.as_bytes_with_nul(), b"hello, world\0");
+///
+/// let err = MyCString::new(b"he\0llo").unwrap_err();
+/// assert_eq!(err.nul_position(), 2);
+/// assert_eq!(err.into_bytes(), *b"he\0llo");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MyCString {
+    vec: MyVec<u8>,
+}
+
+/// 返回第一个NUL字节的位置。
+#[inline]
+fn find_nul(bytes: &[u8]) -> Option<usize> {
+    bytes.iter().position(|&b| b == 0)
+}
+
+impl MyCString {
+    /// 检查`bytes`中是否含有NUL，并在末尾追加NUL。
+    ///
+    /// 如果`bytes`本身就是`MyVec<u8>`，则会接管其内存（追加NUL时可能需要
+    /// 扩容）。
+    pub fn new<T: Into<MyVec<u8>>>(bytes: T) -> Result<Self, NulError> {
+        let vec = bytes.into();
+        match find_nul(&vec) {
+            Some(position) => Err(NulError::new(position, vec)),
+            None => Ok(unsafe { Self::from_my_vec_unchecked(vec) }),
+        }
+    }
+
+    /// 在末尾追加NUL，不检查`vec`中是否含有NUL。
+    ///
+    /// ## Safety
+    ///
+    /// - `vec`中不能含有NUL字节
+    pub unsafe fn from_my_vec_unchecked(mut vec: MyVec<u8>) -> Self {
+        debug_assert!(find_nul(&vec).is_none());
+        vec.reserve(1);
+        vec.push(0);
+        Self { vec }
+    }
+
+    /// 接管以NUL结尾的`vec`，不进行检查。
+    ///
+    /// ## Safety
+    ///
+    /// - `vec`的最后一个字节必须是NUL，且其余位置都不是NUL
+    #[inline]
+    pub unsafe fn from_my_vec_with_nul_unchecked(vec: MyVec<u8>) -> Self {
+        debug_assert!(vec.last() == Some(&0) && find_nul(&vec[..vec.len() - 1]).is_none());
+        Self { vec }
+    }
+
+    /// 指向以NUL结尾的数据，在`self`被修改或者`drop`之前有效。
+    #[inline]
+    pub fn as_ptr(&self) -> *const c_char {
+        self.vec.as_ptr() as *const c_char
+    }
+
+    /// 不包含末尾的NUL。
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.vec[..self.vec.len() - 1]
+    }
+
+    #[inline]
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        &self.vec
+    }
+
+    #[inline]
+    pub fn as_c_str(&self) -> &CStr {
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.vec) }
+    }
+
+    /// 不包含末尾的NUL的长度，与C的`strlen`相同。
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len() - 1
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 移除末尾的NUL，返回其余的字节。
+    #[inline]
+    pub fn into_my_vec(self) -> MyVec<u8> {
+        let mut vec = self.vec;
+        vec.pop();
+        vec
+    }
+
+    #[inline]
+    pub fn into_my_vec_with_nul(self) -> MyVec<u8> {
+        self.vec
+    }
+
+    /// 追加`bytes`。如果其中含有NUL，则返回[`NulError`]（其中的位置是相对
+    /// 于`bytes`的），`self`不会被修改。
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), NulError> {
+        if let Some(position) = find_nul(bytes) {
+            return Err(NulError::new(position, MyVec::from(bytes)));
+        }
+        self.vec.reserve(bytes.len());
+        // 先移除NUL，追加之后再补上
+        self.vec.pop();
+        self.vec.extend_from_slice(bytes);
+        self.vec.push(0);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn push_str(&mut self, string: &str) -> Result<(), NulError> {
+        self.push_bytes(string.as_bytes())
+    }
+
+    /// 追加单个字节，`byte`为0时返回[`NulError`]。
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> Result<(), NulError> {
+        self.push_bytes(&[byte])
+    }
+
+    /// 移除所有内容，只保留末尾的NUL，容量不变。
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.truncate(1);
+        self.vec[0] = 0;
+    }
+}
+
+impl Default for MyCString {
+    /// 空字符串，只包含一个NUL。
+    #[inline]
+    fn default() -> Self {
+        unsafe { Self::from_my_vec_unchecked(MyVec::new()) }
+    }
+}
+
+impl Deref for MyCString {
+    type Target = CStr;
+
+    #[inline]
+    fn deref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl AsRef<CStr> for MyCString {
+    #[inline]
+    fn as_ref(&self) -> &CStr {
+        self
+    }
+}
+
+impl Borrow<CStr> for MyCString {
+    #[inline]
+    fn borrow(&self) -> &CStr {
+        self
+    }
+}
+
+impl fmt::Debug for MyCString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_c_str(), f)
+    }
+}
+
+impl From<&CStr> for MyCString {
+    #[inline]
+    fn from(s: &CStr) -> Self {
+        Self {
+            vec: MyVec::from(s.to_bytes_with_nul()),
+        }
+    }
+}
+
+/// 通过`Vec<u8>`接管[`CString`]的内存，不会复制。
+impl From<CString> for MyCString {
+    #[inline]
+    fn from(s: CString) -> Self {
+        Self {
+            vec: MyVec::from(s.into_bytes_with_nul()),
+        }
+    }
+}
+
+/// 通过`Vec<u8>`将内存交给[`CString`]。`CString`内部使用`Box<[u8]>`存储
+/// 数据，因此只有在容量等于长度时才完全不会重新分配，否则会先缩小容量。
+impl From<MyCString> for CString {
+    #[inline]
+    fn from(s: MyCString) -> Self {
+        let vec: Vec<u8> = s.vec.into();
+        // SAFETY: `MyCString`的不变式与`CString`相同
+        unsafe { CString::from_vec_with_nul_unchecked(vec) }
+    }
+}
+
+impl PartialEq<CStr> for MyCString {
+    #[inline]
+    fn eq(&self, other: &CStr) -> bool {
+        self.as_c_str() == other
+    }
+}
+
+impl PartialEq<&CStr> for MyCString {
+    #[inline]
+    fn eq(&self, other: &&CStr) -> bool {
+        self.as_c_str() == *other
+    }
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::collection::vec::MyVec;
+
+/// 数据中含有NUL字节时，[`MyCString::new`]等方法产生的错误。
+///
+/// 与[`std::ffi::NulError`]相同，我们会将传入的字节返还给调用者。对于
+/// [`MyCString::push_bytes`]等追加操作，返还的是被拒绝的那部分数据，
+/// `MyCString`本身不会被修改。
+///
+/// [`MyCString::new`]: crate::collection::c_string::MyCString::new
+/// [`MyCString::push_bytes`]: crate::collection::c_string::MyCString::push_bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NulError {
+    position: usize,
+    bytes: MyVec<u8>,
+}
+
+impl NulError {
+    #[inline]
+    pub(super) fn new(position: usize, bytes: MyVec<u8>) -> Self {
+        Self { position, bytes }
+    }
+
+    /// 第一个NUL字节在数据中的位置。
+    #[inline]
+    pub fn nul_position(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> MyVec<u8> {
+        self.bytes
+    }
+}
+
+impl fmt::Display for NulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "nul byte found in provided data at position: {}",
+            self.position
+        )
+    }
+}
+
+impl Error for NulError {}
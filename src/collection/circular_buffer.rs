@@ -0,0 +1,233 @@
+use std::fmt;
+use std::ops::Index;
+
+use crate::collection::inplace_deque::InplaceDeque;
+use crate::collection::vec::MyVec;
+use crate::collection::vec_deque::{Iter, IterMut};
+
+/// 容量固定为`N`的环形缓冲区，只保留最近的`N`个元素。
+///
+/// 与[`InplaceDeque`]不同，向已满的缓冲区中[`push`]时不会失败，而是覆盖
+/// 最旧的元素，并将其返还给调用者。被覆盖的元素要么被返还，要么在调用者
+/// 丢弃返回值时被`drop`，恰好一次。
+///
+/// 元素存储在`InplaceDeque<N, T>`中（即`[MaybeUninit<T>; N]`），不会申请
+/// 堆内存，已初始化的区域可能跨越数组的末尾，其释放同样由`InplaceDeque`
+/// 负责。
+///
+/// ```rust
+/// use rust_practice::collection::circular_buffer::CircularBuffer;
+///
+/// let mut samples = CircularBuffer::<3, _>::new();
+/// for x in 1..=3 {
+///     assert_eq!(samples.push(x), None);
+/// }
+/// assert_eq!(samples.push(4), Some(1));
+/// assert_eq!(samples.oldest(), Some(&2));
+/// assert_eq!(samples.latest(), Some(&4));
+/// assert_eq!(samples.to_my_vec(), [2, 3, 4]);
+/// ```
+///
+/// [`push`]: CircularBuffer::push
+pub struct CircularBuffer<const N: usize, T> {
+    deque: InplaceDeque<N, T>,
+}
+
+impl<const N: usize, T> CircularBuffer<N, T> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            deque: InplaceDeque::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.deque.is_full()
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// 追加最新的元素。如果缓冲区已满，则移除并返回最旧的元素。
+    ///
+    /// 当`N == 0`时，缓冲区无法容纳任何元素，`value`会被直接返还。
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if N == 0 {
+            return Some(value);
+        }
+        let evicted = if self.is_full() {
+            self.deque.pop_front()
+        } else {
+            None
+        };
+        // 已满时刚刚移除了最旧的元素，此时一定还有空间。`T`不一定实现了
+        // `Debug`，因此不能直接使用`expect`
+        if self.deque.push_back(value).is_err() {
+            unreachable!("CircularBuffer has room after eviction");
+        }
+        evicted
+    }
+
+    /// 移除并返回最旧的元素。
+    #[inline]
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    /// 移除并返回最新的元素。
+    #[inline]
+    pub fn pop_latest(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+
+    #[inline]
+    pub fn oldest(&self) -> Option<&T> {
+        self.deque.front()
+    }
+
+    #[inline]
+    pub fn latest(&self) -> Option<&T> {
+        self.deque.back()
+    }
+
+    /// 第`index`旧的元素，`0`表示最旧的元素。
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.deque.get(index)
+    }
+
+    /// 按从旧到新的顺序返回两个切片。
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.deque.as_slices()
+    }
+
+    /// 从最旧的元素迭代到最新的元素。
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.deque.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.deque.iter_mut()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.deque.clear();
+    }
+}
+
+impl<const N: usize, T: Clone> CircularBuffer<N, T> {
+    /// 按从旧到新的顺序复制所有元素。
+    pub fn to_my_vec(&self) -> MyVec<T> {
+        let (a, b) = self.as_slices();
+        let mut vec = MyVec::with_capacity(self.len());
+        vec.extend_from_slice(a);
+        vec.extend_from_slice(b);
+        vec
+    }
+}
+
+impl<const N: usize, T> Default for CircularBuffer<N, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: Clone> Clone for CircularBuffer<N, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            deque: self.deque.clone(),
+        }
+    }
+}
+
+impl<const N: usize, T: fmt::Debug> fmt::Debug for CircularBuffer<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const N: usize, T: PartialEq> PartialEq for CircularBuffer<N, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.deque == other.deque
+    }
+}
+
+impl<const N: usize, T: Eq> Eq for CircularBuffer<N, T> {}
+
+impl<const N: usize, T: PartialEq, const M: usize> PartialEq<[T; M]> for CircularBuffer<N, T> {
+    #[inline]
+    fn eq(&self, other: &[T; M]) -> bool {
+        self.deque == *other
+    }
+}
+
+/// ## Panics
+///
+/// `index`越界时`panic`。
+impl<const N: usize, T> Index<usize> for CircularBuffer<N, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("CircularBuffer index out of bounds")
+    }
+}
+
+/// 与[`CircularBuffer::push`]相同，缓冲区已满时覆盖最旧的元素，被覆盖的
+/// 元素会被`drop`。
+impl<const N: usize, T> Extend<T> for CircularBuffer<N, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// 只保留迭代器中的最后`N`个元素。
+impl<const N: usize, T> FromIterator<T> for CircularBuffer<N, T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a CircularBuffer<N, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a mut CircularBuffer<N, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,327 @@
+use std::alloc::{self, Layout};
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+use crate::collection::vec::MyVec;
+
+/// 第一个分段的大小为`2^SHIFT`。
+const SHIFT: usize = 5;
+const FIRST_BUCKET_SIZE: usize = 1 << SHIFT;
+/// 第`i`个分段的大小为`FIRST_BUCKET_SIZE << i`，这些分段足以覆盖所有
+/// 小于`usize::MAX - FIRST_BUCKET_SIZE`的下标。
+const BUCKETS: usize = usize::BITS as usize - SHIFT;
+
+/// 每个槽位带有一个标记，表示其中的值是否已经写入完成。
+struct Slot<T> {
+    ready: AtomicBool,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// 只能追加的并发数组，多个线程可以通过`&self`同时[`push`]和[`get`]，
+/// 不需要加锁。
+///
+/// ## 存储方式
+///
+/// 元素被存储在一组预先确定大小的分段中，第`i`个分段可以容纳
+/// `32 << i`个元素，分段的指针保存在一个固定长度的数组中。分段一旦被分
+/// 配就不会被移动或者重新分配，因此已经发布的元素的地址永远不会改变，
+/// [`get`]返回的引用在`ConcurrentVec`存活期间始终有效。
+///
+/// ## 两阶段的插入
+///
+/// 1. 认领：对`claimed`执行`fetch_add`，得到一个唯一的下标；
+/// 2. 提交：将值写入对应的槽位，然后设置槽位的`ready`标记。
+///
+/// 读者只有在看到`ready`之后才会读取槽位中的值。某个线程认领了下标但还没
+/// 有提交时，该槽位对读者是不可见的，因此[`ConcurrentVec::len`]（认领的
+/// 数量）可能大于实际可以读取的元素数量，[`ConcurrentVec::iter`]只会迭代
+/// 已经发布的前缀。
+///
+/// ## 关于内存顺序
+///
+/// - 认领使用`Relaxed`：我们只需要下标互不相同，而对同一个原子变量的
+///   读-改-写操作总是构成一个全序，不需要与其他内存访问同步。
+/// - 分段的指针使用`compare_exchange(AcqRel, Acquire)`发布，读取时使用
+///   `Acquire`：分段在发布之前被清零（所有`ready`都是`false`），其他线程
+///   看到指针时也必须看到这些初始化。多个线程同时发现分段不存在时，都会
+///   申请内存，但只有一个能成功发布，其余的释放自己申请的内存后使用已经
+///   发布的分段。
+/// - `ready`使用`Release`写入、`Acquire`读取：与值的写入形成
+///   happens-before关系，读者看到`true`时一定能看到完整的值。
+/// - `drop`和[`ConcurrentVec::into_my_vec`]持有`self`的所有权或者
+///   `&mut self`，不存在并发访问，直接使用`get_mut`读取。
+///
+/// 在loom之类的模型检查器中，需要验证的正是上面两对`Release`/`Acquire`：
+/// 如果将`ready`的写入改为`Relaxed`，读者可能在看到`true`之后读到未初始化
+/// 的值；如果分段指针的发布改为`Relaxed`，读者可能看到未清零的`ready`。
+///
+/// ```rust
+/// use std::thread;
+/// use rust_practice::collection::concurrent_vec::ConcurrentVec;
+///
+/// let vec = ConcurrentVec::new();
+/// thread::scope(|s| {
+///     for t in 0..4 {
+///         let vec = &vec;
+///         s.spawn(move || {
+///             for i in 0..100 {
+///                 let index = vec.push(t * 100 + i);
+///                 assert_eq!(vec.get(index), Some(&(t * 100 + i)));
+///             }
+///         });
+///     }
+/// });
+/// let mut all = vec.into_my_vec();
+/// all.sort();
+/// assert!(all.iter().copied().eq(0..400));
+/// ```
+///
+/// [`push`]: ConcurrentVec::push
+/// [`get`]: ConcurrentVec::get
+pub struct ConcurrentVec<T> {
+    buckets: [AtomicPtr<Slot<T>>; BUCKETS],
+    claimed: AtomicUsize,
+    _marker: PhantomData<T>,
+}
+
+/// 通过`&self`可以把`T`放入其中，`drop`时`T`可能在另一个线程被释放，因此
+/// 需要`T: Send`；通过`&self`可以获得`&T`，因此共享时还需要`T: Sync`。
+unsafe impl<T: Send> Send for ConcurrentVec<T> {}
+unsafe impl<T: Send + Sync> Sync for ConcurrentVec<T> {}
+
+impl<T> ConcurrentVec<T> {
+    pub const fn new() -> Self {
+        Self {
+            buckets: [const { AtomicPtr::new(ptr::null_mut()) }; BUCKETS],
+            claimed: AtomicUsize::new(0),
+            _marker: PhantomData,
+        }
+    }
+
+    /// 已经被认领的下标数量，其中可能包含尚未提交的槽位。
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.claimed.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn bucket_size(bucket: usize) -> usize {
+        FIRST_BUCKET_SIZE << bucket
+    }
+
+    #[inline]
+    fn bucket_layout(bucket: usize) -> Layout {
+        Layout::array::<Slot<T>>(Self::bucket_size(bucket)).expect("capacity overflow")
+    }
+
+    /// 将下标拆分为分段的下标和分段内的偏移。
+    ///
+    /// 令`i = index + 32`，则第`b`个分段包含`[32 << b, 64 << b)`中的`i`，
+    /// `b`可以通过`i`的最高位得到。`i`溢出时返回`None`。
+    #[inline]
+    fn locate(index: usize) -> Option<(usize, usize)> {
+        let i = index.checked_add(FIRST_BUCKET_SIZE)?;
+        let bucket = (usize::BITS - 1 - i.leading_zeros()) as usize - SHIFT;
+        Some((bucket, i - Self::bucket_size(bucket)))
+    }
+
+    /// 返回第`bucket`个分段，如果还没有分配，则申请内存并尝试发布。
+    fn get_or_alloc_bucket(&self, bucket: usize) -> *mut Slot<T> {
+        let current = self.buckets[bucket].load(Ordering::Acquire);
+        if !current.is_null() {
+            return current;
+        }
+
+        let layout = Self::bucket_layout(bucket);
+        // SAFETY: 槽位至少包含一个`AtomicBool`，`layout`的大小不为0。清零
+        // 之后所有的`ready`都是`false`
+        let new = unsafe { alloc::alloc_zeroed(layout) } as *mut Slot<T>;
+        if new.is_null() {
+            alloc::handle_alloc_error(layout);
+        }
+
+        match self.buckets[bucket].compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(published) => {
+                // 其他线程抢先发布了分段，新申请的内存从未被使用过
+                unsafe {
+                    alloc::dealloc(new as *mut u8, layout);
+                }
+                published
+            }
+        }
+    }
+
+    /// 在末尾追加元素并返回它的下标。可以在多个线程中同时调用。
+    ///
+    /// 不同线程追加的元素的顺序取决于认领下标的顺序。
+    pub fn push(&self, value: T) -> usize {
+        let index = self.claimed.fetch_add(1, Ordering::Relaxed);
+        let (bucket, offset) = Self::locate(index).expect("ConcurrentVec capacity overflow");
+        let slot = unsafe { &*self.get_or_alloc_bucket(bucket).add(offset) };
+
+        // SAFETY: 下标是唯一的，只有当前线程会写入该槽位，而在`ready`被设
+        // 置之前，其他线程不会读取它
+        unsafe {
+            (*slot.value.get()).write(value);
+        }
+        slot.ready.store(true, Ordering::Release);
+        index
+    }
+
+    /// 返回下标为`index`的元素，如果该下标还没有被认领或者还没有提交，则
+    /// 返回`None`。
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (bucket, offset) = Self::locate(index)?;
+        let base = self.buckets[bucket].load(Ordering::Acquire);
+        if base.is_null() {
+            return None;
+        }
+
+        let slot = unsafe { &*base.add(offset) };
+        if slot.ready.load(Ordering::Acquire) {
+            // SAFETY: `ready`之后该槽位不会再被写入
+            Some(unsafe { (*slot.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// 持有`&mut self`时不存在并发访问。
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (bucket, offset) = Self::locate(index)?;
+        let base = *self.buckets[bucket].get_mut();
+        if base.is_null() {
+            return None;
+        }
+
+        let slot = unsafe { &mut *base.add(offset) };
+        if *slot.ready.get_mut() {
+            Some(unsafe { slot.value.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// 按下标的顺序迭代已经发布的前缀，遇到第一个尚未提交的槽位时停止。
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            vec: self,
+            index: Some(0),
+        }
+    }
+
+    /// 按下标的顺序取出所有已经提交的元素。认领之后没有提交的槽位（例如
+    /// 写入之前线程`panic`了）会被跳过。
+    pub fn into_my_vec(mut self) -> MyVec<T> {
+        let len = *self.claimed.get_mut();
+        let mut vec = MyVec::with_capacity(len);
+        self.for_each_ready(len, |slot| {
+            // 清除标记，之后的`drop`不会再次释放该值
+            *slot.ready.get_mut() = false;
+            vec.push(unsafe { slot.value.get_mut().assume_init_read() });
+        });
+        vec
+    }
+
+    /// 对前`len`个槽位中已经提交的槽位调用`f`。
+    fn for_each_ready<F: FnMut(&mut Slot<T>)>(&mut self, len: usize, mut f: F) {
+        let mut remaining = len;
+        for (bucket, ptr) in self.buckets.iter_mut().enumerate() {
+            let base = *ptr.get_mut();
+            if remaining == 0 || base.is_null() {
+                break;
+            }
+            let count = remaining.min(Self::bucket_size(bucket));
+            for offset in 0..count {
+                let slot = unsafe { &mut *base.add(offset) };
+                if *slot.ready.get_mut() {
+                    f(slot);
+                }
+            }
+            remaining -= count;
+        }
+    }
+}
+
+impl<T> Drop for ConcurrentVec<T> {
+    /// 只`drop`已经提交的槽位，然后释放所有分段。
+    fn drop(&mut self) {
+        let len = *self.claimed.get_mut();
+        self.for_each_ready(len, |slot| unsafe {
+            slot.value.get_mut().assume_init_drop();
+        });
+
+        for (bucket, ptr) in self.buckets.iter_mut().enumerate() {
+            let base = *ptr.get_mut();
+            if !base.is_null() {
+                unsafe {
+                    alloc::dealloc(base as *mut u8, Self::bucket_layout(bucket));
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for ConcurrentVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ConcurrentVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ConcurrentVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// [`ConcurrentVec::iter`]返回的迭代器。
+///
+/// 一旦遇到尚未提交的槽位就会停止，即使之后该槽位被提交，也不会继续产生
+/// 元素。
+pub struct Iter<'a, T> {
+    vec: &'a ConcurrentVec<T>,
+    /// 停止之后为`None`
+    index: Option<usize>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index?;
+        let value = self.vec.get(index);
+        self.index = value.and(index.checked_add(1));
+        value
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
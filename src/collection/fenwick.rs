@@ -0,0 +1,210 @@
+use std::fmt;
+use std::ops::{Add, RangeBounds, Sub};
+
+use crate::collection::{self, vec::MyVec};
+
+/// 最低的非零位，即`i & -i`。
+#[inline]
+const fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+/// 树状数组（Fenwick tree，binary indexed tree），支持`O(log n)`的单点修改
+/// 和前缀求和。
+///
+/// ## 存储方式
+///
+/// 为了方便推导，下面使用从1开始的下标。节点`i`保存了区间
+/// `(i - lowbit(i), i]`的和，其中`lowbit(i)`是`i`的最低非零位。于是：
+///
+/// - 求前`i`个元素的和时，依次累加节点`i`、`i - lowbit(i)`、……，直到0；
+/// - 修改第`i`个元素时，依次更新节点`i`、`i + lowbit(i)`、……，直到超出
+///   长度。
+///
+/// 节点`i`存储在`nodes[i - 1]`中。公开的接口仍然使用从0开始的下标，与切片
+/// 相同，前缀和[`FenwickTree::prefix_sum`]也使用左闭右开的区间。
+///
+/// ## 元素类型
+///
+/// `T`需要满足`Copy + Add + Sub + Default`，其中`Default`作为加法的单位元，
+/// `Sub`用于计算区间和。整数的溢出行为与`T`的`Add`相同，需要回绕的求和可
+/// 以使用[`std::num::Wrapping`]。
+///
+/// ```rust
+/// use rust_practice::collection::fenwick::FenwickTree;
+///
+/// let mut tree = FenwickTree::from_slice(&[1, 2, 3, 4, 5]);
+/// assert_eq!(tree.prefix_sum(3), 6);
+/// tree.update(1, 10);
+/// assert_eq!(tree.range_sum(1..4), 19);
+/// assert_eq!(tree.range_sum(..), 25);
+/// tree.push(6);
+/// assert_eq!(tree.range_sum(4..), 11);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct FenwickTree<T> {
+    nodes: MyVec<T>,
+}
+
+impl<T> FenwickTree<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            nodes: MyVec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    /// 长度为`n`，所有元素都是`T::default()`。
+    pub fn with_len(n: usize) -> Self {
+        let mut nodes = MyVec::with_capacity(n);
+        for _ in 0..n {
+            nodes.push(T::default());
+        }
+        Self { nodes }
+    }
+
+    /// 以`O(n)`的时间构造：每个节点只需要把自己的和加到父节点上一次。
+    pub fn from_slice(values: &[T]) -> Self {
+        let mut nodes = MyVec::from(values);
+        let n = nodes.len();
+        for i in 1..=n {
+            let parent = i + lowbit(i);
+            if parent <= n {
+                nodes[parent - 1] = nodes[parent - 1] + nodes[i - 1];
+            }
+        }
+        Self { nodes }
+    }
+
+    /// 将第`index`个元素加上`delta`。
+    ///
+    /// ## Panics
+    ///
+    /// `index`越界时`panic`。
+    pub fn update(&mut self, index: usize, delta: T) {
+        let n = self.len();
+        assert!(
+            index < n,
+            "FenwickTree index out of bounds: the len is {n} but the index is {index}"
+        );
+        let mut i = index + 1;
+        while i <= n {
+            self.nodes[i - 1] = self.nodes[i - 1] + delta;
+            i += lowbit(i);
+        }
+    }
+
+    /// 前`end`个元素的和，即区间`[0, end)`的和。
+    ///
+    /// ## Panics
+    ///
+    /// `end > len`时`panic`。
+    pub fn prefix_sum(&self, end: usize) -> T {
+        let n = self.len();
+        assert!(
+            end <= n,
+            "FenwickTree prefix end {end} out of range for length {n}"
+        );
+        let mut sum = T::default();
+        let mut i = end;
+        while i > 0 {
+            sum = sum + self.nodes[i - 1];
+            i -= lowbit(i);
+        }
+        sum
+    }
+
+    /// 区间`range`中的元素的和，区间的规则与切片相同，空区间的和为
+    /// `T::default()`。
+    ///
+    /// ## Panics
+    ///
+    /// 区间越界或者左端点大于右端点时`panic`。
+    pub fn range_sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let range = collection::slice::range(range, ..self.len());
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+
+    /// 第`index`个元素的值。
+    #[inline]
+    pub fn get(&self, index: usize) -> T {
+        self.range_sum(index..=index)
+    }
+
+    /// 在末尾追加元素，时间复杂度为`O(log n)`。
+    ///
+    /// 新的节点`i`覆盖`(i - lowbit(i), i]`，除了`value`之外，其余部分都是
+    /// 已有的元素，可以通过两个前缀和相减得到。
+    pub fn push(&mut self, value: T) {
+        let i = self.len() + 1;
+        let covered = self.prefix_sum(i - 1) - self.prefix_sum(i - lowbit(i));
+        self.nodes.push(covered + value);
+    }
+}
+
+impl<T> Default for FenwickTree<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<&[T]> for FenwickTree<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    #[inline]
+    fn from(values: &[T]) -> Self {
+        Self::from_slice(values)
+    }
+}
+
+/// 与[`FenwickTree::push`]相同，逐个追加元素。
+impl<T> Extend<T> for FenwickTree<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for FenwickTree<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: MyVec<T> = iter.into_iter().collect();
+        Self::from_slice(&values)
+    }
+}
+
+/// 输出原始的元素，而不是内部的节点。
+impl<T> fmt::Debug for FenwickTree<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Default + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.len()).map(|i| self.get(i)))
+            .finish()
+    }
+}
@@ -0,0 +1,438 @@
+use std::fmt;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Index, IndexMut};
+use std::ptr;
+use std::slice;
+
+use crate::collection::raw::RawBuf;
+use crate::collection::vec::MyVec;
+use crate::collection::vec_deque::{Iter, IterMut};
+
+/// 间隙缓冲区（gap buffer），常用于文本编辑器。
+///
+/// 缓冲区在光标处保留一段未初始化的“间隙”，元素被分为间隙前后两段：
+///
+/// ```text
+///          gap_start   gap_end
+///              ↓         ↓
+///   +-+-+-+-+-+-+-+-+-+-+-+-+
+///   |0|1|2|3|4|U|U|U|U|5|6|7|
+///   +-+-+-+-+-+-+-+-+-+-+-+-+
+///   U: 未初始化
+/// ```
+///
+/// 光标位于`gap_start`，在光标处插入或删除元素只需要移动间隙的边界，
+/// 时间复杂度为`O(1)`。移动光标时，我们使用[`ptr::copy`]将光标与间
+/// 隙之间的元素搬运到间隙的另一侧，时间复杂度与移动的距离成正比。
+///
+/// 逻辑上的第`i`个元素，如果`i < gap_start`，则位于物理位置`i`，否
+/// 则位于`i + gap_len`。
+///
+/// ## 关于ZST
+///
+/// 对于ZST，[`RawBuf`]的容量为[`isize::MAX`]，间隙的长度也随之
+/// 非常大，但任何指针偏移和复制都是无操作，因此下面的逻辑依然成立。
+///
+/// ```rust
+/// use rust_practice::collection::gap_buffer::GapBuffer;
+///
+/// let mut buf: GapBuffer<char> = "hello".chars().collect();
+/// buf.set_cursor(0);
+/// buf.insert_slice(&['o', 'h', ',', ' ']);
+/// buf.set_cursor(buf.len());
+/// buf.insert('!');
+/// assert_eq!(buf.iter().collect::<String>(), "oh, hello!");
+/// buf.set_cursor(1);
+/// assert_eq!(buf.remove(), Some('h'));
+/// assert_eq!(buf.remove_before(), Some('o'));
+/// assert_eq!(buf.iter().collect::<String>(), ", hello!");
+/// ```
+pub struct GapBuffer<T> {
+    buf: RawBuf<T>,
+    gap_start: usize,
+    gap_end: usize,
+}
+
+impl<T> GapBuffer<T> {
+    #[inline]
+    pub fn new() -> Self {
+        let buf = RawBuf::new();
+        let gap_end = buf.cap();
+        Self {
+            buf,
+            gap_start: 0,
+            gap_end,
+        }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let buf = RawBuf::with_capacity(capacity);
+        let gap_end = buf.cap();
+        Self {
+            buf,
+            gap_start: 0,
+            gap_end,
+        }
+    }
+
+    #[inline]
+    fn ptr(&self) -> *mut T {
+        self.buf.ptr().as_ptr()
+    }
+
+    #[inline]
+    fn gap_len(&self) -> usize {
+        self.gap_end - self.gap_start
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.capacity() - self.gap_len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.cap()
+    }
+
+    /// 光标的位置，即光标前的元素个数。
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.gap_start
+    }
+
+    /// 将光标（间隙）移动到`pos`。
+    ///
+    /// ```text
+    /// 向左移动：[0 1 2 3 . . 4 5] -> [0 1 . . 2 3 4 5]
+    /// 向右移动：[0 1 . . 2 3 4 5] -> [0 1 2 3 . . 4 5]
+    /// ```
+    ///
+    /// 两段可能重叠（间隙比移动的距离短），因此需要使用[`ptr::copy`]。
+    ///
+    /// ## Panics
+    ///
+    /// `pos > len`时`panic`。
+    pub fn set_cursor(&mut self, pos: usize) {
+        assert!(
+            pos <= self.len(),
+            "cursor position {pos} out of bounds (len is {})",
+            self.len()
+        );
+
+        let ptr = self.ptr();
+        unsafe {
+            if pos < self.gap_start {
+                let count = self.gap_start - pos;
+                ptr::copy(ptr.add(pos), ptr.add(self.gap_end - count), count);
+                self.gap_start -= count;
+                self.gap_end -= count;
+            } else if pos > self.gap_start {
+                let count = pos - self.gap_start;
+                ptr::copy(ptr.add(self.gap_end), ptr.add(self.gap_start), count);
+                self.gap_start += count;
+                self.gap_end += count;
+            }
+        }
+    }
+
+    /// 保证间隙中至少有`additional`个位置。
+    ///
+    /// [`RawBuf`]扩容时只会保证`0..old_cap`中的数据不变，因此扩容后
+    /// 我们需要将间隙之后的一段移动到新缓冲区的末尾，间隙的起点（光标）
+    /// 保持不变。
+    pub fn reserve(&mut self, additional: usize) {
+        if self.gap_len() >= additional {
+            return;
+        }
+
+        let old_cap = self.capacity();
+        let back_len = old_cap - self.gap_end;
+        // 按倍数增长，使得连续插入的均摊复杂度为`O(1)`
+        self.buf.reserve(self.len(), additional);
+        unsafe {
+            let new_gap_end = self.capacity() - back_len;
+            ptr::copy(
+                self.ptr().add(self.gap_end),
+                self.ptr().add(new_gap_end),
+                back_len,
+            );
+            self.gap_end = new_gap_end;
+        }
+    }
+
+    /// 在光标处插入`value`，光标随之后移。
+    pub fn insert(&mut self, value: T) {
+        self.reserve(1);
+        unsafe {
+            ptr::write(self.ptr().add(self.gap_start), value);
+        }
+        self.gap_start += 1;
+    }
+
+    /// 在光标处依次插入`values`的克隆，光标随之后移。
+    ///
+    /// 每写入一个元素就移动一次光标，因此即使`clone`发生了`panic`，已经
+    /// 写入的元素也都是有效的。
+    pub fn insert_slice(&mut self, values: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(values.len());
+        for value in values {
+            unsafe {
+                ptr::write(self.ptr().add(self.gap_start), value.clone());
+            }
+            self.gap_start += 1;
+        }
+    }
+
+    /// 移除并返回光标之后的元素（类似`Delete`键）。
+    pub fn remove(&mut self) -> Option<T> {
+        if self.gap_end == self.capacity() {
+            return None;
+        }
+        let value = unsafe { ptr::read(self.ptr().add(self.gap_end)) };
+        self.gap_end += 1;
+        Some(value)
+    }
+
+    /// 移除并返回光标之前的元素（类似`Backspace`键）。
+    pub fn remove_before(&mut self) -> Option<T> {
+        if self.gap_start == 0 {
+            return None;
+        }
+        self.gap_start -= 1;
+        Some(unsafe { ptr::read(self.ptr().add(self.gap_start)) })
+    }
+
+    /// 将逻辑下标转换为物理下标，要求`index < len`。
+    #[inline]
+    fn to_physical_idx(&self, index: usize) -> usize {
+        if index < self.gap_start {
+            index
+        } else {
+            index + self.gap_len()
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            unsafe { Some(&*self.ptr().add(self.to_physical_idx(index))) }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len() {
+            unsafe { Some(&mut *self.ptr().add(self.to_physical_idx(index))) }
+        } else {
+            None
+        }
+    }
+
+    /// 返回光标前后的两段。
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let ptr = self.ptr();
+        unsafe {
+            (
+                slice::from_raw_parts(ptr, self.gap_start),
+                slice::from_raw_parts(ptr.add(self.gap_end), self.capacity() - self.gap_end),
+            )
+        }
+    }
+
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let ptr = self.ptr();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr, self.gap_start),
+                slice::from_raw_parts_mut(ptr.add(self.gap_end), self.capacity() - self.gap_end),
+            )
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter::new(front.iter(), back.iter())
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut::new(front.iter_mut(), back.iter_mut())
+    }
+
+    /// 移除所有元素，保留容量。
+    ///
+    /// 与[`MyVecDeque::clear`]相同，先将间隙扩展到整个缓冲区，再`drop`
+    /// 两段元素。即使前一段的`drop`发生了`panic`，后一段也会被`drop`。
+    ///
+    /// [`MyVecDeque::clear`]: crate::collection::vec_deque::MyVecDeque::clear
+    pub fn clear(&mut self) {
+        struct Dropper<T>(*mut [T]);
+
+        impl<T> Drop for Dropper<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(self.0);
+                }
+            }
+        }
+
+        let (front, back) = self.as_mut_slices();
+        let front: *mut [T] = front;
+        let back: *mut [T] = back;
+        self.gap_start = 0;
+        self.gap_end = self.capacity();
+
+        unsafe {
+            let _back_dropper = Dropper(back);
+            ptr::drop_in_place(front);
+        }
+    }
+}
+
+impl<T> Drop for GapBuffer<T> {
+    /// 只`drop`间隙两侧的元素，内存由[`RawBuf`]释放。
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
+
+impl<T> Default for GapBuffer<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for GapBuffer<T> {
+    /// 克隆的结果中，光标位于相同的位置。
+    fn clone(&self) -> Self {
+        let (front, back) = self.as_slices();
+        let mut ret = Self::with_capacity(self.len());
+        ret.insert_slice(back);
+        ret.set_cursor(0);
+        ret.insert_slice(front);
+        ret
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for GapBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for GapBuffer<T> {
+    /// 只比较元素，不比较光标的位置。
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for GapBuffer<T> {}
+
+impl<T: PartialEq> PartialEq<[T]> for GapBuffer<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        let (front, back) = self.as_slices();
+        self.len() == other.len() && other[..front.len()] == *front && other[front.len()..] == *back
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for GapBuffer<T> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T> Index<usize> for GapBuffer<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("GapBuffer index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for GapBuffer<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("GapBuffer index out of bounds")
+    }
+}
+
+/// 在光标处依次插入。
+impl<T> Extend<T> for GapBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for GapBuffer<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<MyVec<T>>())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a GapBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut GapBuffer<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// 接管`MyVec`的缓冲区，光标位于末尾，不会复制元素。
+impl<T> From<MyVec<T>> for GapBuffer<T> {
+    fn from(value: MyVec<T>) -> Self {
+        let mut value = ManuallyDrop::new(value);
+        let (ptr, len, cap) = (value.as_mut_ptr(), value.len(), value.capacity());
+        Self {
+            buf: unsafe { RawBuf::from_raw_parts(ptr, cap) },
+            gap_start: len,
+            gap_end: cap,
+        }
+    }
+}
+
+/// 先将光标移动到末尾，使得元素连续，再将缓冲区交给`MyVec`。
+impl<T> From<GapBuffer<T>> for MyVec<T> {
+    fn from(mut value: GapBuffer<T>) -> Self {
+        value.set_cursor(value.len());
+        let value = ManuallyDrop::new(value);
+        let (ptr, len, cap) = (value.buf.ptr(), value.len(), value.capacity());
+        unsafe { MyVec::from_parts(ptr, len, cap) }
+    }
+}
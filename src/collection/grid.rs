@@ -0,0 +1,361 @@
+mod error;
+
+pub use error::DimensionError;
+
+use std::fmt;
+use std::iter::{self, FusedIterator};
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+use crate::collection::vec::MyVec;
+
+/// 按行优先（row-major）存储在[`MyVec`]中的二维网格。
+///
+/// 坐标`(x, y)`表示第`y`行第`x`列，对应的下标为`y * width + x`。
+///
+/// 所有构造函数都会使用[`usize::checked_mul`]检查`width * height`，
+/// 因此对于任何合法的坐标（`x < width`且`y < height`），下标的计算都
+/// 不会溢出。`width`和`height`都可以为0，此时网格中没有任何元素，但
+/// 仍然有`height`行（每一行都是空的）。
+///
+/// ```rust
+/// use rust_practice::collection::grid::Grid;
+///
+/// let mut grid = Grid::new(3, 2, 0);
+/// grid[(2, 1)] = 5;
+/// assert_eq!(grid.row(1), [0, 0, 5]);
+/// let grid = grid.transpose();
+/// assert_eq!((grid.width(), grid.height()), (2, 3));
+/// assert_eq!(grid[(1, 2)], 5);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    data: MyVec<T>,
+    width: usize,
+    height: usize,
+}
+
+#[inline]
+fn checked_area(width: usize, height: usize) -> Option<usize> {
+    width.checked_mul(height)
+}
+
+impl<T> Grid<T> {
+    /// ## Panics
+    ///
+    /// `width * height`溢出时`panic`。
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let len = checked_area(width, height).expect("grid dimensions overflow");
+        Self {
+            data: iter::repeat_n(fill, len).collect(),
+            width,
+            height,
+        }
+    }
+
+    /// 使用按行优先排列的`data`构造网格，`width * height`溢出或者不等于
+    /// `data.len()`时返回错误。
+    pub fn from_my_vec(
+        width: usize,
+        height: usize,
+        data: MyVec<T>,
+    ) -> Result<Self, DimensionError<T>> {
+        if checked_area(width, height) != Some(data.len()) {
+            return Err(DimensionError::new(data, width, height));
+        }
+        Ok(Self {
+            data,
+            width,
+            height,
+        })
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `(width, height)`
+    #[inline]
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    #[inline]
+    pub fn into_my_vec(self) -> MyVec<T> {
+        self.data
+    }
+
+    /// 坐标对应的下标，坐标越界时返回`None`。
+    #[inline]
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            y.checked_mul(self.width)?.checked_add(x)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        let index = self.index_of(x, y)?;
+        self.data.get(index)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        let index = self.index_of(x, y)?;
+        self.data.get_mut(index)
+    }
+
+    /// ## Panics
+    ///
+    /// `y >= height`时`panic`。
+    #[inline]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} out of bounds (height is {})",
+            self.height
+        );
+        let start = y * self.width;
+        &self.data[start..start + self.width]
+    }
+
+    /// ## Panics
+    ///
+    /// `y >= height`时`panic`。
+    #[inline]
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(
+            y < self.height,
+            "row {y} out of bounds (height is {})",
+            self.height
+        );
+        let start = y * self.width;
+        &mut self.data[start..start + self.width]
+    }
+
+    /// 依次产生每一行。
+    ///
+    /// 我们没有使用[`slice::chunks_exact`]，因为它不允许`width`为0。
+    #[inline]
+    pub fn rows(&self) -> Rows<'_, T> {
+        Rows {
+            data: &self.data,
+            width: self.width,
+            remaining: self.height,
+        }
+    }
+
+    /// 按行优先的顺序产生`((x, y), &value)`。
+    pub fn iter_with_coords(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + ExactSizeIterator {
+        let width = self.width;
+        // 网格非空时`width > 0`，因此不会除以0
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| ((i % width, i / width), value))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// 将每个元素都设置为`value`。
+    #[inline]
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Grid<U> {
+        Grid {
+            data: self.data.into_iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// 转置，`(x, y)`处的元素被移动到`(y, x)`，不需要`T: Clone`。
+    ///
+    /// 我们直接将元素写入新缓冲区中对应的位置，在所有元素写入之前不会
+    /// 设置长度。写入的过程中只有下标计算，不会发生`panic`，因此不会
+    /// 泄露或者重复`drop`元素。
+    pub fn transpose(self) -> Self {
+        let (width, height) = (self.width, self.height);
+        let len = self.data.len();
+        let mut data: MyVec<T> = MyVec::with_capacity(len);
+        let dst = data.as_mut_ptr();
+        for (i, value) in self.data.into_iter().enumerate() {
+            let (x, y) = (i % width, i / width);
+            unsafe {
+                dst.add(x * height + y).write(value);
+            }
+        }
+        unsafe {
+            data.set_len(len);
+        }
+
+        Self {
+            data,
+            width: height,
+            height: width,
+        }
+    }
+
+    /// 将网格调整为`width * height`，保留左上角重叠的部分，其余位置使
+    /// 用`fill`填充。
+    ///
+    /// 新旧网格都是按行优先存储的，重叠部分在两者中的相对顺序相同，因
+    /// 此我们只需要顺序地遍历一次旧的元素：保留重叠部分，`drop`其余部
+    /// 分，并在每一行的末尾补齐。
+    ///
+    /// 如果`fill.clone()`发生了`panic`，网格会变为空的`0 * 0`网格。
+    ///
+    /// ## Panics
+    ///
+    /// `width * height`溢出时`panic`。
+    pub fn resize(&mut self, width: usize, height: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let len = checked_area(width, height).expect("grid dimensions overflow");
+        let (old_width, old_height) = (self.width, self.height);
+        let mut old = mem::take(&mut self.data).into_iter();
+        self.width = 0;
+        self.height = 0;
+
+        let mut data = MyVec::with_capacity(len);
+        for y in 0..height {
+            if y < old_height {
+                for x in 0..old_width {
+                    let value = old.next().unwrap();
+                    if x < width {
+                        data.push(value);
+                    }
+                }
+            }
+            let kept = if y < old_height {
+                old_width.min(width)
+            } else {
+                0
+            };
+            data.extend(iter::repeat_n(&fill, width - kept).cloned());
+        }
+        drop(old);
+
+        self.data = data;
+        self.width = width;
+        self.height = height;
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.get(x, y) {
+            Some(value) => value,
+            None => panic!(
+                "grid index ({x}, {y}) out of bounds ({}x{})",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(value) => value,
+            None => panic!("grid index ({x}, {y}) out of bounds ({width}x{height})"),
+        }
+    }
+}
+
+/// 每一行作为一个列表输出。
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+/// [`Grid::rows`]返回的迭代器，`remaining`为尚未产生的行数。
+#[derive(Clone, Debug)]
+pub struct Rows<'a, T> {
+    data: &'a [T],
+    width: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (row, rest) = self.data.split_at(self.width);
+        self.data = rest;
+        self.remaining -= 1;
+        Some(row)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (rest, row) = self.data.split_at(self.data.len() - self.width);
+        self.data = rest;
+        self.remaining -= 1;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+
+impl<T> FusedIterator for Rows<'_, T> {}
@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::collection::vec::MyVec;
+
+/// [`Grid::from_my_vec`]失败时产生的错误，即`width * height`溢出或者
+/// 不等于数据的长度。
+///
+/// 与[`FromUtf8Error`]相同，传入的数据会被返还给调用者。
+///
+/// [`Grid::from_my_vec`]: crate::collection::grid::Grid::from_my_vec
+/// [`FromUtf8Error`]: crate::collection::string::FromUtf8Error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionError<T> {
+    data: MyVec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> DimensionError<T> {
+    #[inline]
+    pub(super) fn new(data: MyVec<T>, width: usize, height: usize) -> Self {
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    pub fn into_data(self) -> MyVec<T> {
+        self.data
+    }
+}
+
+impl<T> fmt::Display for DimensionError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "grid of {}x{} does not match data of length {}",
+            self.width,
+            self.height,
+            self.data.len()
+        )
+    }
+}
+
+impl<T: fmt::Debug> Error for DimensionError<T> {}
@@ -0,0 +1,316 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::{Index, IndexMut};
+use std::{cmp, ptr, slice};
+
+use crate::collection::vec_deque::{Iter, IterMut};
+
+/// 类似[`MyVecDeque`]，但是使用预先分配好的N个元素的缓冲区，不会
+/// 动态扩容，也不会申请堆内存。
+///
+/// 与[`InplaceVec`]相同，缓冲区为`[MaybeUninit<T>; N]`，额外记录了
+/// 队首元素的物理位置`head`，第`i`个元素位于`(head + i) % N`。由于
+/// `head < N`且`i <= N`，计算物理位置时只需要一次减法，而不需要取模，
+/// 因此对于任意的N（而不仅仅是2的幂）都是正确的。
+///
+/// 容量已满时，[`InplaceDeque::push_back`]和[`InplaceDeque::push_front`]
+/// 会将元素通过`Err`返还，而不是`panic`。
+///
+/// ```rust
+/// use rust_practice::collection::inplace_deque::InplaceDeque;
+///
+/// let mut deque = InplaceDeque::<3, _>::new();
+/// deque.push_back(1).unwrap();
+/// deque.push_back(2).unwrap();
+/// deque.push_front(0).unwrap();
+/// assert_eq!(deque.as_slices(), (&[0][..], &[1, 2][..]));
+/// assert_eq!(deque.push_back(3), Err(3));
+/// assert_eq!(deque.pop_front(), Some(0));
+/// assert_eq!(deque, [1, 2]);
+/// ```
+///
+/// [`MyVecDeque`]: crate::collection::vec_deque::MyVecDeque
+/// [`InplaceVec`]: crate::collection::inplace_vec::InplaceVec
+pub struct InplaceDeque<const N: usize, T> {
+    buf: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize, T> InplaceDeque<N, T> {
+    pub const fn new() -> Self {
+        Self {
+            buf: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.buf.as_ptr().cast()
+    }
+
+    #[inline]
+    fn mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr().cast()
+    }
+
+    /// 将逻辑下标转换为物理下标，要求`idx <= N`。
+    #[inline]
+    fn to_physical_idx(&self, idx: usize) -> usize {
+        let idx = self.head + idx;
+        if idx >= N { idx - N } else { idx }
+    }
+
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        let idx = self.to_physical_idx(self.len);
+        self.buf[idx].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        // 此处`N > 0`
+        self.head = if self.head == 0 { N - 1 } else { self.head - 1 };
+        self.buf[self.head].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let idx = self.to_physical_idx(self.len);
+        unsafe { Some(self.buf[idx].assume_init_read()) }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let old_head = self.head;
+        self.head = self.to_physical_idx(1);
+        self.len -= 1;
+        unsafe { Some(self.buf[old_head].assume_init_read()) }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            unsafe { Some(self.buf[self.to_physical_idx(index)].assume_init_ref()) }
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            let idx = self.to_physical_idx(index);
+            unsafe { Some(self.buf[idx].assume_init_mut()) }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// 返回两段元素的物理范围，与[`MyVecDeque`]相同。
+    ///
+    /// [`MyVecDeque`]: crate::collection::vec_deque::MyVecDeque
+    #[inline]
+    fn slice_ranges(&self) -> (usize, usize, usize) {
+        let head_len = cmp::min(self.len, N - self.head);
+        (self.head, head_len, self.len - head_len)
+    }
+
+    /// 按顺序返回两个切片，它们连接起来就是队列中的所有元素。
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (head, head_len, tail_len) = self.slice_ranges();
+        unsafe {
+            (
+                slice::from_raw_parts(self.ptr().add(head), head_len),
+                slice::from_raw_parts(self.ptr(), tail_len),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (head, head_len, tail_len) = self.slice_ranges();
+        let ptr = self.mut_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr.add(head), head_len),
+                slice::from_raw_parts_mut(ptr, tail_len),
+            )
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (a, b) = self.as_slices();
+        Iter::new(a.iter(), b.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (a, b) = self.as_mut_slices();
+        IterMut::new(a.iter_mut(), b.iter_mut())
+    }
+
+    /// 详细说明见[`MyVecDeque::clear`]
+    ///
+    /// [`MyVecDeque::clear`]: crate::collection::vec_deque::MyVecDeque::clear
+    pub fn clear(&mut self) {
+        struct Dropper<T>(*mut [T]);
+
+        impl<T> Drop for Dropper<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(self.0);
+                }
+            }
+        }
+
+        let (front, back) = self.as_mut_slices();
+        let front: *mut [T] = front;
+        let back: *mut [T] = back;
+        self.head = 0;
+        self.len = 0;
+
+        unsafe {
+            let _back_dropper = Dropper(back);
+            ptr::drop_in_place(front);
+        }
+    }
+}
+
+impl<const N: usize, T> Default for InplaceDeque<N, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T> Drop for InplaceDeque<N, T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<const N: usize, T> Index<usize> for InplaceDeque<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("InplaceDeque index out of bounds")
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for InplaceDeque<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index)
+            .expect("InplaceDeque index out of bounds")
+    }
+}
+
+impl<const N: usize, T: fmt::Debug> fmt::Debug for InplaceDeque<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const N: usize, T: Clone> Clone for InplaceDeque<N, T> {
+    fn clone(&self) -> Self {
+        let mut ret = Self::new();
+        for elem in self.iter() {
+            // 容量相同，不会失败
+            let _ = ret.push_back(elem.clone());
+        }
+        ret
+    }
+}
+
+impl<const N: usize, T: PartialEq> PartialEq for InplaceDeque<N, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<const N: usize, T: Eq> Eq for InplaceDeque<N, T> {}
+
+impl<const N: usize, T: PartialEq, const M: usize> PartialEq<[T; M]> for InplaceDeque<N, T> {
+    fn eq(&self, other: &[T; M]) -> bool {
+        self.len == M && self.iter().eq(other.iter())
+    }
+}
+
+impl<const N: usize, T: PartialEq> PartialEq<[T]> for InplaceDeque<N, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a InplaceDeque<N, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a mut InplaceDeque<N, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,375 @@
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::slice;
+
+use crate::collection::inplace_vec::{self, InplaceVec};
+
+/// 容量固定为`N`的二叉堆（大顶堆），使用[`InplaceVec<N, T>`]存储元素，不
+/// 会申请堆内存。
+///
+/// 与[`std::collections::BinaryHeap`]相同，[`InplaceHeap::pop`]总是返回最
+/// 大的元素。如果需要小顶堆，请使用[`std::cmp::Reverse`]包装元素。
+///
+/// 容量已满时，[`InplaceHeap::push`]会将元素通过`Err`返还，而不是`panic`。
+///
+/// ```rust
+/// use rust_practice::collection::inplace_heap::InplaceHeap;
+///
+/// let mut heap = InplaceHeap::<3, _>::new();
+/// heap.push(2).unwrap();
+/// heap.push(5).unwrap();
+/// heap.push(1).unwrap();
+/// assert_eq!(heap.push(4), Err(4));
+/// assert_eq!(heap.peek(), Some(&5));
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.into_sorted_inplace_vec(), [1, 2]);
+/// ```
+pub struct InplaceHeap<const N: usize, T> {
+    data: InplaceVec<N, T>,
+}
+
+impl<const N: usize, T> InplaceHeap<N, T> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: InplaceVec::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.data.len() == N
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// 返回最大的元素。
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// 以堆的内部顺序返回所有元素。
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// 以堆的内部顺序迭代所有元素。
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// 以堆的内部顺序返回所有元素。
+    #[inline]
+    pub fn into_inplace_vec(self) -> InplaceVec<N, T> {
+        self.data
+    }
+}
+
+impl<const N: usize, T: Ord> InplaceHeap<N, T> {
+    /// 插入元素，时间复杂度为`O(log n)`。容量已满时返还该元素。
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        let old_len = self.len();
+        self.data.push(value);
+        // SAFETY: `old_len < self.len()`
+        unsafe {
+            sift_up(&mut self.data, 0, old_len);
+        }
+        Ok(())
+    }
+
+    /// 移除并返回最大的元素，时间复杂度为`O(log n)`。
+    pub fn pop(&mut self) -> Option<T> {
+        let mut item = self.data.pop()?;
+        if !self.is_empty() {
+            std::mem::swap(&mut item, &mut self.data[0]);
+            // SAFETY: 堆不为空
+            unsafe {
+                sift_down(&mut self.data, 0);
+            }
+        }
+        Some(item)
+    }
+
+    /// 插入`value`，然后移除并返回最大的元素，相当于先`push`再`pop`，但
+    /// 只需要一次下沉，且即使容量已满也可以使用。
+    ///
+    /// 如果`value`不小于堆顶（或者堆为空），则直接返回`value`，堆不变。
+    /// 因此对一个已满的堆反复调用`push_pop`，堆中始终保留的是目前为止最小
+    /// 的`N`个元素。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::inplace_heap::InplaceHeap;
+    ///
+    /// let mut heap = InplaceHeap::<2, _>::from_iter([3, 8]);
+    /// assert_eq!(heap.push_pop(9), 9);
+    /// assert_eq!(heap.push_pop(1), 8);
+    /// assert_eq!(heap.into_sorted_inplace_vec(), [1, 3]);
+    /// ```
+    pub fn push_pop(&mut self, mut value: T) -> T {
+        match self.data.first_mut() {
+            Some(top) if *top > value => {
+                std::mem::swap(top, &mut value);
+                // SAFETY: 堆不为空
+                unsafe {
+                    sift_down(&mut self.data, 0);
+                }
+                value
+            }
+            _ => value,
+        }
+    }
+
+    /// 返回按升序排列的所有元素（堆排序），不需要额外的空间。
+    pub fn into_sorted_inplace_vec(mut self) -> InplaceVec<N, T> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            self.data.swap(0, end);
+            // SAFETY: `0 < end <= self.len()`
+            unsafe {
+                sift_down_range(&mut self.data, 0, end);
+            }
+        }
+        self.data
+    }
+
+    /// 自底向上建堆，时间复杂度为`O(n)`。
+    fn rebuild(&mut self) {
+        let mut n = self.len() / 2;
+        while n > 0 {
+            n -= 1;
+            // SAFETY: `n < self.len()`
+            unsafe {
+                sift_down(&mut self.data, n);
+            }
+        }
+    }
+}
+
+/// 源自标准库的`BinaryHeap`
+///
+/// 在上浮或下沉的过程中，我们先把当前元素取出，留下一个“空洞”，然后只移动
+/// 其他元素来填补空洞，最后再把当前元素写入空洞的位置。与每次交换两个元素
+/// 相比，这样的移动次数减少了一半。
+///
+/// 比较元素时调用的[`Ord`]可能会`panic`，因此我们在`Hole`的`drop`中把取
+/// 出的元素写回，保证切片中的每个元素仍然恰好出现一次。
+struct Hole<'a, T> {
+    data: &'a mut [T],
+    elt: ManuallyDrop<T>,
+    pos: usize,
+}
+
+impl<'a, T> Hole<'a, T> {
+    /// ## Safety
+    ///
+    /// `pos`必须小于`data.len()`。
+    #[inline]
+    unsafe fn new(data: &'a mut [T], pos: usize) -> Self {
+        debug_assert!(pos < data.len());
+        let elt = unsafe { ptr::read(data.get_unchecked(pos)) };
+        Self {
+            data,
+            elt: ManuallyDrop::new(elt),
+            pos,
+        }
+    }
+
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn element(&self) -> &T {
+        &self.elt
+    }
+
+    /// ## Safety
+    ///
+    /// `index`必须小于`data.len()`且不等于`pos`。
+    #[inline]
+    unsafe fn get(&self, index: usize) -> &T {
+        debug_assert!(index != self.pos && index < self.data.len());
+        unsafe { self.data.get_unchecked(index) }
+    }
+
+    /// 把`index`处的元素移动到空洞中，空洞随之移动到`index`。
+    ///
+    /// ## Safety
+    ///
+    /// 与[`Hole::get`]相同。
+    #[inline]
+    unsafe fn move_to(&mut self, index: usize) {
+        debug_assert!(index != self.pos && index < self.data.len());
+        unsafe {
+            let ptr = self.data.as_mut_ptr();
+            ptr::copy_nonoverlapping(ptr.add(index), ptr.add(self.pos), 1);
+        }
+        self.pos = index;
+    }
+}
+
+impl<T> Drop for Hole<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let pos = self.pos;
+            ptr::copy_nonoverlapping(&*self.elt, self.data.get_unchecked_mut(pos), 1);
+        }
+    }
+}
+
+/// 将`pos`处的元素上浮，不会越过`start`。返回元素的新位置。
+///
+/// ## Safety
+///
+/// `start <= pos < data.len()`。
+unsafe fn sift_up<T: Ord>(data: &mut [T], start: usize, pos: usize) -> usize {
+    let mut hole = unsafe { Hole::new(data, pos) };
+    while hole.pos() > start {
+        let parent = (hole.pos() - 1) / 2;
+        if hole.element() <= unsafe { hole.get(parent) } {
+            break;
+        }
+        unsafe {
+            hole.move_to(parent);
+        }
+    }
+    hole.pos()
+}
+
+/// 将`pos`处的元素下沉，只考虑`[0, end)`范围内的元素。
+///
+/// ## Safety
+///
+/// `pos < end <= data.len()`。
+unsafe fn sift_down_range<T: Ord>(data: &mut [T], pos: usize, end: usize) {
+    let mut hole = unsafe { Hole::new(data, pos) };
+    let mut child = 2 * hole.pos() + 1;
+    while child < end {
+        // 选择较大的子节点
+        if child + 1 < end && unsafe { hole.get(child) <= hole.get(child + 1) } {
+            child += 1;
+        }
+        if hole.element() >= unsafe { hole.get(child) } {
+            return;
+        }
+        unsafe {
+            hole.move_to(child);
+        }
+        child = 2 * hole.pos() + 1;
+    }
+}
+
+/// ## Safety
+///
+/// `pos < data.len()`。
+#[inline]
+unsafe fn sift_down<T: Ord>(data: &mut [T], pos: usize) {
+    let len = data.len();
+    unsafe {
+        sift_down_range(data, pos, len);
+    }
+}
+
+impl<const N: usize, T> Default for InplaceHeap<N, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: Clone> Clone for InplaceHeap<N, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<const N: usize, T: fmt::Debug> fmt::Debug for InplaceHeap<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// 对已有的元素建堆，时间复杂度为`O(n)`。
+impl<const N: usize, T: Ord> From<InplaceVec<N, T>> for InplaceHeap<N, T> {
+    fn from(data: InplaceVec<N, T>) -> Self {
+        let mut heap = Self { data };
+        heap.rebuild();
+        heap
+    }
+}
+
+/// 与[`InplaceVec`]的`FromIterator`不同，元素多于`N`个时不会`panic`，而
+/// 是只保留**最小**的`N`个元素：先用前`N`个元素建堆，之后的每个元素都通过
+/// [`InplaceHeap::push_pop`]与堆顶比较，较大者被`drop`。
+///
+/// 这与将所有元素排序后截取前`N`个的结果相同，但只需要`O(N)`的空间。如果
+/// 需要保留最大的`N`个元素，请使用[`std::cmp::Reverse`]包装元素。
+///
+/// ```rust
+/// use rust_practice::collection::inplace_heap::InplaceHeap;
+///
+/// let heap: InplaceHeap<3, _> = [5, 1, 9, 3, 7, 2].into_iter().collect();
+/// assert_eq!(heap.into_sorted_inplace_vec(), [1, 2, 3]);
+/// ```
+impl<const N: usize, T: Ord> FromIterator<T> for InplaceHeap<N, T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut heap = Self::from(InplaceVec::from_iter_truncated(iter.by_ref()));
+        for value in iter {
+            drop(heap.push_pop(value));
+        }
+        heap
+    }
+}
+
+/// 以堆的内部顺序迭代所有元素。
+impl<const N: usize, T> IntoIterator for InplaceHeap<N, T> {
+    type Item = T;
+    type IntoIter = inplace_vec::IntoIter<N, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a InplaceHeap<N, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
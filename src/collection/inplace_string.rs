@@ -0,0 +1,292 @@
+use std::borrow::Borrow;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+use std::str::{self, FromStr};
+use std::{cmp, fmt};
+
+use crate::collection::inplace_vec::{CapacityError, InplaceVec};
+
+/// 类似[`MyString`]，但是使用[`InplaceVec<N, u8>`]存储字节，不会申请
+/// 堆内存，容量为`N`个字节。
+///
+/// 与`MyString`相同，`InplaceString`始终保证其中的字节是合法的UTF-8
+/// 序列。写入时我们只会整体地写入一个字符串（或一个字符），如果剩
+/// 余的容量不足，则什么也不写入，因此缓冲区中永远不会出现被截断的
+/// 多字节序列。
+///
+/// 实现了[`fmt::Write`]，因此可以使用[`write!`]进行格式化：
+///
+/// ```rust
+/// use rust_practice::collection::inplace_string::InplaceString;
+/// use std::fmt::Write;
+///
+/// let mut s = InplaceString::<8>::new();
+/// write!(s, "{}+{}", 1, 2).unwrap();
+/// assert_eq!(s, "1+2");
+/// let tail = "三倍";
+/// // 写入"="之后剩余4个字节，不足以容纳`tail`的6个字节
+/// assert!(write!(s, "={tail}").is_err());
+/// assert_eq!(s, "1+2=");
+/// ```
+///
+/// 注意上面的例子中，`write!`会将格式化的结果分为多次写入，每一次
+/// 写入都是原子的，但整个`write!`并不是。
+///
+/// [`MyString`]: crate::collection::string::MyString
+#[derive(Clone, Default)]
+pub struct InplaceString<const N: usize> {
+    vec: InplaceVec<N, u8>,
+}
+
+impl<const N: usize> InplaceString<N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            vec: InplaceVec::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.vec.as_slice()) }
+    }
+
+    #[inline]
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(self.vec.as_mut_slice()) }
+    }
+
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.vec.as_slice()
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// 剩余可以写入的字节数。
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.vec.len()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// 尝试写入`string`，剩余容量不足时返回错误，且不会写入任何字节。
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        if string.len() > self.remaining_capacity() {
+            return Err(CapacityError::new((), self.len() + string.len(), N));
+        }
+        self.vec.extend_from_slice(string.as_bytes());
+        Ok(())
+    }
+
+    /// 尝试写入`ch`，剩余容量不足时将其通过错误返还。
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError<char>> {
+        match self.try_push_str(ch.encode_utf8(&mut [0; 4])) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(CapacityError::new(ch, e.required(), e.capacity())),
+        }
+    }
+
+    /// ## Panics
+    ///
+    /// 剩余容量不足时`panic`。
+    pub fn push_str(&mut self, string: &str) {
+        if let Err(e) = self.try_push_str(string) {
+            panic!("{e}");
+        }
+    }
+
+    /// ## Panics
+    ///
+    /// 剩余容量不足时`panic`。
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// 移除并返回最后一个字符。
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+        unsafe {
+            self.vec.set_len(new_len);
+        }
+        Some(ch)
+    }
+
+    /// 将长度缩短为`new_len`个字节，如果`new_len`大于当前长度，则什么
+    /// 也不做。
+    ///
+    /// ## Panics
+    ///
+    /// `new_len`不在字符的边界上时`panic`。
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            assert!(self.is_char_boundary(new_len));
+            unsafe {
+                self.vec.set_len(new_len);
+            }
+        }
+    }
+}
+
+impl<const N: usize> Deref for InplaceString<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> DerefMut for InplaceString<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> fmt::Display for InplaceString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for InplaceString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// 容量不足时返回[`fmt::Error`]，且不会写入任何字节。
+impl<const N: usize> fmt::Write for InplaceString<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+/// 与[`InplaceVec`]相同，由于标准库中的blanket implementation，我们
+/// 无法同时实现`From<&str>`和`TryFrom<&str>`，因此只提供可能失败的
+/// 转换。
+impl<const N: usize> TryFrom<&str> for InplaceString<N> {
+    type Error = CapacityError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut ret = Self::new();
+        ret.try_push_str(value)?;
+        Ok(ret)
+    }
+}
+
+/// 使得可以使用[`str::parse`]构造`InplaceString`。
+impl<const N: usize> FromStr for InplaceString<N> {
+    type Err = CapacityError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl<const N: usize> PartialEq for InplaceString<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for InplaceString<N> {}
+
+impl<const N: usize> PartialEq<str> for InplaceString<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for InplaceString<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<InplaceString<N>> for str {
+    #[inline]
+    fn eq(&self, other: &InplaceString<N>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<InplaceString<N>> for &str {
+    #[inline]
+    fn eq(&self, other: &InplaceString<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialOrd for InplaceString<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for InplaceString<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for InplaceString<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> AsRef<str> for InplaceString<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for InplaceString<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<str> for InplaceString<N> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
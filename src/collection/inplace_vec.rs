@@ -1,15 +1,38 @@
 mod drain;
+mod error;
+mod extract_if;
+#[cfg(feature = "heapless")]
+mod heapless_impl;
+mod inplace_vec_macro;
 mod into_iter;
+mod len;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod splice;
+mod write;
 
 pub use drain::Drain;
+pub use error::CapacityError;
+pub use extract_if::ExtractIf;
 pub use into_iter::IntoIter;
+pub use len::LenType;
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::ArchivedInplaceVec;
+pub use splice::Splice;
 
 use std::borrow::{Borrow, BorrowMut};
+use std::convert::Infallible;
+use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::mem::MaybeUninit;
-use std::ops::{Deref, DerefMut};
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::{Deref, DerefMut, RangeBounds};
 use std::{cmp, ptr, slice};
 
+use crate::collection;
+use crate::collection::vec::MyVec;
+
 /// 类似[`Vec`]，但是预先分配好N个元素的缓冲区，且不会动态扩容。
 ///
 /// ```rust
@@ -26,35 +49,97 @@ use std::{cmp, ptr, slice};
 /// `InplaceVec`的内存是自动释放的，因此在使用`*(ptr.offset(1))`时，
 /// 内存仍然有效，而[`i32`]的[`drop`]什么都不做，因此这段代码完全合
 /// 法。
-#[derive(Debug)]
-pub struct InplaceVec<const N: usize, T> {
+///
+/// 长度的类型`L`默认为[`usize`]，当`N`较小时可以指定更小的类型来减少
+/// 内存占用，详见[`LenType`]。
+///
+/// 默认值没有选择更小的类型，是因为任何更小的类型都会让容量较大的
+/// `InplaceVec`（例如`InplaceVec<100_000, u8>`）无法通过编译，而`L`又
+/// 无法根据`N`自动选择。因此对于小容量、小元素的`InplaceVec`，长度往往
+/// 比缓冲区本身还大，在意内存占用时应当显式地指定`L`：
+///
+/// ```rust
+/// use rust_practice::collection::inplace_vec::InplaceVec;
+/// use std::mem::size_of;
+///
+/// assert_eq!(size_of::<InplaceVec<4, u8>>(), 2 * size_of::<usize>());
+/// assert_eq!(size_of::<InplaceVec<4, u8, u8>>(), 5);
+/// ```
+pub struct InplaceVec<const N: usize, T, L: LenType = usize> {
     buf: [MaybeUninit<T>; N],
-    len: usize,
+    len: L,
 }
 
-impl<T, const N: usize> InplaceVec<N, T> {
+impl<T, const N: usize, L: LenType> InplaceVec<N, T, L> {
     pub const fn new() -> Self {
+        const {
+            assert!(
+                N <= L::MAX,
+                "InplaceVec capacity exceeds the range of its length type"
+            )
+        };
+
         Self {
             // 在此我们使用inline const pattern (RFC 2920)，这样T就无须是Copy的。
             // 可见[rust-lang/rust#76001](https://github.com/rust-lang/rust/issues/76001)
             buf: [const { MaybeUninit::uninit() }; N],
-            len: 0,
+            len: len::from_usize(0),
+        }
+    }
+
+    /// 构造一个已满的`InplaceVec`，第`i`个元素为`f(i)`。
+    ///
+    /// 每写入一个元素都会立即更新长度，因此即使`f`发生了`panic`，已经
+    /// 初始化的元素也会被正确地`drop`。
+    pub fn full_with<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        let mut ret = Self::new();
+        for i in 0..N {
+            ret.buf[i].write(f(i));
+            ret.store_len(ret.len() + 1);
         }
+        ret
+    }
+
+    /// 将数组中的元素移动到`InplaceVec`中，要求`M <= N`。
+    ///
+    /// 与[`InplaceVec::grow_capacity`]相同，我们使用inline const在单态化
+    /// 时检查`M <= N`，因此容量不足会产生编译错误。该函数是`const`的，
+    /// 因此可以用于常量上下文，[`inplace_vec!`]宏也依赖于该函数。
+    ///
+    /// [`inplace_vec!`]: crate::inplace_vec
+    pub const fn from_array<const M: usize>(arr: [T; M]) -> Self {
+        const { assert!(M <= N, "InplaceVec overflow: array is longer than capacity") };
+
+        let arr = ManuallyDrop::new(arr);
+        let mut ret = Self::new();
+        unsafe {
+            // `ManuallyDrop<[T; M]>`与`[T; M]`的内存布局相同
+            ptr::copy_nonoverlapping((&raw const arr).cast::<T>(), ret.as_mut_ptr(), M);
+        }
+        ret.store_len(M);
+        ret
     }
 
     #[inline]
     pub const fn as_slice(&self) -> &[T] {
-        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
 
     #[inline]
     pub const fn as_mut_slice(&mut self) -> &mut [T] {
-        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
     }
 
     #[inline]
     pub const fn len(&self) -> usize {
-        self.len
+        len::to_usize(&self.len)
+    }
+
+    /// 更新长度，所有对长度的修改都应该通过该函数进行。
+    #[inline]
+    const fn store_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= N);
+        self.len = len::from_usize(new_len);
     }
 
     #[inline]
@@ -64,28 +149,46 @@ impl<T, const N: usize> InplaceVec<N, T> {
 
     #[inline]
     pub const fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
+    }
+
+    /// 返回缓冲区中尚未使用的部分，即`buf[len..N]`。
+    ///
+    /// 写入数据之后，需要使用[`InplaceVec::set_len`]来更新长度。
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let len = self.len();
+        &mut self.buf[len..]
+    }
+
+    /// ## Safety
+    ///
+    /// - `new_len`不应该超过`N`
+    /// - `old_len..new_len`的元素必须被初始化
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.store_len(new_len);
     }
 
     #[inline]
     fn overflow_check(&self) {
-        if self.len >= N {
+        if self.len() >= N {
             panic!("InplaceVec overflow");
         }
     }
 
     pub fn push(&mut self, value: T) {
         self.overflow_check();
-        self.buf[self.len].write(value);
-        self.len += 1;
+        self.buf[self.len()].write(value);
+        self.store_len(self.len() + 1);
     }
 
     pub fn pop(&mut self) -> Option<T> {
-        if self.len == 0 {
+        if self.is_empty() {
             None
         } else {
-            self.len -= 1;
-            unsafe { Some(self.buf[self.len].assume_init_read()) }
+            self.store_len(self.len() - 1);
+            unsafe { Some(self.buf[self.len()].assume_init_read()) }
         }
     }
 
@@ -102,40 +205,59 @@ impl<T, const N: usize> InplaceVec<N, T> {
 
     pub fn insert(&mut self, index: usize, value: T) {
         self.overflow_check();
-        assert!(index <= self.len, "InplaceVec insert index out of bounds");
+        assert!(index <= self.len(), "InplaceVec insert index out of bounds");
 
         unsafe {
             ptr::copy(
                 self.as_ptr().add(index),
                 self.as_mut_ptr().add(index + 1),
-                self.len - index,
+                self.len() - index,
             )
         }
         self.buf[index].write(value);
 
-        self.len += 1;
+        self.store_len(self.len() + 1);
     }
 
     pub fn remove(&mut self, index: usize) -> T {
-        assert!(index < self.len, "InplaceVec remove index out of bounds");
+        assert!(index < self.len(), "InplaceVec remove index out of bounds");
         unsafe {
-            self.len -= 1;
+            self.store_len(self.len() - 1);
             let result = self.buf[index].assume_init_read();
             ptr::copy(
                 self.as_ptr().add(index + 1),
                 self.as_mut_ptr().add(index),
-                self.len - index,
+                self.len() - index,
             );
             result
         }
     }
 
+    /// 移除`index`处的元素，并用最后一个元素填补，不保持元素的顺序，
+    /// 时间复杂度为`O(1)`。
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len(),
+            "InplaceVec swap_remove index out of bounds"
+        );
+        unsafe {
+            let last = self.len() - 1;
+            self.store_len(last);
+            let result = self.buf[index].assume_init_read();
+            // `index == last`时两者重叠，但此时复制的是同一个位置，没有影响
+            ptr::copy(self.as_ptr().add(last), self.as_mut_ptr().add(index), 1);
+            result
+        }
+    }
+
     pub fn clear(&mut self) {
         let drop_array: *mut [T] = self.as_mut_slice();
 
         unsafe {
-            self.len = 0;
-            ptr::drop_in_place(drop_array);
+            self.store_len(0);
+            if mem::needs_drop::<T>() {
+                ptr::drop_in_place(drop_array);
+            }
         }
     }
 
@@ -143,21 +265,230 @@ impl<T, const N: usize> InplaceVec<N, T> {
         for elem in iter {
             self.overflow_check();
             unsafe {
-                let ptr = self.as_mut_ptr().add(self.len);
+                let ptr = self.as_mut_ptr().add(self.len());
                 ptr::write(ptr, elem);
-                self.len += 1;
+                self.store_len(self.len() + 1);
             }
         }
     }
+
+    /// 从迭代器中至多取出`N`个元素构造`InplaceVec`，超出容量的部分不会
+    /// 被消费。
+    ///
+    /// 与[`FromIterator`]不同，该函数永远不会因为溢出而`panic`，即使传入
+    /// 的是一个无限迭代器。
+    pub fn from_iter_truncated<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        for elem in iter.into_iter().take(N) {
+            unsafe {
+                let ptr = ret.as_mut_ptr().add(ret.len());
+                ptr::write(ptr, elem);
+                ret.store_len(ret.len() + 1);
+            }
+        }
+        ret
+    }
+
+    /// 从迭代器中构造`InplaceVec`，如果迭代器中的元素多于`N`个，则返回
+    /// [`CapacityError`]。
+    ///
+    /// 该函数至多消费迭代器中的`N + 1`个元素：在填满`N`个元素之后，会再
+    /// 尝试取出一个元素，如果存在，则说明容量不足。此时已经取出的所有元素
+    /// （包括多取出的那一个）都会被`drop`，剩余部分保留在迭代器中。
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError> {
+        let mut iter = iter.into_iter();
+        let ret = Self::from_iter_truncated(iter.by_ref());
+        match iter.next() {
+            None => Ok(ret),
+            Some(_) => Err(CapacityError::new((), N.saturating_add(1), N)),
+        }
+    }
+
+    /// 将`other`中的所有元素移动到`self`的末尾，并清空`other`。
+    ///
+    /// ## Panics
+    ///
+    /// 如果`self.len() + other.len()`超过`N`，则会`panic`，此时不会移动
+    /// 任何元素。
+    pub fn append<const M: usize, L2: LenType>(&mut self, other: &mut InplaceVec<M, T, L2>) {
+        if let Err(err) = self.try_append(other) {
+            panic!("{err}");
+        }
+    }
+
+    /// 与[`InplaceVec::append`]相同，但容量不足时返回[`CapacityError`]，
+    /// 此时`self`和`other`都保持原样。
+    ///
+    /// 元素通过一次[`ptr::copy_nonoverlapping`]移动，然后直接将`other`
+    /// 的长度设置为0，因此不会`drop`任何元素。
+    pub fn try_append<const M: usize, L2: LenType>(
+        &mut self,
+        other: &mut InplaceVec<M, T, L2>,
+    ) -> Result<(), CapacityError> {
+        let count = other.len();
+        if count > N - self.len() {
+            return Err(CapacityError::new((), self.len() + count, N));
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.as_mut_ptr().add(self.len()), count);
+        }
+        other.store_len(0);
+        self.store_len(self.len() + count);
+        Ok(())
+    }
+
+    /// 将元素移动到一个容量为`M`的`InplaceVec`中。
+    ///
+    /// 只要`self.len() <= M`就会成功，元素通过一次
+    /// [`ptr::copy_nonoverlapping`]移动，原来的`InplaceVec`会被forget。
+    /// 否则原样返回`Err(self)`。
+    pub fn into_capacity<const M: usize>(self) -> Result<InplaceVec<M, T, L>, Self> {
+        if self.len() > M {
+            return Err(self);
+        }
+
+        // 元素的所有权被转移到`ret`中，因此不能再`drop`它们
+        let this = ManuallyDrop::new(self);
+        let mut ret = InplaceVec::<M, T, L>::new();
+        unsafe {
+            ptr::copy_nonoverlapping(this.as_ptr(), ret.as_mut_ptr(), this.len());
+        }
+        ret.store_len(this.len());
+        Ok(ret)
+    }
+
+    /// 将元素移动到一个更大（或相同）容量的`InplaceVec`中。
+    ///
+    /// 由于在stable中无法将`M >= N`写为trait bound，因此我们使用
+    /// inline const在单态化时检查，如果`M < N`会产生编译错误，而非运
+    /// 行时`panic`，所以该函数永远不会失败。
+    ///
+    /// ```rust,compile_fail
+    /// use rust_practice::collection::inplace_vec::InplaceVec;
+    ///
+    /// let vec: InplaceVec<4, i32> = InplaceVec::new();
+    /// let _: InplaceVec<2, i32> = vec.grow_capacity();
+    /// ```
+    pub fn grow_capacity<const M: usize>(self) -> InplaceVec<M, T, L> {
+        const { assert!(M >= N, "grow_capacity requires M >= N") };
+        match self.into_capacity() {
+            Ok(ret) => ret,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// 类似[`array::map`]，将每个元素转换为`U`，得到一个长度相同的
+    /// `InplaceVec<N, U>`。
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> InplaceVec<N, U, L> {
+        match self.try_map(|elem| Ok::<U, Infallible>(f(elem))) {
+            Ok(ret) => ret,
+        }
+    }
+
+    /// 依次对每个元素调用`f`，一旦`f`返回错误就立即停止并返回该错误。
+    ///
+    /// 此处我们不需要手动维护`MaybeUninit`的状态：
+    ///
+    /// - 尚未被消费的`T`由[`IntoIter`]持有，提前返回或者`f`发生`panic`
+    ///   时，由`IntoIter`的析构函数负责`drop`；
+    /// - 已经产生的`U`写入`ret`并同步更新`ret.len`，因此由`ret`的析构
+    ///   函数负责`drop`；
+    /// - 正在被转换的那个`T`已经被移入`f`，由`f`自己负责。
+    ///
+    /// 这样，每个元素都恰好被`drop`一次。
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<InplaceVec<N, U, L>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let mut ret = InplaceVec::new();
+        for elem in self {
+            let value = f(elem)?;
+            // `self`最多只有`N`个元素，因此此处不会越界
+            ret.buf[ret.len()].write(value);
+            ret.store_len(ret.len() + 1);
+        }
+        Ok(ret)
+    }
+}
+
+impl<const N: usize, T, L: LenType> InplaceVec<N, T, L> {
+    /// 在保持有序的前提下插入`value`，返回插入的位置。
+    ///
+    /// `compare(elem, &value)`用于比较已有的元素与`value`，要求`self`
+    /// 已经按照`compare`有序。对于相等的元素，`value`会被插入到所有相等
+    /// 元素的后面，因此插入是稳定的。
+    ///
+    /// 如果`InplaceVec`已满，则会将`value`放入[`CapacityError`]中返还，
+    /// 而不是`panic`。
+    pub fn binary_insert_by<F>(
+        &mut self,
+        value: T,
+        mut compare: F,
+    ) -> Result<usize, CapacityError<T>>
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        if self.len() == N {
+            return Err(CapacityError::new(value, N.saturating_add(1), N));
+        }
+
+        let index = self.partition_point(|elem| compare(elem, &value) != cmp::Ordering::Greater);
+        self.insert(index, value);
+        Ok(index)
+    }
+
+    /// 见[`InplaceVec::binary_insert_by`]，`value`的键只会被计算一次。
+    pub fn binary_insert_by_key<K, F>(
+        &mut self,
+        value: T,
+        mut f: F,
+    ) -> Result<usize, CapacityError<T>>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let key = f(&value);
+        self.binary_insert_by(value, |elem, _| f(elem).cmp(&key))
+    }
+
+    /// 见[`collection::slice::rotate_left`]。
+    #[inline]
+    pub fn my_rotate_left(&mut self, mid: usize) {
+        collection::slice::rotate_left(self, mid);
+    }
+
+    /// 见[`collection::slice::rotate_right`]。
+    #[inline]
+    pub fn my_rotate_right(&mut self, k: usize) {
+        collection::slice::rotate_right(self, k);
+    }
 }
 
-impl<T, const N: usize> Default for InplaceVec<N, T> {
+impl<const N: usize, T: Ord, L: LenType> InplaceVec<N, T, L> {
+    /// 见[`InplaceVec::binary_insert_by`]。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::inplace_vec::InplaceVec;
+    /// use rust_practice::inplace_vec;
+    ///
+    /// let mut vec: InplaceVec<4, i32> = inplace_vec![1, 3, 5];
+    /// assert_eq!(vec.binary_insert(4), Ok(2));
+    /// assert_eq!(vec, [1, 3, 4, 5]);
+    /// assert_eq!(vec.binary_insert(2).unwrap_err().into_element(), 2);
+    /// ```
+    pub fn binary_insert(&mut self, value: T) -> Result<usize, CapacityError<T>> {
+        self.binary_insert_by(value, T::cmp)
+    }
+}
+
+impl<T, const N: usize, L: LenType> Default for InplaceVec<N, T, L> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T, const N: usize> Drop for InplaceVec<N, T> {
+impl<T, const N: usize, L: LenType> Drop for InplaceVec<N, T, L> {
     fn drop(&mut self) {
         unsafe {
             ptr::drop_in_place(self.as_mut_slice());
@@ -165,27 +496,35 @@ impl<T, const N: usize> Drop for InplaceVec<N, T> {
     }
 }
 
-impl<T, const N: usize> Deref for InplaceVec<N, T> {
+/// 缓冲区中未初始化的部分没有任何意义，因此我们不使用`#[derive(Debug)]`，
+/// 而是像标准库的容器一样，只将已初始化的元素作为切片输出。
+impl<const N: usize, T: fmt::Debug, L: LenType> fmt::Debug for InplaceVec<N, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T, const N: usize, L: LenType> Deref for InplaceVec<N, T, L> {
     type Target = [T];
     fn deref(&self) -> &Self::Target {
         self.as_slice()
     }
 }
 
-impl<T, const N: usize> DerefMut for InplaceVec<N, T> {
+impl<T, const N: usize, L: LenType> DerefMut for InplaceVec<N, T, L> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut_slice()
     }
 }
 
-impl<'a, const N: usize, T: Clone + 'a> InplaceVec<N, T> {
+impl<'a, const N: usize, T: Clone + 'a, L: LenType> InplaceVec<N, T, L> {
     fn extend_from_iter_ref<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         for refer in iter {
             self.overflow_check();
             unsafe {
                 let ptr = self.as_mut_ptr().add(self.len());
                 ptr::write(ptr, refer.clone());
-                self.len += 1;
+                self.store_len(self.len() + 1);
             }
         }
     }
@@ -195,34 +534,151 @@ impl<'a, const N: usize, T: Clone + 'a> InplaceVec<N, T> {
             unsafe {
                 let ptr = self.as_mut_ptr().add(self.len());
                 ptr::write(ptr, refer.clone());
-                self.len += 1;
+                self.store_len(self.len() + 1);
             }
         }
     }
 }
 
-impl<const N: usize, T: Clone> InplaceVec<N, T> {
+impl<const N: usize, T: Clone, L: LenType> InplaceVec<N, T, L> {
     pub fn extend_from_slice(&mut self, slice: &[T]) {
         assert!(self.len() + slice.len() <= N, "InplaceVec overflow");
         unsafe {
             self.unchecked_extend_from_iter_ref(slice);
         }
     }
+
+    /// 构造一个已满的`InplaceVec`，所有元素都是`value`的克隆。
+    ///
+    /// 前`N - 1`个位置写入`value`的克隆，最后一个位置直接移入`value`，
+    /// 因此恰好调用`N - 1`次`clone`。与[`InplaceVec::full_with`]相同，
+    /// `clone`发生`panic`时，已经写入的元素会被`drop`。
+    ///
+    /// 对于`T: Copy`的类型，编译器能够将下面的循环优化为`memset`。
+    pub fn filled(value: T) -> Self {
+        let mut ret = Self::new();
+        if N == 0 {
+            return ret;
+        }
+
+        for i in 0..N - 1 {
+            ret.buf[i].write(value.clone());
+            ret.store_len(ret.len() + 1);
+        }
+        ret.buf[N - 1].write(value);
+        ret.store_len(ret.len() + 1);
+        ret
+    }
+
+    /// 在`index`处插入`slice`中所有元素的克隆。
+    ///
+    /// 容量检查会在移动任何元素之前完成，因此要么全部插入，要么在
+    /// `panic`时保持原样。
+    ///
+    /// 插入时，首先将尾部一次性向后移动`slice.len()`个位置，然后依次
+    /// 写入克隆的元素。如果`T::clone`发生了`panic`，`guard`会将尾部移
+    /// 回到已写入元素的后面，并设置正确的长度，已经写入的元素会被保留。
+    pub fn insert_from_slice(&mut self, index: usize, slice: &[T]) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "InplaceVec insert_from_slice index (is {index}) should be <= len (is {len})"
+        );
+        assert!(
+            slice.len() <= N - len,
+            "InplaceVec overflow: cannot insert {} elements into a vector of length {len} \
+             with capacity {N}",
+            slice.len()
+        );
+
+        struct Guard<'a, const N: usize, T, L: LenType> {
+            vec: &'a mut InplaceVec<N, T, L>,
+            index: usize,
+            written: usize,
+            shift: usize,
+            tail_len: usize,
+        }
+
+        impl<'a, const N: usize, T, L: LenType> Drop for Guard<'a, N, T, L> {
+            fn drop(&mut self) {
+                unsafe {
+                    let buf_ptr = self.vec.as_mut_ptr();
+                    ptr::copy(
+                        buf_ptr.add(self.index + self.shift),
+                        buf_ptr.add(self.index + self.written),
+                        self.tail_len,
+                    );
+                }
+                self.vec
+                    .store_len(self.index + self.written + self.tail_len);
+            }
+        }
+
+        unsafe {
+            let buf_ptr = self.as_mut_ptr();
+            ptr::copy(
+                buf_ptr.add(index),
+                buf_ptr.add(index + slice.len()),
+                len - index,
+            );
+        }
+        // 在写入完成之前，尾部不属于`InplaceVec`的有效部分
+        self.store_len(index);
+
+        let mut guard = Guard {
+            vec: self,
+            index,
+            written: 0,
+            shift: slice.len(),
+            tail_len: len - index,
+        };
+        for elem in slice {
+            unsafe {
+                let ptr = guard.vec.as_mut_ptr().add(index + guard.written);
+                ptr::write(ptr, elem.clone());
+            }
+            guard.written += 1;
+        }
+    }
+
+    /// 将`range`范围内的元素克隆一份追加到末尾。
+    ///
+    /// 与[`InplaceVec::insert_from_slice`]相同，容量检查在写入之前完成。
+    /// 由于每写入一个元素都会立即更新长度，因此即使`T::clone`发生了
+    /// `panic`，`InplaceVec`也仍然是有效的。
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, range: R) {
+        let len = self.len();
+        let range = collection::slice::range(range, ..len);
+        assert!(
+            range.len() <= N - len,
+            "InplaceVec overflow: cannot extend {} elements onto a vector of length {len} \
+             with capacity {N}",
+            range.len()
+        );
+
+        for i in range {
+            unsafe {
+                let buf_ptr = self.as_mut_ptr();
+                ptr::write(buf_ptr.add(self.len()), (*buf_ptr.add(i)).clone());
+            }
+            self.store_len(self.len() + 1);
+        }
+    }
 }
 
-impl<const N: usize, T> Extend<T> for InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> Extend<T> for InplaceVec<N, T, L> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         self.extend_from_iter(iter);
     }
 }
 
-impl<'a, const N: usize, T: Clone> Extend<&'a T> for InplaceVec<N, T> {
+impl<'a, const N: usize, T: Clone, L: LenType> Extend<&'a T> for InplaceVec<N, T, L> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         self.extend_from_iter_ref(iter);
     }
 }
 
-impl<const N: usize, T> FromIterator<T> for InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> FromIterator<T> for InplaceVec<N, T, L> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut ret = InplaceVec::new();
         ret.extend_from_iter(iter);
@@ -230,7 +686,7 @@ impl<const N: usize, T> FromIterator<T> for InplaceVec<N, T> {
     }
 }
 
-impl<const N: usize, T: Clone> Clone for InplaceVec<N, T> {
+impl<const N: usize, T: Clone, L: LenType> Clone for InplaceVec<N, T, L> {
     fn clone(&self) -> Self {
         let mut vec = InplaceVec::new();
         unsafe { vec.unchecked_extend_from_iter_ref(self.as_slice()) };
@@ -243,114 +699,255 @@ impl<const N: usize, T: Clone> Clone for InplaceVec<N, T> {
     }
 }
 
-impl<const N: usize, T: Clone> From<&[T]> for InplaceVec<N, T> {
-    fn from(value: &[T]) -> Self {
+/// 由于切片的长度可能超过`N`，从切片构造`InplaceVec`是可能失败的，
+/// 因此我们只提供[`TryFrom`]，在容量不足时返回[`CapacityError`]，而
+/// 不是`panic`。
+///
+/// 注意：标准库中存在`impl<T, U: Into<T>> TryFrom<U> for T`，因此不能
+/// 同时为同一类型实现[`From`]和`TryFrom`。
+impl<const N: usize, T: Clone, L: LenType> TryFrom<&[T]> for InplaceVec<N, T, L> {
+    type Error = CapacityError;
+
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        if value.len() > N {
+            return Err(CapacityError::new((), value.len(), N));
+        }
+
         let mut vec = InplaceVec::new();
-        vec.extend_from_slice(value);
-        vec
+        unsafe {
+            vec.unchecked_extend_from_iter_ref(value);
+        }
+        Ok(vec)
     }
 }
 
-impl<const N: usize, T: Clone> From<&mut [T]> for InplaceVec<N, T> {
-    fn from(value: &mut [T]) -> Self {
-        Self::from(&*value)
+impl<const N: usize, T: Clone, L: LenType> TryFrom<&mut [T]> for InplaceVec<N, T, L> {
+    type Error = CapacityError;
+
+    fn try_from(value: &mut [T]) -> Result<Self, Self::Error> {
+        Self::try_from(&*value)
     }
 }
 
-impl<const N: usize, T: Clone, const M: usize> From<&[T; M]> for InplaceVec<N, T> {
-    fn from(value: &[T; M]) -> Self {
-        assert!(M <= N, "InplaceVec overflow");
-        Self::from(value.as_slice())
+impl<const N: usize, T: Clone, const M: usize, L: LenType> TryFrom<&[T; M]>
+    for InplaceVec<N, T, L>
+{
+    type Error = CapacityError;
+
+    fn try_from(value: &[T; M]) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
     }
 }
 
-impl<const N: usize, T: Clone, const M: usize> From<&mut [T; M]> for InplaceVec<N, T> {
-    fn from(value: &mut [T; M]) -> Self {
-        Self::from(value.as_slice())
+impl<const N: usize, T: Clone, const M: usize, L: LenType> TryFrom<&mut [T; M]>
+    for InplaceVec<N, T, L>
+{
+    type Error = CapacityError;
+
+    fn try_from(value: &mut [T; M]) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
     }
 }
 
-impl<const N: usize, T: PartialEq> PartialEq for InplaceVec<N, T> {
+impl<const N: usize, T: PartialEq, L: LenType> PartialEq for InplaceVec<N, T, L> {
     fn eq(&self, other: &Self) -> bool {
         (**self).eq(&**other)
     }
 }
 
-impl<const N: usize, T: Eq> Eq for InplaceVec<N, T> {}
+impl<const N: usize, T: Eq, L: LenType> Eq for InplaceVec<N, T, L> {}
 
-impl<const N: usize, T: PartialEq> PartialEq<[T]> for InplaceVec<N, T> {
+impl<const N: usize, T: PartialEq, L: LenType> PartialEq<[T]> for InplaceVec<N, T, L> {
     fn eq(&self, other: &[T]) -> bool {
         (**self).eq(other)
     }
 }
 
-impl<const N: usize, T: PartialEq> PartialEq<&[T]> for InplaceVec<N, T> {
+impl<const N: usize, T: PartialEq, L: LenType> PartialEq<&[T]> for InplaceVec<N, T, L> {
     fn eq(&self, other: &&[T]) -> bool {
         (**self).eq(*other)
     }
 }
 
-impl<const N: usize, T: PartialEq, const M: usize> PartialEq<[T; M]> for InplaceVec<N, T> {
+impl<const N: usize, T: PartialEq, const M: usize, L: LenType> PartialEq<[T; M]>
+    for InplaceVec<N, T, L>
+{
     fn eq(&self, other: &[T; M]) -> bool {
         (**self).eq(other)
     }
 }
 
-impl<const N: usize, T: PartialEq, const M: usize> PartialEq<&[T; M]> for InplaceVec<N, T> {
+impl<const N: usize, T: PartialEq, const M: usize, L: LenType> PartialEq<&[T; M]>
+    for InplaceVec<N, T, L>
+{
     fn eq(&self, other: &&[T; M]) -> bool {
         (**self).eq(*other)
     }
 }
 
-impl<const N: usize, T: PartialOrd> PartialOrd<InplaceVec<N, T>> for InplaceVec<N, T> {
-    fn partial_cmp(&self, other: &InplaceVec<N, T>) -> Option<cmp::Ordering> {
+impl<const N: usize, T: PartialEq, L: LenType> PartialEq<InplaceVec<N, T, L>> for [T] {
+    fn eq(&self, other: &InplaceVec<N, T, L>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialEq, L: LenType> PartialEq<InplaceVec<N, T, L>> for &[T] {
+    fn eq(&self, other: &InplaceVec<N, T, L>) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialEq, const M: usize, L: LenType> PartialEq<InplaceVec<N, T, L>>
+    for [T; M]
+{
+    fn eq(&self, other: &InplaceVec<N, T, L>) -> bool {
+        self.as_slice().eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialEq, const M: usize, L: LenType> PartialEq<InplaceVec<N, T, L>>
+    for &[T; M]
+{
+    fn eq(&self, other: &InplaceVec<N, T, L>) -> bool {
+        self.as_slice().eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialEq<U>, U, L: LenType> PartialEq<MyVec<U>> for InplaceVec<N, T, L> {
+    fn eq(&self, other: &MyVec<U>) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialEq<U>, U, L: LenType> PartialEq<InplaceVec<N, U, L>> for MyVec<T> {
+    fn eq(&self, other: &InplaceVec<N, U, L>) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialEq<U>, U, L: LenType> PartialEq<Vec<U>> for InplaceVec<N, T, L> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialEq<U>, U, L: LenType> PartialEq<InplaceVec<N, U, L>> for Vec<T> {
+    fn eq(&self, other: &InplaceVec<N, U, L>) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialOrd, L: LenType> PartialOrd<InplaceVec<N, T, L>>
+    for InplaceVec<N, T, L>
+{
+    fn partial_cmp(&self, other: &InplaceVec<N, T, L>) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialOrd, L: LenType> PartialOrd<[T]> for InplaceVec<N, T, L> {
+    fn partial_cmp(&self, other: &[T]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(other)
+    }
+}
+
+impl<const N: usize, T: PartialOrd, L: LenType> PartialOrd<&[T]> for InplaceVec<N, T, L> {
+    fn partial_cmp(&self, other: &&[T]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(*other)
+    }
+}
+
+impl<const N: usize, T: PartialOrd, const M: usize, L: LenType> PartialOrd<[T; M]>
+    for InplaceVec<N, T, L>
+{
+    fn partial_cmp(&self, other: &[T; M]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(other.as_slice())
+    }
+}
+
+impl<const N: usize, T: PartialOrd, const M: usize, L: LenType> PartialOrd<&[T; M]>
+    for InplaceVec<N, T, L>
+{
+    fn partial_cmp(&self, other: &&[T; M]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(other.as_slice())
+    }
+}
+
+impl<const N: usize, T: PartialOrd, L: LenType> PartialOrd<InplaceVec<N, T, L>> for [T] {
+    fn partial_cmp(&self, other: &InplaceVec<N, T, L>) -> Option<cmp::Ordering> {
+        self.partial_cmp(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialOrd, L: LenType> PartialOrd<InplaceVec<N, T, L>> for &[T] {
+    fn partial_cmp(&self, other: &InplaceVec<N, T, L>) -> Option<cmp::Ordering> {
         (**self).partial_cmp(&**other)
     }
 }
 
-impl<const N: usize, T: Ord> Ord for InplaceVec<N, T> {
+impl<const N: usize, T: PartialOrd, const M: usize, L: LenType> PartialOrd<InplaceVec<N, T, L>>
+    for [T; M]
+{
+    fn partial_cmp(&self, other: &InplaceVec<N, T, L>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(&**other)
+    }
+}
+
+impl<const N: usize, T: PartialOrd, const M: usize, L: LenType> PartialOrd<InplaceVec<N, T, L>>
+    for &[T; M]
+{
+    fn partial_cmp(&self, other: &InplaceVec<N, T, L>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(&**other)
+    }
+}
+
+impl<const N: usize, T: Ord, L: LenType> Ord for InplaceVec<N, T, L> {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         (**self).cmp(&**other)
     }
 }
 
-impl<const N: usize, T> AsMut<[T]> for InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> AsMut<[T]> for InplaceVec<N, T, L> {
     fn as_mut(&mut self) -> &mut [T] {
         self
     }
 }
 
-impl<const N: usize, T> AsMut<InplaceVec<N, T>> for InplaceVec<N, T> {
-    fn as_mut(&mut self) -> &mut InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> AsMut<InplaceVec<N, T, L>> for InplaceVec<N, T, L> {
+    fn as_mut(&mut self) -> &mut InplaceVec<N, T, L> {
         self
     }
 }
 
-impl<const N: usize, T> AsRef<[T]> for InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> AsRef<[T]> for InplaceVec<N, T, L> {
     fn as_ref(&self) -> &[T] {
         self
     }
 }
 
-impl<const N: usize, T> AsRef<InplaceVec<N, T>> for InplaceVec<N, T> {
-    fn as_ref(&self) -> &InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> AsRef<InplaceVec<N, T, L>> for InplaceVec<N, T, L> {
+    fn as_ref(&self) -> &InplaceVec<N, T, L> {
         self
     }
 }
 
-impl<const N: usize, T: Hash> Hash for InplaceVec<N, T> {
+/// 与切片的哈希值相同，这是[`Borrow<[T]>`](Borrow)所要求的。
+///
+/// 注意不能只使用[`Hash::hash_slice`]：它不包含长度，对于ZST，不同长度
+/// 的容器会得到相同的哈希值。
+impl<const N: usize, T: Hash, L: LenType> Hash for InplaceVec<N, T, L> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        <T as Hash>::hash_slice(self, state);
+        (**self).hash(state);
     }
 }
 
-impl<const N: usize, T> Borrow<[T]> for InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> Borrow<[T]> for InplaceVec<N, T, L> {
     fn borrow(&self) -> &[T] {
         self
     }
 }
 
-impl<const N: usize, T> BorrowMut<[T]> for InplaceVec<N, T> {
+impl<const N: usize, T, L: LenType> BorrowMut<[T]> for InplaceVec<N, T, L> {
     fn borrow_mut(&mut self) -> &mut [T] {
         self
     }
@@ -1,29 +1,152 @@
 use std::{
+    iter::FusedIterator,
     marker::PhantomData,
+    mem::{self, ManuallyDrop},
     ops::RangeBounds,
     ptr::{self, NonNull},
+    slice,
 };
 
-use crate::collection::{self, inplace_vec::InplaceVec};
+use crate::collection::{
+    self,
+    inplace_vec::{InplaceVec, LenType},
+};
 
-pub struct Drain<'a, const N: usize, T> {
-    _marker: PhantomData<&'a T>,
-    vec: NonNull<InplaceVec<N, T>>,
+/// [`InplaceVec::drain`]返回的迭代器。
+///
+/// ## 关于别名问题
+///
+/// 最初的实现在每次`next`/`next_back`时都会调用`self.vec.as_mut()`，
+/// 得到一个`&mut InplaceVec<N, T>`之后再去索引`buf`。但创建`Drain`的
+/// 那个`&mut self`在概念上仍然是活跃的，每次`as_mut`都会创建一个新的
+/// 唯一引用，在Stacked Borrows/Tree Borrows模型下，Miri会认为这产生
+/// 了重叠的唯一引用。
+///
+/// [`MyVec`]的`Drain`通过在构造时创建`RawValIter`避免了这一问题。
+/// 在此处，我们同样在构造时从`vec`派生出一个指向缓冲区的裸指针`buf`，
+/// 所有的读取和移动都通过`buf`进行，且全程不创建任何引用。只有在最
+/// 后恢复长度时，才通过`vec`写入`len`字段。
+///
+/// [`MyVec`]: crate::collection::vec::MyVec
+pub struct Drain<'a, const N: usize, T, L: LenType = usize> {
+    _marker: PhantomData<&'a mut InplaceVec<N, T, L>>,
+    vec: NonNull<InplaceVec<N, T, L>>,
+    buf: NonNull<T>,
     start: usize,
     end: usize,
-    before_len: usize,
-    after_len: usize,
-    old_len: usize,
+    /// 保留在前面的元素个数，[`Splice`]会在写入替换元素时增加该值
+    ///
+    /// [`Splice`]: crate::collection::inplace_vec::Splice
+    pub(super) before_len: usize,
+    pub(super) after_len: usize,
+    /// 尾部元素的结束位置，尾部元素位于`old_len - after_len..old_len`
+    pub(super) old_len: usize,
 }
 
-impl<'a, const N: usize, T> Iterator for Drain<'a, N, T> {
+impl<'a, const N: usize, T, L: LenType> Drain<'a, N, T, L> {
+    /// 返回尚未被迭代的元素组成的切片。
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().add(self.start), self.len()) }
+    }
+
+    /// 使用`replace_with`中的元素填充`before_len..old_len - after_len`
+    /// 的空洞，如果空洞被填满则返回`true`，迭代器耗尽则返回`false`。
+    ///
+    /// ## Safety
+    ///
+    /// - 所有被drain的元素都必须已经被取出，即`start == end`
+    pub(super) unsafe fn fill<I: Iterator<Item = T>>(&mut self, replace_with: &mut I) -> bool {
+        let hole_end = self.old_len - self.after_len;
+        while self.before_len < hole_end {
+            match replace_with.next() {
+                Some(item) => unsafe {
+                    ptr::write(self.buf.as_ptr().add(self.before_len), item);
+                    self.before_len += 1;
+                },
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// 将尾部元素移动到缓冲区的末端，使空洞尽可能大。
+    ///
+    /// ## Safety
+    ///
+    /// - 与[`Drain::fill`]相同
+    pub(super) unsafe fn move_tail_to_end(&mut self) {
+        let tail_start = self.old_len - self.after_len;
+        unsafe {
+            ptr::copy(
+                self.buf.as_ptr().add(tail_start),
+                self.buf.as_ptr().add(N - self.after_len),
+                self.after_len,
+            );
+        }
+        self.old_len = N;
+    }
+
+    /// 通过裸指针写入`vec`的长度，不会创建指向整个`InplaceVec`的引用。
+    ///
+    /// ## Safety
+    ///
+    /// - 调用之后不应当再通过`buf`访问缓冲区
+    #[inline]
+    unsafe fn set_vec_len(&mut self, len: usize) {
+        unsafe {
+            ptr::addr_of_mut!((*self.vec.as_ptr()).len).write(super::len::from_usize(len));
+        }
+    }
+
+    /// 停止drain，并将尚未被迭代的元素保留在原来的[`InplaceVec`]中。
+    ///
+    /// 未被迭代的元素位于`start..end`，由于前后两端都可能已经被取出了
+    /// 元素，因此需要先将这些元素移动到`before_len`处，再将尾部的元素
+    /// 紧接着移动到它们后面。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::inplace_vec::InplaceVec;
+    ///
+    /// let mut vec: InplaceVec<5, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5]).unwrap();
+    /// let mut drain = vec.drain(1..4);
+    /// assert_eq!(drain.next(), Some(2));
+    /// drain.keep_rest();
+    /// assert_eq!(vec, [1, 3, 4, 5]);
+    /// ```
+    pub fn keep_rest(self) {
+        // 我们手动恢复了长度，因此不能再执行`Drop`中的逻辑
+        let mut this = ManuallyDrop::new(self);
+
+        let before_len = this.before_len;
+        let after_len = this.after_len;
+        let unyielded_len = this.end - this.start;
+
+        unsafe {
+            let buf_ptr = this.buf.as_ptr();
+
+            ptr::copy(
+                buf_ptr.add(this.start),
+                buf_ptr.add(before_len),
+                unyielded_len,
+            );
+            ptr::copy(
+                buf_ptr.add(this.old_len - after_len),
+                buf_ptr.add(before_len + unyielded_len),
+                after_len,
+            );
+            this.set_vec_len(before_len + unyielded_len + after_len);
+        }
+    }
+}
+
+impl<'a, const N: usize, T, L: LenType> Iterator for Drain<'a, N, T, L> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.start >= self.end {
             None
         } else {
-            let item = unsafe { self.vec.as_mut().buf[self.start].assume_init_read() };
+            let item = unsafe { ptr::read(self.buf.as_ptr().add(self.start)) };
             self.start += 1;
             Some(item)
         }
@@ -35,57 +158,88 @@ impl<'a, const N: usize, T> Iterator for Drain<'a, N, T> {
     }
 }
 
-impl<'a, const N: usize, T> DoubleEndedIterator for Drain<'a, N, T> {
+impl<'a, const N: usize, T, L: LenType> DoubleEndedIterator for Drain<'a, N, T, L> {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.start >= self.end {
             None
         } else {
             self.end -= 1;
-            let item = unsafe { self.vec.as_mut().buf[self.end].assume_init_read() };
+            let item = unsafe { ptr::read(self.buf.as_ptr().add(self.end)) };
             Some(item)
         }
     }
 }
 
-impl<'a, const N: usize, T> ExactSizeIterator for Drain<'a, N, T> {
+impl<'a, const N: usize, T, L: LenType> ExactSizeIterator for Drain<'a, N, T, L> {
     fn len(&self) -> usize {
         self.end - self.start
     }
 }
 
-impl<'a, const N: usize, T> Drop for Drain<'a, N, T> {
-    fn drop(&mut self) {
-        for _ in &mut *self {}
+impl<'a, const N: usize, T, L: LenType> FusedIterator for Drain<'a, N, T, L> {}
 
-        let buf_ptr = unsafe { self.vec.as_mut().buf.as_mut_ptr() };
-
-        let before_len = self.before_len;
-        let after_len = self.after_len;
-
-        unsafe {
-            let hole_begin = buf_ptr.add(before_len);
-            let hole_end = buf_ptr.add(self.old_len - after_len);
+impl<'a, const N: usize, T, L: LenType> Drop for Drain<'a, N, T, L> {
+    fn drop(&mut self) {
+        /// 与[`MyVec`]的`Drain`相同，即使剩余元素的`drop`发生了`panic`，
+        /// 也要移回尾部元素并恢复长度。
+        ///
+        /// [`MyVec`]: crate::collection::vec::MyVec
+        struct MoveTail<'r, 'a, const N: usize, T, L: LenType>(&'r mut Drain<'a, N, T, L>);
+
+        impl<const N: usize, T, L: LenType> Drop for MoveTail<'_, '_, N, T, L> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let buf_ptr = drain.buf.as_ptr();
+
+                let before_len = drain.before_len;
+                let after_len = drain.after_len;
+
+                unsafe {
+                    let hole_begin = buf_ptr.add(before_len);
+                    let hole_end = buf_ptr.add(drain.old_len - after_len);
+
+                    ptr::copy(hole_end, hole_begin, after_len);
+                    drain.set_vec_len(before_len + after_len);
+                }
+            }
+        }
 
-            ptr::copy(hole_end, hole_begin, after_len);
-            self.vec.as_mut().len = before_len + after_len;
+        let guard = MoveTail(self);
+
+        // 不需要`drop`的类型不必处理剩余的元素
+        if mem::needs_drop::<T>() {
+            let drain = &*guard.0;
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    drain.buf.as_ptr().add(drain.start),
+                    drain.len(),
+                ));
+            }
         }
     }
 }
 
-impl<const N: usize, T> InplaceVec<N, T> {
-    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, N, T> {
+impl<const N: usize, T, L: LenType> InplaceVec<N, T, L> {
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, N, T, L> {
         let old_len = self.len();
         let range = collection::slice::range(range, ..old_len);
 
         let before_len = range.start;
         let after_len = old_len - range.end;
 
-        self.len = 0;
+        self.store_len(0);
+
+        // `buf`必须从`vec`派生，且派生过程中不能创建引用，否则之后通过
+        // `vec`写入长度时会使`buf`失效。
+        let vec = NonNull::from_mut(self);
+        let buf =
+            unsafe { NonNull::new_unchecked(ptr::addr_of_mut!((*vec.as_ptr()).buf).cast::<T>()) };
 
         Drain {
             _marker: PhantomData,
             old_len,
-            vec: NonNull::from_mut(self),
+            vec,
+            buf,
             start: range.start,
             end: range.end,
             before_len,
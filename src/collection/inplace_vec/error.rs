@@ -0,0 +1,65 @@
+use std::error::Error;
+use std::fmt;
+
+/// 当[`InplaceVec`]的容量不足以容纳新的元素时产生的错误。
+///
+/// `required`表示完成该操作至少需要的容量，`capacity`表示实际可用的
+/// 容量（即`N`）。对于需要取回元素的操作，元素会被存放在`element`中
+/// 返还给调用者，否则`T`默认为`()`。
+///
+/// [`InplaceVec`]: crate::collection::inplace_vec::InplaceVec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()> {
+    element: T,
+    required: usize,
+    capacity: usize,
+}
+
+impl<T> CapacityError<T> {
+    #[inline]
+    pub const fn new(element: T, required: usize, capacity: usize) -> Self {
+        Self {
+            element,
+            required,
+            capacity,
+        }
+    }
+
+    #[inline]
+    pub const fn element(&self) -> &T {
+        &self.element
+    }
+
+    #[inline]
+    pub fn into_element(self) -> T {
+        self.element
+    }
+
+    #[inline]
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 丢弃其中携带的元素，只保留容量信息。
+    #[inline]
+    pub fn simplify(self) -> CapacityError {
+        CapacityError::new((), self.required, self.capacity)
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "InplaceVec overflow: required capacity {}, but only {} available",
+            self.required, self.capacity
+        )
+    }
+}
+
+impl<T: fmt::Debug> Error for CapacityError<T> {}
@@ -0,0 +1,121 @@
+use std::{iter::FusedIterator, ptr};
+
+use crate::collection::inplace_vec::{InplaceVec, LenType};
+
+/// [`InplaceVec::extract_if`]返回的迭代器。
+///
+/// 迭代时，我们使用两个索引：`idx`表示下一个待检查的元素，`del`表示
+/// 已经被取出的元素个数。每个被保留的元素都会被向前移动`del`个位置，
+/// 因此在任意时刻，缓冲区的布局如下：
+///
+/// ```text
+///   0        idx - del     idx         old_len
+///   +------------+----------+------------+
+///   |  保留的元素 |  空洞     | 未检查的元素 |
+///   +------------+----------+------------+
+/// ```
+///
+/// 在`drop`时，我们将未检查的元素向前移动以填补空洞，然后恢复长度。
+///
+/// 与[`Drain`]相同，构造时我们会将`vec`的长度设置为0，即使迭代器被
+/// [`std::mem::forget`]了，也只会泄露所有元素，而不会产生未定义行为。
+///
+/// [`Drain`]: crate::collection::inplace_vec::Drain
+pub struct ExtractIf<'a, const N: usize, T, F, L: LenType = usize>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut InplaceVec<N, T, L>,
+    idx: usize,
+    del: usize,
+    old_len: usize,
+    pred: F,
+}
+
+impl<'a, const N: usize, T, F, L: LenType> Iterator for ExtractIf<'a, N, T, F, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            while self.idx < self.old_len {
+                let buf_ptr = self.vec.as_mut_ptr();
+                let cur = buf_ptr.add(self.idx);
+
+                // 如果`pred`发生了`panic`，`idx`尚未增加，因此当前元素会在
+                // `drop`中被保留下来。
+                let matched = (self.pred)(&mut *cur);
+                self.idx += 1;
+
+                if matched {
+                    self.del += 1;
+                    return Some(ptr::read(cur));
+                } else if self.del > 0 {
+                    ptr::copy_nonoverlapping(cur, buf_ptr.add(self.idx - 1 - self.del), 1);
+                }
+            }
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<'a, const N: usize, T, F, L: LenType> FusedIterator for ExtractIf<'a, N, T, F, L> where
+    F: FnMut(&mut T) -> bool
+{
+}
+
+impl<'a, const N: usize, T, F, L: LenType> Drop for ExtractIf<'a, N, T, F, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        unsafe {
+            if self.idx < self.old_len && self.del > 0 {
+                let buf_ptr = self.vec.as_mut_ptr();
+                ptr::copy(
+                    buf_ptr.add(self.idx),
+                    buf_ptr.add(self.idx - self.del),
+                    self.old_len - self.idx,
+                );
+            }
+        }
+        self.vec.store_len(self.old_len - self.del);
+    }
+}
+
+impl<const N: usize, T, L: LenType> InplaceVec<N, T, L> {
+    /// 返回一个迭代器，按顺序取出所有使`pred`返回`true`的元素，其余的
+    /// 元素会被保留并保持原有的顺序。
+    ///
+    /// 如果迭代器在迭代完成之前被`drop`，尚未被检查的元素都会被保留。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::inplace_vec::InplaceVec;
+    ///
+    /// let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5, 6]).unwrap();
+    /// let evens: Vec<_> = vec.extract_if(|x| *x % 2 == 0).collect();
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// assert_eq!(vec, [1, 3, 5]);
+    /// ```
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, N, T, F, L>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let old_len = self.len();
+        self.store_len(0);
+
+        ExtractIf {
+            vec: self,
+            idx: 0,
+            del: 0,
+            old_len,
+            pred,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::mem::ManuallyDrop;
+use std::ptr;
+
+use crate::collection::inplace_vec::{InplaceVec, LenType};
+
+/// 两者都将元素直接存放在数组中，因此只需要一次[`ptr::copy_nonoverlapping`]
+/// 就可以转移所有元素。原来的容器会被forget：它的元素已经被移走，而且
+/// 它本身没有堆上的内存需要释放。
+impl<const N: usize, T, L: LenType, HL: heapless::LenType> From<heapless::Vec<T, N, HL>>
+    for InplaceVec<N, T, L>
+{
+    fn from(value: heapless::Vec<T, N, HL>) -> Self {
+        match Self::try_from_heapless(value) {
+            Ok(vec) => vec,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// 与`From<heapless::Vec<T, N>> for InplaceVec<N, T>`相同，只移动元素。
+impl<const N: usize, T, L: LenType, HL: heapless::LenType> From<InplaceVec<N, T, L>>
+    for heapless::Vec<T, N, HL>
+{
+    fn from(value: InplaceVec<N, T, L>) -> Self {
+        match value.try_into_heapless() {
+            Ok(vec) => vec,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// 标准库中存在`impl<T, U: Into<T>> TryFrom<U> for T`，而`M == N`时上面
+/// 的[`From`]已经存在，在stable中又无法写出`M != N`的约束，因此容量不同时
+/// 的转换只能以方法的形式提供，与[`InplaceVec::into_capacity`]相同，失败时
+/// 原样返回输入，以免丢失不能`Clone`的元素。
+impl<const N: usize, T, L: LenType> InplaceVec<N, T, L> {
+    /// 将`heapless::Vec`中的元素移动到`InplaceVec`中，只要长度不超过`N`
+    /// 就会成功，否则返回`Err(value)`。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::inplace_vec::InplaceVec;
+    ///
+    /// let small: heapless::Vec<i32, 2> = heapless::Vec::from_slice(&[1, 2]).unwrap();
+    /// let vec = InplaceVec::<4, i32>::try_from_heapless(small).unwrap();
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    pub fn try_from_heapless<const M: usize, HL: heapless::LenType>(
+        value: heapless::Vec<T, M, HL>,
+    ) -> Result<Self, heapless::Vec<T, M, HL>> {
+        if value.len() > N {
+            return Err(value);
+        }
+
+        let value = ManuallyDrop::new(value);
+        let mut ret = InplaceVec::new();
+        unsafe {
+            ptr::copy_nonoverlapping(value.as_ptr(), ret.as_mut_ptr(), value.len());
+            ret.set_len(value.len());
+        }
+        Ok(ret)
+    }
+
+    /// 将元素移动到容量为`M`的`heapless::Vec`中，只要长度不超过`M`就会
+    /// 成功，否则返回`Err(self)`。
+    pub fn try_into_heapless<const M: usize, HL: heapless::LenType>(
+        self,
+    ) -> Result<heapless::Vec<T, M, HL>, Self> {
+        if self.len() > M {
+            return Err(self);
+        }
+
+        let this = ManuallyDrop::new(self);
+        let mut ret = heapless::Vec::new();
+        unsafe {
+            ptr::copy_nonoverlapping(this.as_ptr(), ret.as_mut_ptr(), this.len());
+            ret.set_len(this.len());
+        }
+        Ok(ret)
+    }
+}
+
+impl<const N: usize, T: PartialEq<U>, U, L: LenType, const M: usize, HL: heapless::LenType>
+    PartialEq<heapless::Vec<U, M, HL>> for InplaceVec<N, T, L>
+{
+    fn eq(&self, other: &heapless::Vec<U, M, HL>) -> bool {
+        (**self).eq(other.as_slice())
+    }
+}
+
+impl<const N: usize, T: PartialEq<U>, U, L: LenType, const M: usize, HL: heapless::LenType>
+    PartialEq<InplaceVec<N, U, L>> for heapless::Vec<T, M, HL>
+{
+    fn eq(&self, other: &InplaceVec<N, U, L>) -> bool {
+        self.as_slice().eq(&**other)
+    }
+}
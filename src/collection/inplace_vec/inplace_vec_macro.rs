@@ -0,0 +1,54 @@
+/// 构造[`InplaceVec`]的宏，类似于[`vec!`]。
+///
+/// - `inplace_vec![1, 2, 3]`：容量`N`从上下文中推断，元素个数超过`N`
+///   时会产生编译错误；
+/// - `inplace_vec![cap 8; 1, 2, 3]`：显式指定容量，同样在元素个数超过
+///   容量时产生编译错误，长度的类型总是默认的[`usize`]；
+/// - `inplace_vec![0u8; 5]`：将元素克隆`5`次，由于次数可以是运行时的
+///   值，超过容量时会在运行时`panic`。
+///
+/// 前两种形式可以用于常量上下文。
+///
+/// ```rust
+/// use rust_practice::collection::inplace_vec::InplaceVec;
+/// use rust_practice::inplace_vec;
+///
+/// let vec: InplaceVec<4, i32> = inplace_vec![1, 2, 3];
+/// assert_eq!(vec, [1, 2, 3]);
+///
+/// let vec = inplace_vec![cap 8; 'a', 'b',];
+/// assert_eq!(vec.capacity(), 8);
+///
+/// let vec: InplaceVec<8, u8> = inplace_vec![0u8; 5];
+/// assert_eq!(vec, [0; 5]);
+///
+/// // 除了`cap`形式以外，长度的类型也从上下文中推断
+/// let vec: InplaceVec<8, u8, u8> = inplace_vec![0u8; 5];
+/// assert_eq!(vec.len(), 5);
+/// let vec: InplaceVec<8, u8, u8> = inplace_vec![1, 2];
+/// assert_eq!(vec, [1, 2]);
+///
+/// const CONST_VEC: InplaceVec<4, i32> = inplace_vec![1, 2];
+/// assert_eq!(CONST_VEC, [1, 2]);
+/// ```
+///
+/// ```rust,compile_fail
+/// use rust_practice::inplace_vec;
+///
+/// let vec = inplace_vec![cap 2; 1, 2, 3];
+/// ```
+///
+/// [`InplaceVec`]: crate::collection::inplace_vec::InplaceVec
+#[macro_export]
+macro_rules! inplace_vec {
+    (cap $cap:expr; $( $x:expr ),* $(,)?) => {
+        $crate::collection::inplace_vec::InplaceVec::<$cap, _>::from_array([$( $x ),*])
+    };
+    ($elem:expr; $n:expr) => {
+        <$crate::collection::inplace_vec::InplaceVec<_, _, _> as ::core::iter::FromIterator<_>>
+            ::from_iter(::core::iter::repeat_n($elem, $n))
+    };
+    ($( $x:expr ),* $(,)?) => {
+        $crate::collection::inplace_vec::InplaceVec::from_array([$( $x ),*])
+    };
+}
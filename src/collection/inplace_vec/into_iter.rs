@@ -1,15 +1,16 @@
 use core::slice;
 use std::{
+    fmt,
     iter::FusedIterator,
     mem::{self, MaybeUninit},
     ptr,
 };
 
-use crate::collection::inplace_vec::InplaceVec;
+use crate::collection::inplace_vec::{InplaceVec, LenType};
 
 /// 在此处，[`InplaceVec`]的迭代逻辑和[`MyVec`]的迭代逻辑完全相同，
 /// 唯一值得注意的是，两者的drop逻辑不同。`MyVec`需要手动释放，因此
-/// 我们在`MyVec`的`IntoIter`中使用了[`MyRawVec`]来管理内存。而在`InplaceVec`
+/// 我们在`MyVec`的`IntoIter`中使用了[`RawBuf`](crate::collection::raw::RawBuf)来管理内存。而在`InplaceVec`
 /// 中，由于内存是自动释放的，因此我们不需要一个`RawVec`来管理内存。
 /// 但这并不表明我们不需要关心原来的buffer，如果我们把IntoIter实现
 /// 为如下的样子：
@@ -82,6 +83,19 @@ pub struct IntoIter<const N: usize, T> {
     end: usize,
 }
 
+impl<const N: usize, T> IntoIter<N, T> {
+    /// 返回剩余元素组成的切片，即`buf[begin..end]`。
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().add(self.begin).cast(), self.len()) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(self.buf.as_mut_ptr().add(self.begin).cast(), self.len())
+        }
+    }
+}
+
 impl<const N: usize, T> Iterator for IntoIter<N, T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -126,29 +140,52 @@ impl<const N: usize, T> FusedIterator for IntoIter<N, T> {}
 
 impl<const N: usize, T> Drop for IntoIter<N, T> {
     fn drop(&mut self) {
-        unsafe {
-            let drop_array: *mut [T] =
-                slice::from_raw_parts_mut(self.buf.as_mut_ptr().add(self.begin).cast(), self.len());
-            std::ptr::drop_in_place(drop_array);
+        if mem::needs_drop::<T>() {
+            unsafe {
+                let drop_array = ptr::slice_from_raw_parts_mut(
+                    self.buf.as_mut_ptr().add(self.begin).cast::<T>(),
+                    self.len(),
+                );
+                ptr::drop_in_place(drop_array);
+            }
         }
     }
 }
 
-impl<const N: usize, T> IntoIterator for InplaceVec<N, T> {
+impl<const N: usize, T: fmt::Debug> fmt::Debug for IntoIter<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+/// 只克隆剩余的元素，并将其放置在新缓冲区的开头（即`begin = 0`），
+/// 已经被消费的和从未初始化的位置都不会被读取。
+///
+/// 借助[`InplaceVec`]来构造新的缓冲区，这样即使`T::clone`发生了`panic`，
+/// 已经克隆出来的元素也能被正确地`drop`。
+impl<const N: usize, T: Clone> Clone for IntoIter<N, T> {
+    fn clone(&self) -> Self {
+        let mut vec = InplaceVec::<N, T>::new();
+        vec.extend_from_slice(self.as_slice());
+        vec.into_iter()
+    }
+}
+
+impl<const N: usize, T, L: LenType> IntoIterator for InplaceVec<N, T, L> {
     type Item = T;
     type IntoIter = IntoIter<N, T>;
     fn into_iter(self) -> Self::IntoIter {
         unsafe {
             let buf = ptr::read(&self.buf);
             let begin = 0;
-            let end = self.len;
+            let end = self.len();
             mem::forget(self);
             IntoIter { buf, begin, end }
         }
     }
 }
 
-impl<'a, const N: usize, T> IntoIterator for &'a InplaceVec<N, T> {
+impl<'a, const N: usize, T, L: LenType> IntoIterator for &'a InplaceVec<N, T, L> {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
     fn into_iter(self) -> Self::IntoIter {
@@ -156,7 +193,7 @@ impl<'a, const N: usize, T> IntoIterator for &'a InplaceVec<N, T> {
     }
 }
 
-impl<'a, const N: usize, T> IntoIterator for &'a mut InplaceVec<N, T> {
+impl<'a, const N: usize, T, L: LenType> IntoIterator for &'a mut InplaceVec<N, T, L> {
     type Item = &'a mut T;
     type IntoIter = slice::IterMut<'a, T>;
     fn into_iter(self) -> Self::IntoIter {
@@ -0,0 +1,91 @@
+use std::mem::{self, MaybeUninit};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// [`InplaceVec`]中用于存储长度的类型。
+///
+/// 对于`InplaceVec<4, u8>`来说，缓冲区只占4字节，而一个[`usize`]的长
+/// 度却要占8字节（再加上对齐），因此我们允许使用更小的无符号整数来
+/// 存储长度，例如`InplaceVec<4, u8, u8>`只占5字节。
+///
+/// 理想情况下，长度的类型应该根据`N`自动选择，但在stable中，无法根据
+/// const generic的值来选择类型（这需要`generic_const_exprs`），因此我
+/// 们将其作为一个默认为`usize`的类型参数。`N`是否超过该类型的表示范围
+/// 会在构造时通过inline const检查。
+///
+/// 该trait是sealed的，只为[`u8`]、[`u16`]、[`u32`]和[`usize`]实现。
+///
+/// ```compile_fail
+/// use rust_practice::collection::inplace_vec::InplaceVec;
+///
+/// // 256超过了u8的表示范围
+/// let vec = InplaceVec::<256, i32, u8>::new();
+/// ```
+///
+/// [`InplaceVec`]: crate::collection::inplace_vec::InplaceVec
+pub trait LenType: private::Sealed + Copy + 'static {
+    /// 该类型能够表示的最大长度
+    const MAX: usize;
+}
+
+macro_rules! impl_len_type {
+    ($($ty:ty),*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl LenType for $ty {
+                const MAX: usize = if mem::size_of::<$ty>() >= mem::size_of::<usize>() {
+                    usize::MAX
+                } else {
+                    <$ty>::MAX as usize
+                };
+            }
+        )*
+    };
+}
+
+impl_len_type!(u8, u16, u32, usize);
+
+/// 将`L`转换为`usize`。
+///
+/// 由于在stable中无法在`const fn`中调用trait方法，而[`InplaceVec::len`]
+/// 等方法是`const`的，因此我们不能使用`Into<usize>`之类的trait。好在
+/// [`LenType`]是sealed的，大小相同就意味着是同一个无符号整数类型（32位
+/// 平台上的`usize`与`u32`表示相同），因此可以按大小读取后用`as`转换。
+///
+/// [`InplaceVec::len`]: crate::collection::inplace_vec::InplaceVec::len
+#[inline]
+pub(super) const fn to_usize<L: LenType>(len: &L) -> usize {
+    let ptr = (len as *const L).cast::<()>();
+    unsafe {
+        match mem::size_of::<L>() {
+            1 => *ptr.cast::<u8>() as usize,
+            2 => *ptr.cast::<u16>() as usize,
+            4 => *ptr.cast::<u32>() as usize,
+            _ => *ptr.cast::<usize>(),
+        }
+    }
+}
+
+/// 将`usize`转换为`L`，与[`to_usize`]相反。
+///
+/// 调用者需要保证`n <= L::MAX`，否则`as`会截断高位。[`InplaceVec`]在
+/// 构造时已经检查了`N <= L::MAX`，而长度总是不超过`N`。
+///
+/// [`InplaceVec`]: crate::collection::inplace_vec::InplaceVec
+#[inline]
+pub(super) const fn from_usize<L: LenType>(n: usize) -> L {
+    let mut len = MaybeUninit::<L>::uninit();
+    let ptr = len.as_mut_ptr().cast::<()>();
+    unsafe {
+        match mem::size_of::<L>() {
+            1 => ptr.cast::<u8>().write(n as u8),
+            2 => ptr.cast::<u16>().write(n as u16),
+            4 => ptr.cast::<u32>().write(n as u32),
+            _ => ptr.cast::<usize>().write(n),
+        }
+        len.assume_init()
+    }
+}
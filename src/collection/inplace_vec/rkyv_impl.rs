@@ -0,0 +1,134 @@
+use std::fmt;
+use std::ops::Deref;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::collection::inplace_vec::{CapacityError, InplaceVec, LenType};
+
+/// 归档后的[`InplaceVec`]。
+///
+/// 归档格式与[`ArchivedVec`]相同，元素存放在归档的其他位置，因此归档
+/// 本身的大小与`N`无关。不同之处在于长度不能超过`N`：校验时（见
+/// [`CheckBytes`]的实现）会拒绝长度超过`N`的归档，因此对于不可信的数据，
+/// 通过`rkyv::access`得到的`ArchivedInplaceVec`的长度一定不超过`N`。
+///
+/// ```rust
+/// use rkyv::rancor::Error;
+/// use rust_practice::collection::inplace_vec::{ArchivedInplaceVec, InplaceVec};
+/// use rust_practice::inplace_vec;
+///
+/// let vec: InplaceVec<4, u32> = inplace_vec![1, 2, 3];
+/// let bytes = rkyv::to_bytes::<Error>(&vec).unwrap();
+/// let archived = rkyv::access::<ArchivedInplaceVec<4, rkyv::Archived<u32>>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.len(), 3);
+///
+/// // 同样的数据不能作为容量为2的`InplaceVec`访问
+/// assert!(rkyv::access::<ArchivedInplaceVec<2, rkyv::Archived<u32>>, Error>(&bytes).is_err());
+/// ```
+#[repr(transparent)]
+pub struct ArchivedInplaceVec<const N: usize, T> {
+    inner: ArchivedVec<T>,
+}
+
+impl<const N: usize, T> ArchivedInplaceVec<N, T> {
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+}
+
+impl<const N: usize, T> Deref for ArchivedInplaceVec<N, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize, T: fmt::Debug> fmt::Debug for ArchivedInplaceVec<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// SAFETY: `repr(transparent)`，与`ArchivedVec<T>`的布局相同
+unsafe impl<const N: usize, T: Portable> Portable for ArchivedInplaceVec<N, T> {}
+
+/// 在校验`ArchivedVec`之前先检查长度，超过`N`时返回[`CapacityError`]，
+/// 这样过长的归档不需要校验其中的元素就会被拒绝。
+///
+/// `ArchivedVec`的长度字段是一个整数，任意的位模式都是合法的，因此在
+/// 校验之前读取它是安全的。
+unsafe impl<const N: usize, T, C> CheckBytes<C> for ArchivedInplaceVec<N, T>
+where
+    ArchivedVec<T>: CheckBytes<C>,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        let len = unsafe { (*value).inner.len() };
+        if len > N {
+            return Err(Source::new(CapacityError::new((), len, N)));
+        }
+        unsafe { ArchivedVec::<T>::check_bytes(value.cast(), context) }
+    }
+}
+
+impl<const N: usize, T: Archive, L: LenType> Archive for InplaceVec<N, T, L> {
+    type Archived = ArchivedInplaceVec<N, T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `ArchivedInplaceVec`是`ArchivedVec`的`repr(transparent)`包装
+        let out = unsafe { out.cast_unchecked::<ArchivedVec<T::Archived>>() };
+        ArchivedVec::resolve_from_slice(self, resolver, out);
+    }
+}
+
+impl<const N: usize, T, L, S> Serialize<S> for InplaceVec<N, T, L>
+where
+    T: Serialize<S>,
+    L: LenType,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self, serializer)
+    }
+}
+
+/// 经过校验的归档长度一定不超过`N`，但通过`access_unchecked`得到的归档
+/// 没有这样的保证，因此反序列化时仍然会检查长度，而不是`panic`。
+impl<const N: usize, T, L, D> Deserialize<InplaceVec<N, T, L>, D>
+    for ArchivedInplaceVec<N, T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    L: LenType,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<InplaceVec<N, T, L>, D::Error> {
+        if self.len() > N {
+            return Err(Source::new(CapacityError::new((), self.len(), N)));
+        }
+
+        let mut vec = InplaceVec::new();
+        for item in self.iter() {
+            vec.push(item.deserialize(deserializer)?);
+        }
+        Ok(vec)
+    }
+}
+
+impl<const N: usize, T: PartialEq<U>, U, L: LenType> PartialEq<InplaceVec<N, U, L>>
+    for ArchivedInplaceVec<N, T>
+{
+    fn eq(&self, other: &InplaceVec<N, U, L>) -> bool {
+        self.as_slice().eq(&**other)
+    }
+}
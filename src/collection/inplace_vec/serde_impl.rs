@@ -0,0 +1,60 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, SeqAccess, Visitor},
+};
+
+use crate::collection::inplace_vec::{InplaceVec, LenType};
+
+/// 序列化为由已初始化元素组成的序列。
+impl<const N: usize, T: Serialize, L: LenType> Serialize for InplaceVec<N, T, L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+struct InplaceVecVisitor<const N: usize, T, L> {
+    _marker: PhantomData<(T, L)>,
+}
+
+impl<'de, const N: usize, T: Deserialize<'de>, L: LenType> Visitor<'de>
+    for InplaceVecVisitor<N, T, L>
+{
+    type Value = InplaceVec<N, T, L>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of at most {N} elements")
+    }
+
+    /// 如果格式提供了长度提示，我们会提前拒绝过长的序列。但并不是所有
+    /// 的格式都会提供长度提示，因此在写入每个元素之前仍然需要检查容量，
+    /// 而不是依赖[`InplaceVec::push`]的`panic`。
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        if let Some(len) = seq.size_hint()
+            && len > N
+        {
+            return Err(de::Error::invalid_length(len, &self));
+        }
+
+        let mut vec = InplaceVec::new();
+        while let Some(elem) = seq.next_element()? {
+            if vec.len() == N {
+                return Err(de::Error::invalid_length(N + 1, &self));
+            }
+            vec.push(elem);
+        }
+        Ok(vec)
+    }
+}
+
+/// 反序列化时会检查容量，元素个数超过`N`时返回错误，而不是`panic`。
+impl<'de, const N: usize, T: Deserialize<'de>, L: LenType> Deserialize<'de>
+    for InplaceVec<N, T, L>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(InplaceVecVisitor {
+            _marker: PhantomData,
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use std::{iter::FusedIterator, ops::RangeBounds};
+
+use crate::collection::inplace_vec::{Drain, InplaceVec, LenType};
+
+/// [`InplaceVec::splice`]返回的迭代器。
+///
+/// 迭代时产出被移除的元素，在`drop`时将`replace_with`中的元素插入到
+/// 被移除的范围处。
+///
+/// 插入的过程分为两步：
+///
+/// 1. 首先使用替换的元素填充被移除的范围（空洞），如果替换的元素不
+///    多于被移除的元素，之后由[`Drain`]的析构函数负责将尾部向前移动；
+/// 2. 否则，将尾部移动到缓冲区的末端以扩大空洞，并继续填充。
+///
+/// 如果此时仍然有剩余的替换元素，则说明最终长度会超过`N`，此时会
+/// `panic`。在`panic`时，`InplaceVec`仍然是有效的：能够容纳下的替换
+/// 元素都会被保留，多出来的元素和`replace_with`中剩余的元素会被`drop`。
+pub struct Splice<'a, const N: usize, I: Iterator + 'a, L: LenType = usize> {
+    drain: Drain<'a, N, I::Item, L>,
+    replace_with: I,
+}
+
+impl<'a, const N: usize, I: Iterator, L: LenType> Iterator for Splice<'a, N, I, L> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<'a, const N: usize, I: Iterator, L: LenType> DoubleEndedIterator for Splice<'a, N, I, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<'a, const N: usize, I: Iterator, L: LenType> ExactSizeIterator for Splice<'a, N, I, L> {}
+
+impl<'a, const N: usize, I: Iterator, L: LenType> FusedIterator for Splice<'a, N, I, L> {}
+
+impl<'a, const N: usize, I: Iterator, L: LenType> Drop for Splice<'a, N, I, L> {
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+
+        // SAFETY:
+        // 上面已经取出了所有被drain的元素。
+        unsafe {
+            if !self.drain.fill(&mut self.replace_with) {
+                return;
+            }
+
+            self.drain.move_tail_to_end();
+            if !self.drain.fill(&mut self.replace_with) {
+                return;
+            }
+        }
+
+        if self.replace_with.next().is_some() {
+            panic!("InplaceVec splice overflow: the result would exceed capacity {N}");
+        }
+    }
+}
+
+impl<const N: usize, T, L: LenType> InplaceVec<N, T, L> {
+    /// 移除`range`范围内的元素，并使用`replace_with`中的元素替换它们。
+    /// 返回的迭代器会产出被移除的元素。
+    ///
+    /// `replace_with`只有在返回的[`Splice`]被`drop`时才会被消费。
+    ///
+    /// ## Panics
+    ///
+    /// - 如果`range`越界，则会`panic`
+    /// - 如果替换之后的长度超过`N`，则会在[`Splice`]被`drop`时`panic`，
+    ///   具体行为见[`Splice`]
+    ///
+    /// ```rust
+    /// use rust_practice::collection::inplace_vec::InplaceVec;
+    ///
+    /// let mut vec: InplaceVec<5, i32> = InplaceVec::try_from(&[1, 2, 3, 4]).unwrap();
+    /// let removed: Vec<_> = vec.splice(1..3, [7, 8, 9]).collect();
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(vec, [1, 7, 8, 9, 4]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, N, I::IntoIter, L>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::{fmt, io, ptr};
+
+use crate::collection::inplace_vec::{InplaceVec, LenType};
+
+impl<const N: usize, L: LenType> InplaceVec<N, u8, L> {
+    /// 将`bytes`中尽可能多的字节复制到剩余的容量中，返回复制的字节数。
+    fn write_truncated(&mut self, bytes: &[u8]) -> usize {
+        let spare = self.spare_capacity_mut();
+        let count = bytes.len().min(spare.len());
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), spare.as_mut_ptr().cast::<u8>(), count);
+            self.set_len(self.len() + count);
+        }
+        count
+    }
+}
+
+/// 将[`InplaceVec<N, u8>`]作为一个固定大小的写入目标。
+///
+/// `write`会写入尽可能多的字节并返回写入的字节数，对于[`io::Write`]
+/// 来说，这种“短写入”是合法的。当缓冲区已满时，`write`返回`Ok(0)`，
+/// 此时默认的[`io::Write::write_all`]会返回[`io::ErrorKind::WriteZero`]。
+impl<const N: usize, L: LenType> io::Write for InplaceVec<N, u8, L> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(self.write_truncated(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// 使得`write!(buf, "...")`可以直接格式化到[`InplaceVec<N, u8>`]中。
+///
+/// 与[`io::Write`]不同，每次`write_str`要么完整写入，要么不写入任何
+/// 内容并返回[`fmt::Error`]，因此缓冲区中不会出现被截断的片段。
+impl<const N: usize, L: LenType> fmt::Write for InplaceVec<N, u8, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.len() > N - self.len() {
+            return Err(fmt::Error);
+        }
+        self.write_truncated(s.as_bytes());
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+use crate::collection::string::MyString;
+use crate::collection::vec::MyVec;
+
+/// [`Interner`]返回的符号，是字符串在`Interner`中的编号。
+///
+/// 同一个`Interner`中，内容相同的字符串总是得到相同的符号，因此比较符号
+/// 等价于比较字符串的内容，但只需要比较一个`u32`。符号的顺序是字符串被
+/// 首次插入的顺序，而不是字符串的字典序。
+///
+/// 符号不记录它来自哪个`Interner`，使用其他`Interner`的符号是一个逻辑错
+/// 误：可能`panic`，也可能得到一个无关的字符串，但不会导致未定义行为。
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    #[inline]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn as_usize(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// 字符串驻留池：对字符串去重，并使用[`Symbol`]引用它们。
+///
+/// 所有字符串的内容被连续地存储在同一个[`MyString`]中，`spans`记录了每个
+/// 符号对应的字节范围`(start, end)`。为了避免使用`HashMap`，我们另外维护
+/// 一个按字符串内容排序的符号数组`sorted`，查找时对其进行二分查找，插入
+/// 新的字符串时需要移动其后的元素。
+///
+/// 已经插入的字符串永远不会被移除。
+///
+/// ```rust
+/// use rust_practice::collection::interner::Interner;
+///
+/// let mut interner = Interner::new();
+/// let a = interner.intern("foo");
+/// let b = interner.intern("bar");
+/// assert_ne!(a, b);
+/// assert_eq!(interner.intern("foo"), a);
+/// assert_eq!(interner.resolve(b), "bar");
+/// assert_eq!(interner.len(), 2);
+/// assert_eq!(interner.byte_len(), 6);
+/// ```
+#[derive(Clone, Default)]
+pub struct Interner {
+    buf: MyString,
+    spans: MyVec<(u32, u32)>,
+    sorted: MyVec<Symbol>,
+}
+
+impl Interner {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            buf: MyString::new(),
+            spans: MyVec::new(),
+            sorted: MyVec::new(),
+        }
+    }
+
+    /// 已经插入的（不同的）字符串的数量。
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// 所有字符串的总字节数。
+    #[inline]
+    pub fn byte_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// 返回`string`的符号。如果`string`还没有被插入，则将其复制到缓冲区
+    /// 的末尾并分配一个新的符号。
+    ///
+    /// ## Panics
+    ///
+    /// 字符串的数量或者总字节数超过[`u32::MAX`]时`panic`。
+    pub fn intern(&mut self, string: &str) -> Symbol {
+        let index = match self.search(string) {
+            Ok(index) => return self.sorted[index],
+            Err(index) => index,
+        };
+
+        let start = self.buf.len();
+        let end = start
+            .checked_add(string.len())
+            .filter(|&end| end <= u32::MAX as usize)
+            .expect("Interner overflow: buffer exceeds u32::MAX bytes");
+        let symbol = u32::try_from(self.spans.len())
+            .map(Symbol)
+            .expect("Interner overflow: too many symbols");
+
+        self.buf.push_str(string);
+        self.spans.push((start as u32, end as u32));
+        self.sorted.insert(index, symbol);
+        symbol
+    }
+
+    /// 查找`string`的符号，不会插入新的字符串。
+    #[inline]
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        self.search(string).ok().map(|index| self.sorted[index])
+    }
+
+    #[inline]
+    pub fn contains(&self, string: &str) -> bool {
+        self.search(string).is_ok()
+    }
+
+    /// 返回符号对应的字符串，如果符号不属于当前`Interner`，则返回`None`。
+    #[inline]
+    pub fn try_resolve(&self, symbol: Symbol) -> Option<&str> {
+        let &(start, end) = self.spans.get(symbol.as_usize())?;
+        Some(&self.buf[start as usize..end as usize])
+    }
+
+    /// ## Panics
+    ///
+    /// 符号不属于当前`Interner`时`panic`。
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        self.try_resolve(symbol)
+            .expect("symbol does not belong to this Interner")
+    }
+
+    /// 按符号的顺序（即插入的顺序）迭代所有字符串。
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            interner: self,
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// 在`sorted`中二分查找`string`。
+    fn search(&self, string: &str) -> Result<usize, usize> {
+        self.sorted
+            .binary_search_by(|&symbol| self.resolve(symbol).cmp(string))
+    }
+}
+
+impl fmt::Debug for Interner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> Extend<&'a str> for Interner {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for string in iter {
+            self.intern(string);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Interner {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<'a> IntoIterator for &'a Interner {
+    type Item = (Symbol, &'a str);
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// [`Interner::iter`]返回的迭代器，产生`(Symbol, &str)`。
+#[derive(Clone)]
+pub struct Iter<'a> {
+    interner: &'a Interner,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iter<'a> {
+    #[inline]
+    fn item(&self, index: usize) -> (Symbol, &'a str) {
+        let symbol = Symbol(index as u32);
+        (symbol, self.interner.resolve(symbol))
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (Symbol, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.item(self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.item(self.back))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
@@ -1,23 +1,157 @@
+//! 管理一段未初始化的内存，用于构建自定义的容器。
+
 use std::alloc::{self, Layout};
-use std::mem;
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::mem::{self, ManuallyDrop};
 use std::ptr::NonNull;
 
+/// 一次分配最多占用的字节数，见[`checked_alloc_size`]。
+const MAX_BYTES: usize = isize::MAX as usize;
+
+/// 所有容量溢出的`panic`都使用该信息。
+const CAPACITY_OVERFLOW: &str = "capacity overflow";
+
+/// 检查`cap`个大小为`elem_size`字节的元素能否放入一次分配中，并返回所
+/// 需的字节数。
+///
+/// 要求字节数不超过`max_bytes`，并且元素个数本身不超过`isize::MAX`（对
+/// ZST同样如此，见[`RawBuf::new`]）。[`RawBuf`]中所有的容量计算都通过
+/// 该函数检查，其中`max_bytes`总是`isize::MAX`；将它作为参数，是为了能
+/// 够在64位平台上模拟32位平台的上限。
+///
+/// ```rust
+/// use rust_practice::collection::raw::checked_alloc_size;
+///
+/// // 32位平台上，`isize::MAX`为`i32::MAX`
+/// let max = i32::MAX as usize;
+/// assert_eq!(checked_alloc_size(4, (1 << 29) - 1, max), Some((1 << 31) - 4));
+/// assert_eq!(checked_alloc_size(4, 1 << 29, max), None);
+/// assert_eq!(checked_alloc_size(0, usize::MAX, max), None);
+/// ```
+pub const fn checked_alloc_size(elem_size: usize, cap: usize, max_bytes: usize) -> Option<usize> {
+    if cap > isize::MAX as usize {
+        return None;
+    }
+    match elem_size.checked_mul(cap) {
+        Some(bytes) if bytes <= max_bytes => Some(bytes),
+        _ => None,
+    }
+}
+
+/// 按倍数增长时的新容量：至少为`required`，通常为`cap`的两倍，但不会超
+/// 过[`checked_alloc_size`]所允许的最大容量。`required`本身无法分配时
+/// 返回`None`。
+///
+/// 这样在接近上限时，即使`2 * cap`无法分配，只要`required`可以分配，扩
+/// 容仍然会成功。
+///
+/// ```rust
+/// use rust_practice::collection::raw::amortized_capacity;
+///
+/// let max = i32::MAX as usize;
+/// assert_eq!(amortized_capacity(4, 10, 11, max), Some(20));
+/// // `2 * cap`超过了上限，退化为允许的最大容量
+/// assert_eq!(amortized_capacity(4, 1 << 28, (1 << 28) + 1, max), Some((1 << 29) - 1));
+/// assert_eq!(amortized_capacity(4, 1 << 28, 1 << 29, max), None);
+/// ```
+pub fn amortized_capacity(
+    elem_size: usize,
+    cap: usize,
+    required: usize,
+    max_bytes: usize,
+) -> Option<usize> {
+    checked_alloc_size(elem_size, required, max_bytes)?;
+
+    let max_cap = match max_bytes.checked_div(elem_size) {
+        Some(max_cap) => cmp::min(max_cap, isize::MAX as usize),
+        None => isize::MAX as usize,
+    };
+    Some(cmp::max(required, cap.saturating_mul(2)).min(max_cap))
+}
+
 /// 源自The Rustonomicon
 ///
 /// ## 类型介绍
 ///
-/// [`MyRawVec`]是`ptr`和`cap`元组的抽象，其目的是合并
-/// [`MyVec`]和[`IntoIter`]当中重复的逻辑。
+/// [`RawBuf`]是`ptr`和`cap`元组的抽象，其目的是合并
+/// [`MyVec`]、[`IntoIter`]以及其他基于连续内存的容器（如
+/// [`MyVecDeque`]、[`GapBuffer`]）当中重复的逻辑。
 ///
-/// `MyRawVec`用于管理内存的分配、释放和容量增长逻辑。
+/// `RawBuf`用于管理内存的分配、释放和容量增长逻辑。
 ///
 /// 其包含[`NonNull<T>`]类型的`ptr`（表示指向分配的内存
 /// 空间）和[`usize`]类型的`cap`（表示最多可以容纳的元
 /// 素个数）
 ///
+/// ## 安全约定
+///
+/// `RawBuf`**从不**读取、写入或者`drop`其中的元素，它只知道`cap`，
+/// 不知道哪些位置已经被初始化。因此：
+///
+/// - 记录哪些位置已经初始化（例如`len`）是使用者的责任；
+/// - 扩容时（[`RawBuf::reserve`]等）`0..cap`中的字节会被原样复制到新
+///   的内存中，之后原来的指针失效，需要重新调用[`RawBuf::ptr`]；
+/// - 缩容（[`RawBuf::shrink`]）时`new_cap..cap`中的内容会被丢弃，其中
+///   的元素需要使用者提前移走或者`drop`；
+/// - `RawBuf`被`drop`时只释放内存，其中的元素需要使用者提前`drop`。
+///
+/// 对于ZST，`RawBuf`从不分配内存，`cap`始终为[`isize::MAX`]。
+///
+/// ## 示例
+///
+/// 一个最小的栈：
+///
+/// ```rust
+/// use std::ptr;
+///
+/// use rust_practice::collection::raw::RawBuf;
+///
+/// struct Stack<T> {
+///     buf: RawBuf<T>,
+///     len: usize,
+/// }
+///
+/// impl<T> Stack<T> {
+///     fn push(&mut self, value: T) {
+///         self.buf.reserve(self.len, 1);
+///         unsafe { ptr::write(self.buf.ptr().as_ptr().add(self.len), value) };
+///         self.len += 1;
+///     }
+///
+///     fn pop(&mut self) -> Option<T> {
+///         if self.len == 0 {
+///             return None;
+///         }
+///         self.len -= 1;
+///         unsafe { Some(ptr::read(self.buf.ptr().as_ptr().add(self.len))) }
+///     }
+/// }
+///
+/// // `RawBuf`只释放内存，元素需要我们自己`drop`
+/// impl<T> Drop for Stack<T> {
+///     fn drop(&mut self) {
+///         while self.pop().is_some() {}
+///     }
+/// }
+///
+/// let mut stack = Stack { buf: RawBuf::new(), len: 0 };
+/// for i in 0..10 {
+///     stack.push(i.to_string());
+/// }
+/// assert!(stack.buf.cap() >= 10);
+/// assert_eq!(stack.pop().as_deref(), Some("9"));
+/// ```
+///
+/// [`MyVec`]: crate::collection::vec::MyVec
+/// [`IntoIter`]: crate::collection::vec::IntoIter
+/// [`MyVecDeque`]: crate::collection::vec_deque::MyVecDeque
+/// [`GapBuffer`]: crate::collection::gap_buffer::GapBuffer
+///
 /// ## 成员类型选择
 ///
-/// `ptr`不应当使用`*mut T`，其原因是在此情况下，`MyRawVec<T>`
+/// `ptr`不应当使用`*mut T`，其原因是在此情况下，`RawBuf<T>`
 /// 在`T`上是不变的(invariant)。这就导致`MyVec<T>`在`T`
 /// 上是不变的。也就是说，一个`&MyVec<&'static str>`不
 /// 能传给需要`&MyVec<&'a str>`的地方。
@@ -40,12 +174,12 @@ use std::ptr::NonNull;
 ///
 /// 如果我需要表明我拥有类型T，如果仅仅是有一个指向存储
 /// T的指针是不够的，这将会导致对T的访问在任意生命周期
-/// 都会有效，此时可以使用[`PhantomData<T>`]来表明该类
+/// 都会有效，此时可以使用[`PhantomData<T>`](std::marker::PhantomData)来表明该类
 /// 型拥有T，因此T的生命周期与该类型的生命周期相同。
 ///
 /// 但在`RFC 1238`之后，任何包含[`Drop`]的类型都隐含的
 /// 被认为持有其`Drop`中使用的泛型类型的所有权。这里是
-/// `impl<T> Drop for MyRawVec<T>`，因此，`MyRawVec<T>`
+/// `impl<T> Drop for RawBuf<T>`，因此，`RawBuf<T>`
 /// 拥有T。
 ///
 /// 此处使用`PhantomData<T>`是为了另外一个意图，示例如
@@ -93,7 +227,7 @@ use std::ptr::NonNull;
 /// 当泛型参数**以拥有的方式被结构体字段使用**时，才会生
 /// 效，也就是`RFC 1238`不再适用。我们必须手动使用`PhantomData<T>`
 #[derive(Debug)]
-pub(super) struct MyRawVec<T> {
+pub struct RawBuf<T> {
     ptr: NonNull<T>,
     cap: usize,
 }
@@ -104,22 +238,24 @@ pub(super) struct MyRawVec<T> {
 /// 需要手动去实现。
 ///
 /// 一个类型是`Send`的，必须保证该类型可以被安全的发送到另
-/// 外一个线程。如果[`MyVec`]中所拥有的元素是`Send`的，则
+/// 外一个线程。如果`MyVec`中所拥有的元素是`Send`的，则
 /// 整个`MyVec`当然就可以发送到另外一个线程。
 ///
 /// 一个类型是`Sync`的，必须保证该类型可以安全的在线程之间
 /// 共享，即`T`是`Sync`当且仅当`&T`是`Send`。如果`MyVec`中
 /// 所拥有的元素是`Sync`的，则整个`MyVec`当然可以安全的在线
 /// 程之间共享。
-unsafe impl<T: Send> Send for MyRawVec<T> {}
-unsafe impl<T: Sync> Sync for MyRawVec<T> {}
+unsafe impl<T: Send> Send for RawBuf<T> {}
+unsafe impl<T: Sync> Sync for RawBuf<T> {}
 
-impl<T> MyRawVec<T> {
+impl<T> RawBuf<T> {
+    /// 指向缓冲区开头的指针，`cap == 0`或者ZST时是悬垂的，但总是对齐的。
     #[inline]
     pub const fn ptr(&self) -> NonNull<T> {
         self.ptr
     }
 
+    /// 最多可以容纳的元素个数，ZST为`isize::MAX`。
     #[inline]
     pub const fn cap(&self) -> usize {
         self.cap
@@ -142,7 +278,7 @@ impl<T> MyRawVec<T> {
     /// [`NonNull::dangling`]，这其实就是存入了[`mem::align_of`]。
     /// 我们应当始终保证`ptr`是指向对齐的内存的，即使我们不去访问
     /// 它。原因是，可能会有外部的代码去获取该指针，并使用
-    /// [`ptr::read`]尝试去读取这块内存，而`ptr::read`要求必须对
+    /// [`ptr::read`](std::ptr::read)尝试去读取这块内存，而`ptr::read`要求必须对
     /// 齐。虽然读取长度为0字节的内存时（也就是此处讨论的情况），
     /// `ptr::read`其实什么都不做，但还是要避免这种情况。
     ///
@@ -157,14 +293,14 @@ impl<T> MyRawVec<T> {
     ///
     /// 对于ZST来说，不存在所谓的内存溢出或者offset有符号数的问题
     /// （因为任何指针偏移操作都被认为是无操作），所以可以将其容
-    /// 量设置为`usize::MAX`。但此处需要考虑到在[`RawValIter`]中，
+    /// 量设置为`usize::MAX`。但此处需要考虑到在`RawValIter`中，
     /// 我们对ZST进行了特殊讨论，`start`是`NonNull::dangling`，而
     /// `end as usize - start as usize`是元素数量，考录到如果`size`
     /// 为`usize::MAX`，则end必然会溢出，考虑到这个问题，我们选择
     /// 将其设置为[`isize::MAX`]。
     ///
     /// 相关问题见[rust-lang/nomicon#433](https://github.com/rust-lang/nomicon/issues/433)
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         // 下面的分支可以在编译期确定。
         let cap = if mem::size_of::<T>() == 0 {
             isize::MAX as usize
@@ -173,7 +309,7 @@ impl<T> MyRawVec<T> {
         };
 
         // `NonNull::dangling()`既可以表示未分配空间，也可以表示分配大小为0的空间
-        MyRawVec {
+        RawBuf {
             ptr: NonNull::dangling(),
             cap,
         }
@@ -243,8 +379,11 @@ impl<T> MyRawVec<T> {
     /// 能超过`isize::MAX`字节。
     ///
     /// 对于64位平台来说，这完全是OK的，因为一般不会有这么大的内
-    /// 存。但对于32位平台来说就有可能会分配这么大的空间。此处我
-    /// 不做考虑。
+    /// 存。但对于32位平台来说，`isize::MAX`只有2GB，是可能真正被触
+    /// 及的：此时`2 * cap`的字节数可能超过`isize::MAX`，而`cap + 1`
+    /// 却仍然可以分配。因此所有的容量计算都通过[`checked_alloc_size`]
+    /// 检查字节数，按倍数增长时使用[`amortized_capacity`]，将新的容量
+    /// 限制在允许的最大容量以内，而不是直接`panic`。
     ///
     /// ## 关于ZST的问题
     ///
@@ -255,80 +394,180 @@ impl<T> MyRawVec<T> {
     /// 一般来说，我们会认为在0x01处存在一个可以存放无限多个ZST元
     /// 素的空间，该空间不能为0x00，因为不能使用该地址，此外，整个
     /// 内存的第一页（一般是前4KB空间）一般是受到保护不会被分配的。
-    pub fn grow(&mut self) {
-        // 由于我们已经将ZST的容量设置为isize::MAX了，所以如果ZST
-        // 执行了这个函数必然表示其容量溢出了。
-        assert!(mem::size_of::<T>() != 0, "capacity overflow");
+    pub(crate) fn grow(&mut self) {
+        // 由于我们已经将ZST的容量设置为isize::MAX了，所以如果ZST执行了
+        // 这个函数，`cap + 1`必然会溢出，`required_cap`会`panic`。
+        //
+        // `cap == 0`时新的容量为1，否则为`2 * cap`（不超过最大容量）。
+        self.reserve(self.cap, 1);
+    }
 
-        let (new_cap, new_layout) = if self.cap == 0 {
-            (1, Layout::array::<T>(1).unwrap())
-        } else {
-            // 由于此处self.cap <= isize::MAX的，所以下面的表达式不会溢出
-            let new_cap = 2 * self.cap;
-
-            // `Layout::array`会检查字节数是小于等于isize::MAX的，但由于
-            // 这正是我们希望检查的，我们希望在字节数超过isize::MAX时直接
-            // panic。
-            let new_layout = Layout::array::<T>(new_cap).expect("Allocation too large");
-            (new_cap, new_layout)
-        };
+    /// 申请恰好能容纳`capacity`个元素的内存，ZST不会申请内存。
+    ///
+    /// ## Panics
+    ///
+    /// 所需的字节数超过`isize::MAX`时`panic`，内存分配失败时调用
+    /// [`alloc::handle_alloc_error`]终止程序。
+    pub fn with_capacity(capacity: usize) -> Self {
+        match Self::try_with_capacity(capacity) {
+            Ok(buf) => buf,
+            Err(TryReserveError::CapacityOverflow) => panic!("{CAPACITY_OVERFLOW}"),
+            Err(TryReserveError::AllocError { layout }) => alloc::handle_alloc_error(layout),
+        }
+    }
 
-        // SAFETY:
-        // 注意，使用realloc申请0字节空间是未定义行为，但在此处，我们
-        // 保证其大小至少为1字节。ZST类型的`cap`永远都是`isize::MAX`，
-        // 所以应该不会执行此处的代码。
-        let new_ptr = unsafe { self.try_alloc_nonzeroed(new_layout) };
+    /// 与[`RawBuf::with_capacity`]相同，但在容量溢出或者分配失败时返回
+    /// 错误，而不是`panic`或者终止程序。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::raw::{RawBuf, TryReserveError};
+    ///
+    /// let buf = RawBuf::<u64>::try_with_capacity(16).unwrap();
+    /// assert_eq!(buf.cap(), 16);
+    ///
+    /// let err = RawBuf::<u64>::try_with_capacity(usize::MAX / 4).unwrap_err();
+    /// assert_eq!(err, TryReserveError::CapacityOverflow);
+    /// ```
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut ret = Self::new();
+        if mem::size_of::<T>() == 0 || capacity == 0 {
+            return Ok(ret);
+        }
 
-        self.ptr = Self::handle_alloc_err(new_ptr as *mut T, new_layout);
-        self.cap = new_cap;
+        if checked_alloc_size(mem::size_of::<T>(), capacity, MAX_BYTES).is_none() {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+        let layout = Layout::array::<T>(capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+        let ptr = unsafe { ret.try_alloc_new(layout) };
+        ret.ptr = NonNull::new(ptr as *mut T).ok_or(TryReserveError::AllocError { layout })?;
+        ret.cap = capacity;
+        Ok(ret)
     }
 
-    pub fn with_capacity(capacity: usize) -> Self {
-        let mut ret = Self::new();
-        if mem::size_of::<T>() != 0 && capacity > 0 {
-            let layout = Layout::array::<T>(capacity).expect("Allocation too large");
-            let ptr = unsafe { ret.try_alloc_new(layout) };
+    /// 保证至少能容纳`len + additional`个元素，其中`len`是使用者已经初
+    /// 始化的元素个数。
+    ///
+    /// 容量不足时按倍数增长（至少为原来的两倍），使得连续插入的均摊复
+    /// 杂度为`O(1)`。需要恰好的容量时使用[`RawBuf::reserve_exact`]。
+    ///
+    /// ## Panics
+    ///
+    /// `len + additional`溢出，或者所需的字节数超过`isize::MAX`时`panic`。
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        let required = Self::required_cap(len, additional);
+        if required <= self.cap {
+            return;
+        }
 
-            ret.ptr = Self::handle_alloc_err(ptr as *mut T, layout);
-            ret.cap = capacity;
+        // `required`已经通过了检查，因此总能得到新的容量
+        let new_cap = amortized_capacity(mem::size_of::<T>(), self.cap, required, MAX_BYTES)
+            .expect(CAPACITY_OVERFLOW);
+        // SAFETY: `self.cap < required <= new_cap`，且`new_cap`通过了检查
+        unsafe { self.grow_to(new_cap) };
+    }
+
+    /// 保证恰好能容纳`len + additional`个元素，容量已经足够时什么也不做。
+    ///
+    /// ## Panics
+    ///
+    /// 与[`RawBuf::reserve`]相同。
+    pub fn reserve_exact(&mut self, len: usize, additional: usize) {
+        let required = Self::required_cap(len, additional);
+        if required <= self.cap {
+            return;
         }
-        ret
+
+        // SAFETY: `self.cap < required`，且`required`通过了检查
+        unsafe { self.grow_to(required) };
+    }
+
+    fn required_cap(len: usize, additional: usize) -> usize {
+        len.checked_add(additional)
+            .filter(|&cap| checked_alloc_size(mem::size_of::<T>(), cap, MAX_BYTES).is_some())
+            .expect(CAPACITY_OVERFLOW)
     }
 
     /// ## Safety
-    /// 此处必须保证exact_cap不会超过`isize::MAX`，即使是ZST！
-    pub unsafe fn reserve_exact(&mut self, exact_cap: usize) {
-        if exact_cap <= self.cap {
+    ///
+    /// 必须保证`self.cap < new_cap`，且`new_cap`通过了[`checked_alloc_size`]
+    /// 的检查，此时`T`一定不是ZST。
+    unsafe fn grow_to(&mut self, new_cap: usize) {
+        let new_layout = Layout::array::<T>(new_cap).expect(CAPACITY_OVERFLOW);
+        let new_ptr = self.try_alloc(new_layout);
+
+        self.ptr = Self::handle_alloc_err(new_ptr as *mut T, new_layout);
+        self.cap = new_cap;
+    }
+
+    /// 将容量缩小为`new_cap`，调用者需要保证`new_cap`之后没有需要保留的
+    /// 元素。缩小为0时释放内存并使用[`NonNull::dangling`]。
+    ///
+    /// ZST的容量始终为`isize::MAX`，不做任何处理。
+    ///
+    /// ## Panics
+    ///
+    /// `new_cap`大于当前容量时`panic`。
+    pub fn shrink(&mut self, new_cap: usize) {
+        assert!(new_cap <= self.cap, "Tried to shrink to a larger capacity");
+        if mem::size_of::<T>() == 0 || new_cap == self.cap {
             return;
         }
 
-        let new_layout = Layout::array::<T>(exact_cap).expect("Allocation too large");
+        let new_layout = Layout::array::<T>(new_cap).unwrap();
+        // `new_layout`大小为0时`try_alloc`会释放原有的内存
         let new_ptr = self.try_alloc(new_layout);
 
         self.ptr = Self::handle_alloc_err(new_ptr as *mut T, new_layout);
-        self.cap = exact_cap;
+        self.cap = new_cap;
     }
 
+    /// 由指针和容量构造`RawBuf`，是[`RawBuf::into_parts`]的逆操作。
+    ///
+    /// ## Safety
+    ///
+    /// - 对于非ZST，`cap > 0`时`ptr`必须是由全局分配器以
+    ///   `Layout::array::<T>(cap)`分配的；`cap == 0`时`ptr`应当是
+    ///   [`NonNull::dangling`]
+    /// - 对于ZST，`cap`必须为`isize::MAX`
+    /// - 这块内存的所有权转移给`RawBuf`，之后不能再由其他人释放
+    ///
+    /// ```rust
+    /// use rust_practice::collection::raw::RawBuf;
+    ///
+    /// let buf = RawBuf::<u32>::with_capacity(8);
+    /// let (ptr, cap) = buf.into_parts();
+    /// let buf = unsafe { RawBuf::from_parts(ptr, cap) };
+    /// assert_eq!((buf.ptr(), buf.cap()), (ptr, 8));
+    /// ```
     #[inline]
-    pub unsafe fn from_parts(ptr: NonNull<T>, capacity: usize) -> Self {
-        Self {
-            ptr,
-            cap: capacity,
-        }
+    pub const unsafe fn from_parts(ptr: NonNull<T>, cap: usize) -> Self {
+        Self { ptr, cap }
     }
 
+    /// 与[`RawBuf::from_parts`]相同，但接受裸指针。
+    ///
+    /// ## Safety
+    ///
+    /// 与[`RawBuf::from_parts`]相同，此外`ptr`不能为空。
     #[inline]
-    pub unsafe fn from_raw_parts(ptr: *mut T, capacity: usize) -> Self {
+    pub unsafe fn from_raw_parts(ptr: *mut T, cap: usize) -> Self {
         Self {
             ptr: unsafe { NonNull::new(ptr).unwrap_unchecked() },
-            cap: capacity,
+            cap,
         }
     }
 
+    /// 返回指针和容量，内存的所有权转移给调用者，不会被释放。
+    #[inline]
+    pub fn into_parts(self) -> (NonNull<T>, usize) {
+        let this = ManuallyDrop::new(self);
+        (this.ptr, this.cap)
+    }
+
     /// 如果分配失败了，`new_ptr`会是空指针，对应产生None，此处使用
     /// `alloc::handle_alloc_error`终止程序。
     #[inline]
-    pub fn handle_alloc_err(ptr: *mut T, new_layout: Layout) -> NonNull<T> {
+    fn handle_alloc_err(ptr: *mut T, new_layout: Layout) -> NonNull<T> {
         match NonNull::new(ptr) {
             Some(p) => p,
             None => alloc::handle_alloc_error(new_layout),
@@ -336,7 +575,7 @@ impl<T> MyRawVec<T> {
     }
 
     #[inline]
-    pub fn try_alloc(&mut self, new_layout: Layout) -> *mut u8 {
+    fn try_alloc(&mut self, new_layout: Layout) -> *mut u8 {
         if new_layout.size() == 0 {
             unsafe { self.try_alloc_zeroed() }
         } else {
@@ -353,7 +592,8 @@ impl<T> MyRawVec<T> {
                 alloc::dealloc(old_ptr, old_layout);
             }
         }
-        NonNull::dangling().as_ptr()
+        // 必须是按`T`对齐的悬垂指针，而不是按`u8`对齐
+        NonNull::<T>::dangling().as_ptr() as *mut u8
     }
 
     #[inline]
@@ -382,10 +622,17 @@ impl<T> MyRawVec<T> {
     }
 }
 
-impl<T> Drop for MyRawVec<T> {
+impl<T> Default for RawBuf<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for RawBuf<T> {
     /// 源自The Rustonomicon
     ///
-    /// 此处我们实现[`MyRawVec::drop`]，由于[`MyRawVec`]仅负责
+    /// 此处我们实现[`RawBuf::drop`]，由于[`RawBuf`]仅负责
     /// 管理内存分配，因此我们不应当干预其中的元素。相反，我们
     /// 认为其中的元素都被合理地drop了。
     ///
@@ -404,3 +651,25 @@ impl<T> Drop for MyRawVec<T> {
         }
     }
 }
+
+/// [`RawBuf::try_with_capacity`]失败时的错误。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// 所需的字节数超过了`isize::MAX`
+    CapacityOverflow,
+    /// 分配器无法分配`layout`大小的内存
+    AllocError { layout: Layout },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityOverflow => f.write_str("capacity overflow"),
+            Self::AllocError { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+impl Error for TryReserveError {}
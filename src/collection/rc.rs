@@ -0,0 +1,220 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::process;
+use std::ptr::NonNull;
+
+use crate::collection::boxed::MyBox;
+
+/// 引用计数和值被分配在同一块内存中。
+struct RcInner<T> {
+    strong: Cell<usize>,
+    value: T,
+}
+
+/// 单线程的引用计数指针，与[`std::rc::Rc`]类似。
+///
+/// 我们使用[`MyBox`]分配`RcInner`，通过[`MyBox::into_raw`]取得其中的指
+/// 针，最后一个`MyRc`被`drop`时再使用[`MyBox::from_raw`]将其还原并释放。
+///
+/// 引用计数使用[`Cell`]存储，不是原子的，因此`MyRc`既不是`Send`也不是
+/// `Sync`。由于我们持有的是[`NonNull`]，这两个trait不会被自动实现，不需
+/// 要额外的标记：
+///
+/// ```rust,compile_fail
+/// use rust_practice::collection::rc::MyRc;
+///
+/// fn assert_send<T: Send>(_: T) {}
+/// assert_send(MyRc::new(1));
+/// ```
+///
+/// 目前还没有实现弱引用（`Weak`），因此引用计数形成环时会导致内存泄露。
+///
+/// ```rust
+/// use rust_practice::collection::rc::MyRc;
+///
+/// let mut a = MyRc::new(String::from("rc"));
+/// MyRc::get_mut(&mut a).unwrap().push('!');
+/// let b = MyRc::clone(&a);
+/// assert_eq!(MyRc::strong_count(&a), 2);
+/// assert!(MyRc::get_mut(&mut a).is_none());
+/// let a = MyRc::try_unwrap(a).unwrap_err();
+/// drop(b);
+/// assert_eq!(MyRc::try_unwrap(a).unwrap(), "rc!");
+/// ```
+pub struct MyRc<T> {
+    ptr: NonNull<RcInner<T>>,
+    _marker: PhantomData<RcInner<T>>,
+}
+
+impl<T> MyRc<T> {
+    pub fn new(value: T) -> Self {
+        let inner = MyBox::new(RcInner {
+            strong: Cell::new(1),
+            value,
+        });
+        Self {
+            ptr: unsafe { NonNull::new_unchecked(MyBox::into_raw(inner)) },
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn inner(&self) -> &RcInner<T> {
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// 与[`MyBox`]相同，以下都是关联函数，以避免与`T`的方法冲突。
+    #[inline]
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().strong.get()
+    }
+
+    /// 两者是否指向同一个分配。
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+
+    /// 只有在当前`MyRc`是唯一的引用时，才能获得可变引用。
+    #[inline]
+    pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+        if Self::strong_count(this) == 1 {
+            unsafe { Some(&mut this.ptr.as_mut().value) }
+        } else {
+            None
+        }
+    }
+
+    /// 写时复制：如果当前`MyRc`不是唯一的引用，则先克隆其中的值，使当前
+    /// `MyRc`指向一个新的分配，然后返回可变引用。
+    pub fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        if Self::strong_count(this) != 1 {
+            *this = Self::new((**this).clone());
+        }
+        unsafe { &mut this.ptr.as_mut().value }
+    }
+
+    /// 如果当前`MyRc`是唯一的引用，则取出其中的值，否则原样返还。
+    pub fn try_unwrap(this: Self) -> Result<T, Self> {
+        if Self::strong_count(&this) != 1 {
+            return Err(this);
+        }
+
+        let this = ManuallyDrop::new(this);
+        let inner = unsafe { MyBox::from_raw(this.ptr.as_ptr()) };
+        Ok(MyBox::into_inner(inner).value)
+    }
+}
+
+impl<T> Clone for MyRc<T> {
+    /// 与标准库相同，如果引用计数溢出，则直接终止程序。在正常的程序中，
+    /// 只有`mem::forget`了大量的`MyRc`才会发生这种情况。
+    fn clone(&self) -> Self {
+        let strong = &self.inner().strong;
+        let count = strong.get().wrapping_add(1);
+        if count == 0 {
+            process::abort();
+        }
+        strong.set(count);
+
+        Self {
+            ptr: self.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for MyRc<T> {
+    fn drop(&mut self) {
+        let strong = &self.inner().strong;
+        let count = strong.get() - 1;
+        strong.set(count);
+        if count == 0 {
+            // 最后一个引用，`drop`值并释放内存
+            drop(unsafe { MyBox::from_raw(self.ptr.as_ptr()) });
+        }
+    }
+}
+
+impl<T> Deref for MyRc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+impl<T: Default> Default for MyRc<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for MyRc<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> AsRef<T> for MyRc<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyRc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MyRc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+/// 按指向的值比较。
+impl<T: PartialEq> PartialEq for MyRc<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for MyRc<T> {}
+
+impl<T: PartialOrd> PartialOrd for MyRc<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for MyRc<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash> Hash for MyRc<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
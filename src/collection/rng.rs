@@ -0,0 +1,94 @@
+/// 最小的随机数生成器接口，与`rand_core::RngCore`类似，但只需要实现
+/// [`RngCore::next_u64`]。
+///
+/// 本crate中需要随机数的地方（如[`MyVec::shuffle`]）都只依赖这个trait，
+/// 因此不需要引入`rand`。开启`rand` feature之后，可以通过[`RandCompat`]
+/// 使用任何实现了`rand_core::RngCore`的生成器。
+///
+/// [`MyVec::shuffle`]: crate::collection::vec::MyVec::shuffle
+pub trait RngCore {
+    fn next_u64(&mut self) -> u64;
+
+    /// 均匀地返回`0..bound`中的一个数。
+    ///
+    /// 使用Lemire的乘法方法：`next_u64() * bound`的高64位落在`0..bound`
+    /// 中，低64位小于`2^64 mod bound`时存在偏差，拒绝之后重新生成，因此
+    /// 结果是严格均匀的，且绝大多数情况下不需要除法。
+    ///
+    /// ## Panics
+    ///
+    /// `bound == 0`时`panic`。
+    fn next_below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "next_below bound must be positive");
+        let mut product = self.next_u64() as u128 * bound as u128;
+        if (product as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u64) < threshold {
+                product = self.next_u64() as u128 * bound as u128;
+            }
+        }
+        (product >> 64) as u64
+    }
+}
+
+impl<R: RngCore + ?Sized> RngCore for &mut R {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// xorshift64生成器，速度很快，统计性质足以用于生成测试数据，但不能用于
+/// 密码学。
+///
+/// 相同的种子总是产生相同的序列。
+///
+/// ```rust
+/// use rust_practice::collection::rng::{RngCore, XorShift64};
+///
+/// let mut a = XorShift64::seed_from_u64(42);
+/// let mut b = XorShift64::seed_from_u64(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// assert!(a.next_below(6) < 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// xorshift的状态不能为0，否则会一直输出0。我们先用SplitMix64打散
+    /// 种子，因此相近的种子也会得到差别很大的序列，`0`也是合法的种子。
+    pub const fn seed_from_u64(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        Self {
+            state: if z == 0 { 0x2545_f491_4f6c_dd1d } else { z },
+        }
+    }
+}
+
+impl RngCore for XorShift64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+/// 将实现了`rand_core::RngCore`的生成器用作本crate的[`RngCore`]。
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone)]
+pub struct RandCompat<R>(pub R);
+
+#[cfg(feature = "rand")]
+impl<R: rand_core::RngCore> RngCore for RandCompat<R> {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
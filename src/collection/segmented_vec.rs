@@ -0,0 +1,307 @@
+mod iter;
+
+pub use iter::{IntoIter, Iter, IterMut};
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::collection::vec::MyVec;
+
+/// 默认的分段大小。
+const DEFAULT_CHUNK_SIZE: usize = 64;
+
+/// 元素地址稳定的可增长数组。
+///
+/// 元素被存储在大小固定为`chunk_size`的分段中，每个分段是一个容量恰好为
+/// `chunk_size`的[`MyVec<T>`]，分段的列表本身也是一个`MyVec`。当最后一个
+/// 分段已满时，我们申请一个新的分段，而不是重新分配已有的内存，因此
+/// [`SegmentedVec::push`]永远不会移动已有的元素，指向它们的指针在之后的
+/// `push`中始终有效。分段列表扩容时移动的只是`MyVec`本身（指针、长度和
+/// 容量），而不是分段中的元素。
+///
+/// 分段大小必须是2的幂，这样下标可以通过移位和掩码拆分为分段的下标和分
+/// 段内的偏移，[`SegmentedVec::get`]的时间复杂度为`O(1)`。
+///
+/// 除了[`SegmentedVec::pop`]、[`SegmentedVec::truncate`]和
+/// [`SegmentedVec::clear`]，我们不提供移除元素的方法，因为从中间移除元素
+/// 必然会移动其他元素。
+///
+/// ```rust
+/// use rust_practice::collection::segmented_vec::SegmentedVec;
+///
+/// let mut vec = SegmentedVec::with_chunk_size(4);
+/// let first: *const i32 = vec.push(0);
+/// for i in 1..100 {
+///     vec.push(i);
+/// }
+/// // 第一个元素没有被移动
+/// assert_eq!(unsafe { *first }, 0);
+/// assert_eq!(vec[57], 57);
+/// assert_eq!(vec.chunk_count(), 25);
+/// ```
+pub struct SegmentedVec<T> {
+    chunks: MyVec<MyVec<T>>,
+    len: usize,
+    shift: u32,
+}
+
+impl<T> SegmentedVec<T> {
+    /// 使用默认的分段大小（64）。
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// ## Panics
+    ///
+    /// `chunk_size`不是2的幂时`panic`。
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        assert!(
+            chunk_size.is_power_of_two(),
+            "SegmentedVec chunk size must be a power of two"
+        );
+        Self {
+            chunks: MyVec::new(),
+            len: 0,
+            shift: chunk_size.trailing_zeros(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        1 << self.shift
+    }
+
+    /// 已经申请的分段数量。
+    #[inline]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// 在不申请新分段的情况下可以容纳的元素数量。
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.chunks.len() << self.shift
+    }
+
+    /// 将下标拆分为分段的下标和分段内的偏移。
+    #[inline]
+    fn locate(&self, index: usize) -> (usize, usize) {
+        (index >> self.shift, index & (self.chunk_size() - 1))
+    }
+
+    /// 在末尾插入元素，并返回它的可变引用。
+    ///
+    /// 如果最后一个分段已满，则申请一个新的分段，已有的元素不会被移动。
+    pub fn push(&mut self, value: T) -> &mut T {
+        if self.len == self.capacity() {
+            self.chunks.push(MyVec::with_capacity(self.chunk_size()));
+        }
+        let (chunk, _) = self.locate(self.len);
+        let chunk = &mut self.chunks[chunk];
+        debug_assert!(chunk.len() < chunk.capacity());
+        // 分段的容量恰好为`chunk_size`，且此时一定未满，因此不会重新分配
+        chunk.push(value);
+        self.len += 1;
+        chunk.last_mut().unwrap()
+    }
+
+    /// 移除并返回最后一个元素，变空的分段会被释放。
+    pub fn pop(&mut self) -> Option<T> {
+        let chunk = self.chunks.last_mut()?;
+        let value = chunk.pop();
+        if chunk.is_empty() {
+            self.chunks.pop();
+        }
+        self.len -= 1;
+        value
+    }
+
+    /// 只保留前`len`个元素，之后的元素会被`drop`，不再需要的分段会被释放。
+    /// 如果`len`不小于当前长度，则什么都不做。
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let chunk_count = len.div_ceil(self.chunk_size());
+        let (_, offset) = self.locate(len);
+        self.chunks.truncate(chunk_count);
+        if let Some(last) = self.chunks.last_mut()
+            && offset != 0
+        {
+            last.truncate(offset);
+        }
+        self.len = len;
+    }
+
+    /// 移除所有元素并释放所有分段。
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        let (chunk, offset) = self.locate(index);
+        // SAFETY: `index < len`，因此分段和偏移都有效
+        unsafe { Some(self.chunks.get_unchecked(chunk).get_unchecked(offset)) }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let (chunk, offset) = self.locate(index);
+        unsafe {
+            Some(
+                self.chunks
+                    .get_unchecked_mut(chunk)
+                    .get_unchecked_mut(offset),
+            )
+        }
+    }
+
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.chunks.last()?.last()
+    }
+
+    /// 以切片的形式迭代每个分段，除最后一个分段外，每个切片的长度都是
+    /// `chunk_size`。
+    #[inline]
+    pub fn chunks(&self) -> impl Iterator<Item = &[T]> {
+        self.chunks.iter().map(|chunk| chunk.as_slice())
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.chunks.iter(), self.len)
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.chunks.iter_mut(), self.len)
+    }
+}
+
+impl<T> Default for SegmentedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 克隆的结果使用相同的分段大小。
+impl<T: Clone> Clone for SegmentedVec<T> {
+    fn clone(&self) -> Self {
+        let mut ret = Self::with_chunk_size(self.chunk_size());
+        ret.extend(self.iter().cloned());
+        ret
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SegmentedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// 只比较元素，不比较分段大小。
+impl<T: PartialEq> PartialEq for SegmentedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SegmentedVec<T> {}
+
+impl<T> Index<usize> for SegmentedVec<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for SegmentedVec<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<T> Extend<T> for SegmentedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for SegmentedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<T> IntoIterator for SegmentedVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.chunks.into_iter(), self.len)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SegmentedVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SegmentedVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,83 @@
+use std::iter::{Flatten, FusedIterator};
+use std::slice;
+
+use crate::collection::vec::{self, MyVec};
+
+/// 三种迭代器都是对分段迭代器的[`Flatten`]，额外记录剩余的元素数量以实
+/// 现[`ExactSizeIterator`]。
+macro_rules! impl_iter {
+    ($name:ident, $inner:ty, $item:ty $(, $lt:lifetime)?) => {
+        impl<$($lt,)? T> $name<$($lt,)? T> {
+            #[inline]
+            pub(super) fn new(chunks: $inner, len: usize) -> Self {
+                Self {
+                    inner: chunks.flatten(),
+                    len,
+                }
+            }
+        }
+
+        impl<$($lt,)? T> Iterator for $name<$($lt,)? T> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let item = self.inner.next()?;
+                self.len -= 1;
+                Some(item)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<$($lt,)? T> DoubleEndedIterator for $name<$($lt,)? T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let item = self.inner.next_back()?;
+                self.len -= 1;
+                Some(item)
+            }
+        }
+
+        impl<$($lt,)? T> ExactSizeIterator for $name<$($lt,)? T> {}
+
+        impl<$($lt,)? T> FusedIterator for $name<$($lt,)? T> {}
+    };
+}
+
+/// [`SegmentedVec::iter`]返回的迭代器。
+///
+/// [`SegmentedVec::iter`]: super::SegmentedVec::iter
+#[derive(Clone)]
+pub struct Iter<'a, T> {
+    inner: Flatten<slice::Iter<'a, MyVec<T>>>,
+    len: usize,
+}
+
+impl_iter!(Iter, slice::Iter<'a, MyVec<T>>, &'a T, 'a);
+
+/// [`SegmentedVec::iter_mut`]返回的迭代器。
+///
+/// [`SegmentedVec::iter_mut`]: super::SegmentedVec::iter_mut
+pub struct IterMut<'a, T> {
+    inner: Flatten<slice::IterMut<'a, MyVec<T>>>,
+    len: usize,
+}
+
+impl_iter!(IterMut, slice::IterMut<'a, MyVec<T>>, &'a mut T, 'a);
+
+/// [`SegmentedVec`]的[`IntoIterator`]实现返回的迭代器。
+///
+/// 被`drop`时，剩余的元素和所有分段会通过[`MyVec`]的[`vec::IntoIter`]被
+/// 释放。
+///
+/// [`SegmentedVec`]: super::SegmentedVec
+pub struct IntoIter<T> {
+    inner: Flatten<vec::IntoIter<MyVec<T>>>,
+    len: usize,
+}
+
+impl_iter!(IntoIter, vec::IntoIter<MyVec<T>>, T);
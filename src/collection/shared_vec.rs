@@ -0,0 +1,254 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::collection::arc::MyArc;
+use crate::collection::vec::{self, MyVec};
+
+/// 写时复制（copy-on-write）的数组，所有克隆共享同一个缓冲区。
+///
+/// 我们使用[`MyArc<MyVec<T>>`]保存元素，因此[`Clone`]只需要增加引用计数，
+/// 时间复杂度为`O(1)`，且`SharedVec`可以在线程之间共享（在
+/// `T: Send + Sync`时）。读取通过`Deref<Target = [T]>`进行，不需要任何检
+/// 查。
+///
+/// 任何修改操作（如[`SharedVec::push`]、[`SharedVec::as_mut_slice`]）都
+/// 会先通过[`MyArc::make_mut`]检查引用计数：如果缓冲区被共享，则先复制
+/// 一份，之后的修改只影响当前的`SharedVec`；如果当前`SharedVec`是唯一的
+/// 引用，则直接在原来的缓冲区上修改。
+///
+/// ```rust
+/// use rust_practice::collection::shared_vec::SharedVec;
+/// use rust_practice::my_vec;
+///
+/// let a = SharedVec::from(my_vec![1, 2, 3]);
+/// let mut b = a.clone();
+/// assert!(SharedVec::ptr_eq(&a, &b));
+/// // `b`被修改之前复制了缓冲区，`a`不受影响
+/// b.push(4);
+/// assert_eq!(*a, [1, 2, 3]);
+/// assert_eq!(*b, [1, 2, 3, 4]);
+/// assert!(!SharedVec::ptr_eq(&a, &b));
+/// ```
+pub struct SharedVec<T> {
+    inner: MyArc<MyVec<T>>,
+}
+
+impl<T> SharedVec<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: MyArc::new(MyVec::new()),
+        }
+    }
+
+    /// 共享当前缓冲区的`SharedVec`的数量（包括自身）。
+    #[inline]
+    pub fn ref_count(&self) -> usize {
+        MyArc::strong_count(&self.inner)
+    }
+
+    #[inline]
+    pub fn is_unique(&self) -> bool {
+        self.ref_count() == 1
+    }
+
+    /// 两者是否共享同一个缓冲区。
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        MyArc::ptr_eq(&this.inner, &other.inner)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    /// 只有在当前`SharedVec`是唯一的引用时，才能获得内部`MyVec`的可变引
+    /// 用，不会复制。
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut MyVec<T>> {
+        MyArc::get_mut(&mut self.inner)
+    }
+
+    /// 移除所有元素。缓冲区被共享时不会复制，而是换用一个新的空数组。
+    pub fn clear(&mut self) {
+        match self.get_mut() {
+            Some(vec) => vec.clear(),
+            None => *self = Self::new(),
+        }
+    }
+}
+
+impl<T: Clone> SharedVec<T> {
+    /// 返回内部`MyVec`的可变引用，如果缓冲区被共享，则先复制。
+    #[inline]
+    pub fn make_mut(&mut self) -> &mut MyVec<T> {
+        MyArc::make_mut(&mut self.inner)
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.make_mut()
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.make_mut().push(value);
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.make_mut().pop()
+    }
+
+    #[inline]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.make_mut().insert(index, value);
+    }
+
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        self.make_mut().remove(index)
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.make_mut().truncate(len);
+        }
+    }
+
+    /// 取出内部的`MyVec`。如果当前`SharedVec`是唯一的引用，则不会复制，否
+    /// 则克隆一份。
+    pub fn into_my_vec(self) -> MyVec<T> {
+        match MyArc::try_unwrap(self.inner) {
+            Ok(vec) => vec,
+            Err(shared) => (*shared).clone(),
+        }
+    }
+}
+
+/// 只增加引用计数，不复制元素，因此不要求`T: Clone`。
+impl<T> Clone for SharedVec<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: MyArc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Deref for SharedVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> Default for SharedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<MyVec<T>> for SharedVec<T> {
+    #[inline]
+    fn from(vec: MyVec<T>) -> Self {
+        Self {
+            inner: MyArc::new(vec),
+        }
+    }
+}
+
+impl<T: Clone> From<SharedVec<T>> for MyVec<T> {
+    #[inline]
+    fn from(vec: SharedVec<T>) -> Self {
+        vec.into_my_vec()
+    }
+}
+
+impl<T> AsRef<[T]> for SharedVec<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedVec<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// 总是按元素比较。
+///
+/// 不能在共享同一个缓冲区时直接返回`true`：对于`f64::NAN`这样不等于自
+/// 身的元素，克隆会与原值相等，而内容相同的深拷贝却不相等。
+impl<T: PartialEq> PartialEq for SharedVec<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for SharedVec<T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for SharedVec<T> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: Eq> Eq for SharedVec<T> {}
+
+impl<T: Hash> Hash for SharedVec<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: Clone> Extend<T> for SharedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.make_mut().extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for SharedVec<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<MyVec<T>>())
+    }
+}
+
+/// 与[`SharedVec::into_my_vec`]相同，缓冲区被共享时会复制。
+impl<T: Clone> IntoIterator for SharedVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_my_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SharedVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
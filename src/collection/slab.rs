@@ -0,0 +1,309 @@
+use std::fmt;
+use std::iter::{Enumerate, FusedIterator};
+use std::mem;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use crate::collection::vec::MyVec;
+
+/// 槽位，空槽位组成一条单向链表（空闲链表）。
+#[derive(Clone)]
+enum Entry<T> {
+    Occupied(T),
+    Vacant { next_free: usize },
+}
+
+/// 预先分配的存储，插入元素时返回一个键（槽位的下标），之后可以用这
+/// 个键访问该元素。
+///
+/// 与[`MyVec`]的下标不同，移除某个元素不会改变其他元素的键：被移除的
+/// 槽位会被标记为空，并加入空闲链表，之后的[`Slab::insert`]优先复用这
+/// 些槽位。`next_free`为空闲链表的头，等于`entries.len()`时表示链表
+/// 为空。
+///
+/// 需要注意，键会被复用：某个元素被移除后，它的键可能被分配给新插入
+/// 的元素。在键被复用之前，用旧的键访问只会得到`None`，而不会`panic`。
+///
+/// `Entry`是一个枚举，`MyVec<Entry<T>>`在`drop`时只会`drop`处于
+/// `Occupied`状态的元素，因此我们不需要手动实现`Drop`。
+///
+/// ```rust
+/// use rust_practice::collection::slab::Slab;
+///
+/// let mut slab = Slab::new();
+/// let a = slab.insert("a");
+/// let b = slab.insert("b");
+/// assert_eq!(slab.remove(a), Some("a"));
+/// assert_eq!(slab.get(a), None);
+/// assert_eq!(slab[b], "b");
+/// // 复用`a`的槽位
+/// assert_eq!(slab.insert("c"), a);
+/// ```
+#[derive(Clone)]
+pub struct Slab<T> {
+    entries: MyVec<Entry<T>>,
+    len: usize,
+    next_free: usize,
+}
+
+impl<T> Slab<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entries: MyVec::new(),
+            len: 0,
+            next_free: 0,
+        }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: MyVec::with_capacity(capacity),
+            len: 0,
+            next_free: 0,
+        }
+    }
+
+    /// 元素的个数。
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 无需重新分配内存即可容纳的元素个数。
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// 下一次调用[`Slab::insert`]将返回的键。
+    #[inline]
+    pub fn vacant_key(&self) -> usize {
+        self.next_free
+    }
+
+    /// 插入`value`并返回它的键，优先复用空闲链表中的槽位。
+    pub fn insert(&mut self, value: T) -> usize {
+        let key = self.next_free;
+        if key == self.entries.len() {
+            self.entries.push(Entry::Occupied(value));
+            self.next_free = key + 1;
+        } else {
+            match mem::replace(&mut self.entries[key], Entry::Occupied(value)) {
+                Entry::Vacant { next_free } => self.next_free = next_free,
+                Entry::Occupied(_) => unreachable!("free list points to an occupied entry"),
+            }
+        }
+        self.len += 1;
+        key
+    }
+
+    /// 移除`key`对应的元素，`key`无效（越界或者已经被移除）时返回`None`。
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let entry = self.entries.get_mut(key)?;
+        if let Entry::Vacant { .. } = entry {
+            return None;
+        }
+
+        let vacant = Entry::Vacant {
+            next_free: self.next_free,
+        };
+        match mem::replace(entry, vacant) {
+            Entry::Occupied(value) => {
+                self.next_free = key;
+                self.len -= 1;
+                Some(value)
+            }
+            Entry::Vacant { .. } => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    #[inline]
+    pub fn get(&self, key: usize) -> Option<&T> {
+        match self.entries.get(key)? {
+            Entry::Occupied(value) => Some(value),
+            Entry::Vacant { .. } => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.entries.get_mut(key)? {
+            Entry::Occupied(value) => Some(value),
+            Entry::Vacant { .. } => None,
+        }
+    }
+
+    /// 只保留满足`f`的元素，被移除元素的槽位加入空闲链表。
+    pub fn retain<F: FnMut(usize, &mut T) -> bool>(&mut self, mut f: F) {
+        for key in 0..self.entries.len() {
+            let keep = match &mut self.entries[key] {
+                Entry::Occupied(value) => f(key, value),
+                Entry::Vacant { .. } => true,
+            };
+            if !keep {
+                self.remove(key);
+            }
+        }
+    }
+
+    /// 移除所有元素，之后的键从0开始重新分配。
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.len = 0;
+        self.next_free = 0;
+    }
+
+    /// 按键的顺序产生`(key, &value)`，跳过空槽位。
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            entries: self.entries.iter().enumerate(),
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            entries: self.entries.iter_mut().enumerate(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for Slab<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Slab<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// ## Panics
+///
+/// `key`无效时`panic`。
+impl<T> Index<usize> for Slab<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, key: usize) -> &T {
+        match self.get(key) {
+            Some(value) => value,
+            None => panic!("invalid slab key {key}"),
+        }
+    }
+}
+
+/// ## Panics
+///
+/// `key`无效时`panic`。
+impl<T> IndexMut<usize> for Slab<T> {
+    #[inline]
+    fn index_mut(&mut self, key: usize) -> &mut T {
+        match self.get_mut(key) {
+            Some(value) => value,
+            None => panic!("invalid slab key {key}"),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Slab<T> {
+    type Item = (usize, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Slab<T> {
+    type Item = (usize, &'a mut T);
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// [`Slab::iter`]返回的迭代器，`len`为尚未产生的元素个数。
+pub struct Iter<'a, T> {
+    entries: Enumerate<slice::Iter<'a, Entry<T>>>,
+    len: usize,
+}
+
+/// [`Slab::iter_mut`]返回的迭代器。
+pub struct IterMut<'a, T> {
+    entries: Enumerate<slice::IterMut<'a, Entry<T>>>,
+    len: usize,
+}
+
+macro_rules! impl_iter {
+    ($name:ident, $item:ty) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = (usize, $item);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                for (key, entry) in self.entries.by_ref() {
+                    if let Entry::Occupied(value) = entry {
+                        self.len -= 1;
+                        return Some((key, value));
+                    }
+                }
+                None
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                while let Some((key, entry)) = self.entries.next_back() {
+                    if let Entry::Occupied(value) = entry {
+                        self.len -= 1;
+                        return Some((key, value));
+                    }
+                }
+                None
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+impl_iter!(Iter, &'a T);
+impl_iter!(IterMut, &'a mut T);
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            len: self.len,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use core::panic;
+use std::cmp::Ordering;
 use std::ops::{Bound, Range, RangeBounds, RangeTo};
 
 /// 由于[`std::slice::range`]到目前`1.90.0`为止，仍然
@@ -34,3 +35,143 @@ pub fn range<R: RangeBounds<usize>>(range: R, bounds: RangeTo<usize>) -> Range<u
 
     left..right
 }
+
+/// 在有序的切片中二分查找，`f`返回当前元素与目标的大小关系。
+///
+/// 与标准库中切片的`binary_search_by`的约定相同：找到时返回`Ok(index)`，如果
+/// 有多个匹配的元素，可能返回其中任意一个；找不到时返回`Err(index)`，
+/// `index`是保持有序的插入位置。
+///
+/// 我们在`lo..hi`中查找，中点使用`lo + (hi - lo) / 2`计算，而不是
+/// `(lo + hi) / 2`：虽然`MyVec`的长度不超过`isize::MAX`，但对于ZST的切片，
+/// 长度可以达到`usize::MAX`，此时`lo + hi`可能溢出。
+///
+/// ```rust
+/// use rust_practice::collection::slice;
+///
+/// let values = [1, 3, 5, 7];
+/// assert_eq!(slice::binary_search_by(&values, |x| x.cmp(&5)), Ok(2));
+/// assert_eq!(slice::binary_search_by(&values, |x| x.cmp(&4)), Err(2));
+/// assert_eq!(slice::binary_search_by(&values, |x| x.cmp(&9)), Err(4));
+/// ```
+pub fn binary_search_by<T, F>(slice: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    // 循环不变式：`slice[..lo]`都小于目标，`slice[hi..]`都大于目标
+    let (mut lo, mut hi) = (0, slice.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match f(&slice[mid]) {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(lo)
+}
+
+/// 在按照`f`返回的键有序的切片中二分查找`key`，见[`binary_search_by`]。
+pub fn binary_search_by_key<T, B, F>(slice: &[T], key: &B, mut f: F) -> Result<usize, usize>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    binary_search_by(slice, |x| f(x).cmp(key))
+}
+
+/// 在有序的切片中二分查找`value`，见[`binary_search_by`]。
+pub fn binary_search<T: Ord>(slice: &[T], value: &T) -> Result<usize, usize> {
+    binary_search_by(slice, |x| x.cmp(value))
+}
+
+/// 切片必须按照`pred`分区，即满足`pred`的元素都在不满足的元素之前，返回
+/// 第一个不满足`pred`的元素的下标，所有元素都满足时返回`slice.len()`。
+///
+/// ```rust
+/// use rust_practice::collection::slice;
+///
+/// let values = [1, 2, 2, 3, 5];
+/// assert_eq!(slice::partition_point(&values, |&x| x < 2), 1);
+/// assert_eq!(slice::partition_point(&values, |&x| x <= 2), 3);
+/// assert_eq!(slice::partition_point(&values, |_| true), 5);
+/// ```
+pub fn partition_point<T, P>(slice: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    // 比较函数永远不返回`Equal`，因此结果总是`Err`
+    binary_search_by(slice, |x| {
+        if pred(x) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    })
+    .unwrap_or_else(|index| index)
+}
+
+/// 原地反转`slice`，只交换元素，不会克隆。
+fn reverse<T>(slice: &mut [T]) {
+    let (mut i, mut j) = (0, slice.len());
+    while i + 1 < j {
+        j -= 1;
+        slice.swap(i, j);
+        i += 1;
+    }
+}
+
+/// 将`slice`向左旋转`mid`个位置，使`slice[mid]`成为第一个元素。
+///
+/// 使用三次反转：记`slice = AB`，其中`A = slice[..mid]`，则
+/// `BA = (A^r B^r)^r`。每个元素恰好被交换常数次，时间复杂度为`O(n)`，不需
+/// 要额外的内存，`T`也不需要实现`Clone`。由于只有交换，中途不可能
+/// `panic`。
+///
+/// ## Panics
+///
+/// `mid > slice.len()`时`panic`。
+///
+/// ```rust
+/// use rust_practice::collection::slice;
+///
+/// let mut values = [1, 2, 3, 4, 5];
+/// slice::rotate_left(&mut values, 2);
+/// assert_eq!(values, [3, 4, 5, 1, 2]);
+/// ```
+pub fn rotate_left<T>(slice: &mut [T], mid: usize) {
+    let len = slice.len();
+    assert!(
+        mid <= len,
+        "rotate_left mid {mid} out of range for slice of length {len}"
+    );
+    if mid == 0 || mid == len {
+        return;
+    }
+    reverse(&mut slice[..mid]);
+    reverse(&mut slice[mid..]);
+    reverse(slice);
+}
+
+/// 将`slice`向右旋转`k`个位置，使`slice[len - k]`成为第一个元素，见
+/// [`rotate_left`]。
+///
+/// ## Panics
+///
+/// `k > slice.len()`时`panic`。
+///
+/// ```rust
+/// use rust_practice::collection::slice;
+///
+/// let mut values = [1, 2, 3, 4, 5];
+/// slice::rotate_right(&mut values, 2);
+/// assert_eq!(values, [4, 5, 1, 2, 3]);
+/// ```
+pub fn rotate_right<T>(slice: &mut [T], k: usize) {
+    let len = slice.len();
+    assert!(
+        k <= len,
+        "rotate_right k {k} out of range for slice of length {len}"
+    );
+    rotate_left(slice, len - k);
+}
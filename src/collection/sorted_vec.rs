@@ -0,0 +1,338 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, Deref, RangeBounds};
+use std::{fmt, slice};
+
+use crate::collection::vec::{self, MyVec};
+
+/// 始终保持有序的[`MyVec`]，可以作为缓存友好的集合使用。
+///
+/// `SortedVec`本身允许重复的元素（即多重集合），相等的元素按照插入
+/// 的先后顺序排列。如果需要集合的语义，可以使用[`SortedVec::insert_unique`]
+/// 和[`SortedVec::from_my_vec_dedup`]，它们会拒绝或者移除重复的元素。
+///
+/// 为了保证有序性，我们只实现了`Deref<Target = [T]>`，而没有实现
+/// `DerefMut`，否则调用者可以任意修改其中的元素。
+///
+/// ```rust
+/// use rust_practice::collection::sorted_vec::SortedVec;
+///
+/// let mut set: SortedVec<_> = [5, 1, 3].into_iter().collect();
+/// assert_eq!(set.insert_unique(2), Ok(1));
+/// assert_eq!(set.insert_unique(3), Err(3));
+/// assert_eq!(set, [1, 2, 3, 5]);
+/// assert!(set.contains(&5));
+/// assert_eq!(set.range(2..5).copied().collect::<Vec<_>>(), [2, 3]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SortedVec<T> {
+    vec: MyVec<T>,
+}
+
+impl<T: Ord> SortedVec<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self { vec: MyVec::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: MyVec::with_capacity(capacity),
+        }
+    }
+
+    /// 对`vec`进行（稳定的）排序，保留重复的元素。
+    pub fn from_my_vec(mut vec: MyVec<T>) -> Self {
+        vec.sort();
+        Self { vec }
+    }
+
+    /// 对`vec`进行排序并去除重复的元素，相等的元素只保留最先出现的那个。
+    pub fn from_my_vec_dedup(mut vec: MyVec<T>) -> Self {
+        vec.sort();
+        vec.dedup();
+        Self { vec }
+    }
+
+    #[inline]
+    pub fn into_my_vec(self) -> MyVec<T> {
+        self.vec
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// 插入`value`，返回其所在的下标。
+    ///
+    /// 如果已经存在相等的元素，`value`会被插入到它们的后面。
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.vec.partition_point(|x| x <= &value);
+        self.vec.insert(index, value);
+        index
+    }
+
+    /// 插入`value`，如果已经存在相等的元素，则不插入，并将`value`通过
+    /// `Err`返还。
+    pub fn insert_unique(&mut self, value: T) -> Result<usize, T> {
+        match self.vec.binary_search(&value) {
+            Ok(_) => Err(value),
+            Err(index) => {
+                self.vec.insert(index, value);
+                Ok(index)
+            }
+        }
+    }
+
+    /// 插入`value`，如果已经存在相等的元素，则替换（第一个）该元素并
+    /// 将其返回。
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        let index = self.lower_bound(&value);
+        match self.vec.get_mut(index) {
+            Some(x) if *x == value => Some(std::mem::replace(x, value)),
+            _ => {
+                self.vec.insert(index, value);
+                None
+            }
+        }
+    }
+
+    /// 第一个不小于`value`的元素的下标。
+    #[inline]
+    fn lower_bound(&self, value: &T) -> usize {
+        self.vec.partition_point(|x| x < value)
+    }
+
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.get(value).is_some()
+    }
+
+    /// 返回第一个与`value`相等的元素。
+    pub fn get(&self, value: &T) -> Option<&T> {
+        self.vec
+            .get(self.lower_bound(value))
+            .filter(|x| *x == value)
+    }
+
+    /// 移除第一个与`value`相等的元素。
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let index = self.lower_bound(value);
+        if self.vec.get(index)? == value {
+            Some(self.vec.remove(index))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn remove_index(&mut self, index: usize) -> T {
+        self.vec.remove(index)
+    }
+
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.vec.is_empty() {
+            None
+        } else {
+            Some(self.vec.remove(0))
+        }
+    }
+
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+    }
+
+    /// 返回位于`range`中的元素的切片。
+    pub fn range_slice<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        let start = match range.start_bound() {
+            Bound::Included(x) => self.vec.partition_point(|e| e < x),
+            Bound::Excluded(x) => self.vec.partition_point(|e| e <= x),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(x) => self.vec.partition_point(|e| e <= x),
+            Bound::Excluded(x) => self.vec.partition_point(|e| e < x),
+            Bound::Unbounded => self.vec.len(),
+        };
+        // 对于`5..3`这样的范围，`end < start`
+        &self.vec[start..end.max(start)]
+    }
+
+    #[inline]
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> slice::Iter<'_, T> {
+        self.range_slice(range).iter()
+    }
+}
+
+/// 集合运算。
+///
+/// 这些运算都是对两个有序序列的线性归并，时间复杂度为`O(n + m)`。对
+/// 于重复的元素，我们将两侧相等的元素一一配对，因此在多重集合的意义
+/// 下，结果中某个元素出现的次数为：
+///
+/// - `union`：两侧次数的最大值
+/// - `intersection`：两侧次数的最小值
+/// - `difference`：`self`中的次数减去`other`中的次数（不小于0）
+///
+/// 对于没有重复元素的`SortedVec`，这就是通常的集合运算。
+impl<T: Ord + Clone> SortedVec<T> {
+    fn merge(&self, other: &Self, keep_left: bool, keep_both: bool, keep_right: bool) -> Self {
+        let (a, b) = (self.as_slice(), other.as_slice());
+        let mut ret = MyVec::with_capacity(if keep_right {
+            a.len() + b.len()
+        } else {
+            a.len()
+        });
+        let (mut i, mut j) = (0, 0);
+
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                Ordering::Less => {
+                    if keep_left {
+                        ret.push(a[i].clone());
+                    }
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    if keep_right {
+                        ret.push(b[j].clone());
+                    }
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    if keep_both {
+                        ret.push(a[i].clone());
+                    }
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        if keep_left {
+            ret.extend_from_slice(&a[i..]);
+        }
+        if keep_right {
+            ret.extend_from_slice(&b[j..]);
+        }
+
+        Self { vec: ret }
+    }
+
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        self.merge(other, true, true, true)
+    }
+
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.merge(other, false, true, false)
+    }
+
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.merge(other, true, false, false)
+    }
+
+    #[inline]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.merge(other, true, false, true)
+    }
+}
+
+impl<T: Ord> Default for SortedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for SortedVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SortedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.vec.iter()).finish()
+    }
+}
+
+impl<T: Ord> From<MyVec<T>> for SortedVec<T> {
+    #[inline]
+    fn from(value: MyVec<T>) -> Self {
+        Self::from_my_vec(value)
+    }
+}
+
+impl<T> From<SortedVec<T>> for MyVec<T> {
+    #[inline]
+    fn from(value: SortedVec<T>) -> Self {
+        value.vec
+    }
+}
+
+/// 先收集所有元素，再排序一次，而不是逐个插入。
+impl<T: Ord> FromIterator<T> for SortedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_my_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for SortedVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for SortedVec<T> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.vec == *other
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for SortedVec<T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        self.vec == *other
+    }
+}
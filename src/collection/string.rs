@@ -0,0 +1,467 @@
+mod error;
+
+pub use error::FromUtf8Error;
+
+use std::borrow::{Borrow, BorrowMut};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Deref, DerefMut};
+use std::{cmp, fmt, ptr, str};
+
+use crate::collection::vec::MyVec;
+
+/// 基于[`MyVec<u8>`]的UTF-8字符串，与[`String`]类似。
+///
+/// `MyString`始终维护一个不变式：`vec`中的字节是合法的UTF-8序列。所有
+/// 的安全方法都会保证这一点，而[`MyString::from_utf8_unchecked`]等
+/// unsafe方法则要求调用者保证。正因为如此，我们可以安全地将其解引用
+/// 为[`str`]。
+///
+/// 对于需要传入字节下标的方法（[`MyString::insert`]、[`MyString::truncate`]
+/// 等），下标必须位于字符的边界上，否则会`panic`，其信息与[`String`]
+/// 相同。
+///
+/// ```rust
+/// use rust_practice::collection::string::MyString;
+///
+/// let mut s = MyString::from("你好");
+/// s.push('，');
+/// s.push_str("world");
+/// assert_eq!(s, "你好，world");
+/// assert_eq!(s.pop(), Some('d'));
+/// s.insert_str(0, "🦀");
+/// assert_eq!(s.as_str(), "🦀你好，worl");
+/// ```
+#[derive(Clone, Default)]
+pub struct MyString {
+    vec: MyVec<u8>,
+}
+
+impl MyString {
+    #[inline]
+    pub fn new() -> Self {
+        Self { vec: MyVec::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: MyVec::with_capacity(capacity),
+        }
+    }
+
+    /// 检查`vec`是否为合法的UTF-8序列，并接管其内存。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::string::MyString;
+    /// use rust_practice::my_vec;
+    ///
+    /// let s = MyString::from_utf8(my_vec![0xe4, 0xbd, 0xa0]).unwrap();
+    /// assert_eq!(s, "你");
+    ///
+    /// let err = MyString::from_utf8(my_vec![b'a', 0xe4, 0xbd]).unwrap_err();
+    /// assert_eq!(err.utf8_error().valid_up_to(), 1);
+    /// assert_eq!(err.into_bytes(), [b'a', 0xe4, 0xbd]);
+    /// ```
+    pub fn from_utf8(vec: MyVec<u8>) -> Result<Self, FromUtf8Error> {
+        match str::from_utf8(&vec) {
+            Ok(_) => Ok(Self { vec }),
+            Err(e) => Err(FromUtf8Error::new(vec, e)),
+        }
+    }
+
+    /// ## Safety
+    ///
+    /// - `vec`中的字节必须是合法的UTF-8序列
+    #[inline]
+    pub unsafe fn from_utf8_unchecked(vec: MyVec<u8>) -> Self {
+        Self { vec }
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> MyVec<u8> {
+        self.vec
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.vec
+    }
+
+    /// ## Safety
+    ///
+    /// - 在返回的引用被释放之前，必须保证其中的字节是合法的UTF-8序列
+    #[inline]
+    pub unsafe fn as_mut_vec(&mut self) -> &mut MyVec<u8> {
+        &mut self.vec
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.vec) }
+    }
+
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(&mut self.vec) }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    pub fn push(&mut self, ch: char) {
+        match ch.len_utf8() {
+            1 => self.vec.push(ch as u8),
+            _ => self.push_str(ch.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.vec.extend_from_slice(string.as_bytes());
+    }
+
+    /// 移除并返回最后一个字符。
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+        unsafe {
+            self.vec.set_len(new_len);
+        }
+        Some(ch)
+    }
+
+    /// 将长度缩短为`new_len`个字节，如果`new_len`大于当前长度，则什么
+    /// 也不做。
+    ///
+    /// ## Panics
+    ///
+    /// `new_len`不在字符的边界上时`panic`。
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            assert!(self.is_char_boundary(new_len));
+            unsafe {
+                self.vec.set_len(new_len);
+            }
+        }
+    }
+
+    /// 在字节下标`idx`处插入字节，调用者需要保证插入之后仍然是合法的
+    /// UTF-8序列。
+    ///
+    /// 与[`MyVec::insert`]相同，我们先将`idx`之后的字节整体向后移动，
+    /// 然后将`bytes`复制到空出的位置。
+    unsafe fn insert_bytes(&mut self, idx: usize, bytes: &[u8]) {
+        let len = self.len();
+        let amt = bytes.len();
+        self.vec.reserve(amt);
+
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            ptr::copy(ptr.add(idx), ptr.add(idx + amt), len - idx);
+            ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.add(idx), amt);
+            self.vec.set_len(len + amt);
+        }
+    }
+
+    /// ## Panics
+    ///
+    /// `idx`大于长度或者不在字符的边界上时`panic`。
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        assert!(self.is_char_boundary(idx));
+        let mut bits = [0; 4];
+        let bits = ch.encode_utf8(&mut bits).as_bytes();
+
+        unsafe {
+            self.insert_bytes(idx, bits);
+        }
+    }
+
+    /// ## Panics
+    ///
+    /// `idx`大于长度或者不在字符的边界上时`panic`。
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        assert!(self.is_char_boundary(idx));
+
+        unsafe {
+            self.insert_bytes(idx, string.as_bytes());
+        }
+    }
+
+    /// 移除并返回位于字节下标`idx`处的字符。
+    ///
+    /// ## Panics
+    ///
+    /// `idx`大于等于长度或者不在字符的边界上时`panic`。
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self[idx..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+
+        let next = idx + ch.len_utf8();
+        let len = self.len();
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            ptr::copy(ptr.add(next), ptr.add(idx), len - next);
+            self.vec.set_len(len - (next - idx));
+        }
+        ch
+    }
+}
+
+impl Deref for MyString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl DerefMut for MyString {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl fmt::Display for MyString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for MyString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Write for MyString {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl From<&str> for MyString {
+    fn from(value: &str) -> Self {
+        let mut ret = Self::with_capacity(value.len());
+        ret.push_str(value);
+        ret
+    }
+}
+
+impl From<&mut str> for MyString {
+    #[inline]
+    fn from(value: &mut str) -> Self {
+        Self::from(&*value)
+    }
+}
+
+impl From<char> for MyString {
+    fn from(value: char) -> Self {
+        let mut ret = Self::new();
+        ret.push(value);
+        ret
+    }
+}
+
+/// 直接接管`String`的内存，不会复制。
+impl From<String> for MyString {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self {
+            vec: MyVec::from(value.into_bytes()),
+        }
+    }
+}
+
+/// 直接将内存交给`String`，不会复制。
+impl From<MyString> for String {
+    #[inline]
+    fn from(value: MyString) -> Self {
+        unsafe { String::from_utf8_unchecked(Vec::from(value.vec)) }
+    }
+}
+
+impl Add<&str> for MyString {
+    type Output = MyString;
+
+    #[inline]
+    fn add(mut self, rhs: &str) -> MyString {
+        self.push_str(rhs);
+        self
+    }
+}
+
+impl AddAssign<&str> for MyString {
+    #[inline]
+    fn add_assign(&mut self, rhs: &str) {
+        self.push_str(rhs);
+    }
+}
+
+impl Extend<char> for MyString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|ch| self.push(ch));
+    }
+}
+
+impl<'a> Extend<&'a char> for MyString {
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a> Extend<&'a str> for MyString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.push_str(s));
+    }
+}
+
+impl FromIterator<char> for MyString {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<'a> FromIterator<&'a char> for MyString {
+    fn from_iter<I: IntoIterator<Item = &'a char>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<'a> FromIterator<&'a str> for MyString {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl PartialEq for MyString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for MyString {}
+
+macro_rules! impl_eq_str {
+    ($($rhs:ty),*) => {
+        $(
+            impl PartialEq<$rhs> for MyString {
+                #[inline]
+                fn eq(&self, other: &$rhs) -> bool {
+                    self.as_str() == &other[..]
+                }
+            }
+
+            impl PartialEq<MyString> for $rhs {
+                #[inline]
+                fn eq(&self, other: &MyString) -> bool {
+                    &self[..] == other.as_str()
+                }
+            }
+        )*
+    };
+}
+
+impl_eq_str!(str, &str, String);
+
+impl PartialOrd for MyString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MyString {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// 与[`str`]的哈希保持一致，这样`MyString`才能通过[`Borrow<str>`]
+/// 用于`HashMap`的查找。
+impl Hash for MyString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl AsRef<str> for MyString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl AsMut<str> for MyString {
+    #[inline]
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl AsRef<[u8]> for MyString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for MyString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl BorrowMut<str> for MyString {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str {
+        self
+    }
+}
@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt;
+use std::str::Utf8Error;
+
+use crate::collection::vec::MyVec;
+
+/// [`MyString::from_utf8`]失败时产生的错误。
+///
+/// 与[`std::string::FromUtf8Error`]相同，我们会将传入的字节返还给调用
+/// 者，以避免不必要的复制。
+///
+/// [`MyString::from_utf8`]: crate::collection::string::MyString::from_utf8
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromUtf8Error {
+    bytes: MyVec<u8>,
+    error: Utf8Error,
+}
+
+impl FromUtf8Error {
+    #[inline]
+    pub(super) fn new(bytes: MyVec<u8>, error: Utf8Error) -> Self {
+        Self { bytes, error }
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> MyVec<u8> {
+        self.bytes
+    }
+
+    #[inline]
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl Error for FromUtf8Error {}
@@ -0,0 +1,301 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::collection::inplace_vec::{self, InplaceVec};
+use crate::collection::vec_map::{Iter, IterMut, Keys, Values, ValuesMut};
+
+/// 容量固定为`N`的映射，使用[`InplaceVec<N, (K, V)>`]存储键值对，不会
+/// 申请堆内存。
+///
+/// 查找使用线性搜索，因此只要求`K: Eq`，而不要求`Hash`或者`Ord`。对于
+/// 很小的`N`，线性搜索通常比哈希或者二分查找更快。移除时我们使用
+/// [`InplaceVec::swap_remove`]，因此键值对的顺序是不确定的。
+///
+/// 与标准库的映射相同，可以使用键的借用形式进行查找，例如使用`&str`查
+/// 找`String`类型的键。
+///
+/// ```rust
+/// use rust_practice::collection::tiny_map::TinyMap;
+///
+/// let mut map = TinyMap::<2, String, i32>::new();
+/// assert_eq!(map.insert("a".to_string(), 1), Ok(None));
+/// assert_eq!(map.insert("b".to_string(), 2), Ok(None));
+/// // 已存在的键总是可以更新
+/// assert_eq!(map.insert("a".to_string(), 3), Ok(Some(1)));
+/// // 容量已满，新的键值对被返还
+/// assert_eq!(map.insert("c".to_string(), 4), Err(("c".to_string(), 4)));
+/// assert_eq!(map.get("a"), Some(&3));
+/// ```
+#[derive(Clone)]
+pub struct TinyMap<const N: usize, K, V> {
+    vec: InplaceVec<N, (K, V)>,
+}
+
+impl<const N: usize, K, V> TinyMap<N, K, V> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            vec: InplaceVec::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.vec.len() == N
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.vec
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.vec.iter())
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(self.vec.iter_mut())
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.vec.iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.vec.iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self.vec.iter_mut())
+    }
+
+    /// 只保留满足`f`的键值对。
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        let mut i = 0;
+        while i < self.vec.len() {
+            let (k, v) = &mut self.vec[i];
+            if f(k, v) {
+                i += 1;
+            } else {
+                // 最后一个元素被移动到`i`处，需要再次检查
+                self.vec.swap_remove(i);
+            }
+        }
+    }
+}
+
+impl<const N: usize, K: Eq, V> TinyMap<N, K, V> {
+    #[inline]
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.vec.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    /// 插入键值对，如果键已经存在，则替换其值并返回`Ok(Some(old))`。
+    ///
+    /// 如果键不存在且容量已满，则将键值对通过`Err`返还。
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        match self.position(&key) {
+            Some(index) => Ok(Some(std::mem::replace(&mut self.vec[index].1, value))),
+            None if self.is_full() => Err((key, value)),
+            None => {
+                self.vec.push((key, value));
+                Ok(None)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let (k, v) = &self.vec[self.position(key)?];
+        Some((k, v))
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        Some(&mut self.vec[index].1)
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// 移除键值对，最后一个键值对会被移动到空出的位置。
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        Some(self.vec.swap_remove(index))
+    }
+}
+
+impl<const N: usize, K, V> Default for TinyMap<N, K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, K: fmt::Debug, V: fmt::Debug> fmt::Debug for TinyMap<N, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// 与标准库的映射相同，比较时不考虑键值对的顺序。
+impl<const N: usize, const M: usize, K: Eq, V: PartialEq> PartialEq<TinyMap<M, K, V>>
+    for TinyMap<N, K, V>
+{
+    fn eq(&self, other: &TinyMap<M, K, V>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<const N: usize, K: Eq, V: Eq> Eq for TinyMap<N, K, V> {}
+
+/// ## Panics
+///
+/// `key`不存在时`panic`。
+impl<const N: usize, K, V, Q> Index<&Q> for TinyMap<N, K, V>
+where
+    K: Eq + Borrow<Q>,
+    Q: Eq + ?Sized,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in TinyMap")
+    }
+}
+
+/// ## Panics
+///
+/// `key`不存在时`panic`。
+impl<const N: usize, K, V, Q> IndexMut<&Q> for TinyMap<N, K, V>
+where
+    K: Eq + Borrow<Q>,
+    Q: Eq + ?Sized,
+{
+    #[inline]
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key).expect("key not found in TinyMap")
+    }
+}
+
+/// 与[`TinyMap::insert`]相同，已经存在的键会被更新。
+///
+/// ## Panics
+///
+/// 与[`InplaceVec`]的`Extend`相同，如果某个新的键无法放入（容量已满），
+/// 则`panic`。此时在它之前的键值对都已经被插入，而该键值对和迭代器中
+/// 剩余的部分会被`drop`。如果需要处理溢出，请使用[`TinyMap::insert`]。
+impl<const N: usize, K: Eq, V> Extend<(K, V)> for TinyMap<N, K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            if self.insert(key, value).is_err() {
+                panic!("TinyMap overflow: capacity is {N}");
+            }
+        }
+    }
+}
+
+/// 溢出时的行为与`Extend`相同。
+impl<const N: usize, K: Eq, V> FromIterator<(K, V)> for TinyMap<N, K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<const N: usize, K, V> IntoIterator for TinyMap<N, K, V> {
+    type Item = (K, V);
+    type IntoIter = inplace_vec::IntoIter<N, (K, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, const N: usize, K, V> IntoIterator for &'a TinyMap<N, K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize, K, V> IntoIterator for &'a mut TinyMap<N, K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,154 @@
+use std::{fmt, iter};
+
+use crate::collection::vec::MyVec;
+
+/// 并查集（disjoint set union），维护`0..len`上的一组互不相交的集合。
+///
+/// 每个集合用一棵树表示，`parents[x]`是`x`的父节点，根节点的父节点是它
+/// 自身。合并时按秩（rank）合并，将秩较小的树挂到秩较大的树下；查找时进
+/// 行路径压缩，将路径上的所有节点直接挂到根节点下。两者结合后，单次操作
+/// 的均摊时间复杂度为`O(α(n))`。
+///
+/// 秩是树高的上界，按秩合并时秩为`r`的树至少包含`2^r`个节点，因此秩不会
+/// 超过`usize::BITS`，使用`u8`存储即可。
+///
+/// 下标越界时，所有方法都会`panic`。
+///
+/// ```rust
+/// use rust_practice::collection::union_find::UnionFind;
+///
+/// let mut sets = UnionFind::new(4);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(2, 3));
+/// assert!(!sets.union(1, 0));
+/// assert!(sets.connected(0, 1));
+/// assert!(!sets.connected(1, 2));
+/// assert_eq!(sets.set_count(), 2);
+/// ```
+#[derive(Clone)]
+pub struct UnionFind {
+    parents: MyVec<usize>,
+    ranks: MyVec<u8>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// 包含`n`个单元素集合`{0}, {1}, ..., {n - 1}`。
+    pub fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+            ranks: iter::repeat_n(0, n).collect(),
+            sets: n,
+        }
+    }
+
+    /// 元素的数量。
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// 集合的数量。
+    #[inline]
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+
+    /// 添加一个新的单元素集合，返回其中的元素。
+    pub fn make_set(&mut self) -> usize {
+        let x = self.len();
+        self.parents.push(x);
+        self.ranks.push(0);
+        self.sets += 1;
+        x
+    }
+
+    #[inline]
+    fn check(&self, x: usize) {
+        assert!(
+            x < self.len(),
+            "UnionFind index out of bounds: the len is {} but the index is {x}",
+            self.len()
+        );
+    }
+
+    /// 返回`x`所在集合的代表元素，并进行路径压缩。
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.find_immutable(x);
+        // 第二遍：将路径上的节点直接挂到根节点下
+        let mut cur = x;
+        while cur != root {
+            let next = self.parents[cur];
+            self.parents[cur] = root;
+            cur = next;
+        }
+        root
+    }
+
+    /// 返回`x`所在集合的代表元素，不修改树的结构。
+    pub fn find_immutable(&self, x: usize) -> usize {
+        self.check(x);
+        let mut cur = x;
+        while self.parents[cur] != cur {
+            cur = self.parents[cur];
+        }
+        cur
+    }
+
+    /// 合并`a`和`b`所在的集合。如果两者已经在同一个集合中，返回`false`。
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a == b {
+            return false;
+        }
+
+        let (child, root) = if self.ranks[a] < self.ranks[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[child] = root;
+        if self.ranks[child] == self.ranks[root] {
+            self.ranks[root] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    /// `a`和`b`是否在同一个集合中。
+    #[inline]
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// 将所有元素重新分为单元素集合，不改变元素的数量。
+    pub fn reset(&mut self) {
+        for (i, parent) in self.parents.iter_mut().enumerate() {
+            *parent = i;
+        }
+        self.ranks.fill(0);
+        self.sets = self.len();
+    }
+}
+
+impl Default for UnionFind {
+    #[inline]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// 按代表元素输出每个元素所在的集合。
+impl fmt::Debug for UnionFind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.len()).map(|x| self.find_immutable(x)))
+            .finish()
+    }
+}
@@ -1,24 +1,42 @@
+#[cfg(feature = "bytes")]
+mod bytes_impl;
+mod chunk_by;
+mod cursor;
 mod drain;
 mod into_iter;
+mod map_in_place;
+mod merge;
+mod partition;
 mod raw_val_iter;
-mod raw_vec;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+mod shuffle;
+mod sort;
+mod sort_unstable;
+mod top_k;
 mod vec_macro;
 
-use raw_vec::MyRawVec;
 use std::borrow::{Borrow, BorrowMut};
 use std::hash::{Hash, Hasher};
-use std::mem::ManuallyDrop;
-use std::ops::{Deref, DerefMut};
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Add, AddAssign, Deref, DerefMut};
 use std::ptr::NonNull;
 use std::slice;
 use std::{cmp, ptr};
 
+use crate::collection;
+use crate::collection::raw::RawBuf;
+
+#[cfg(feature = "bytes")]
+pub use bytes_impl::MyVecReader;
+pub use chunk_by::IntoChunkBy;
+pub use cursor::CursorMut;
 pub use drain::Drain;
 pub use into_iter::IntoIter;
 
 #[derive(Debug)]
 pub struct MyVec<T> {
-    buf: MyRawVec<T>,
+    buf: RawBuf<T>,
     len: usize,
 }
 
@@ -74,7 +92,7 @@ impl<T> MyVec<T> {
     #[inline]
     pub fn new() -> Self {
         MyVec {
-            buf: MyRawVec::new(),
+            buf: RawBuf::new(),
             len: 0,
         }
     }
@@ -82,23 +100,21 @@ impl<T> MyVec<T> {
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         MyVec {
-            buf: MyRawVec::with_capacity(capacity),
+            buf: RawBuf::with_capacity(capacity),
             len: 0,
         }
     }
 
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
-        unsafe {
-            // SAFETY:
-            // 此处使用了filter来保证new_cap不会超过`isize::MAX`
-            self.buf.reserve_exact(
-                self.len
-                    .checked_add(additional)
-                    .filter(|&new_cap| new_cap <= isize::MAX as usize)
-                    .expect("Allocation too large"),
-            );
-        }
+        self.buf.reserve_exact(self.len, additional);
+    }
+
+    /// 将容量缩小为`len`，多余的内存会被释放（`len == 0`时释放全部内存）。
+    /// 对于ZST，容量始终为`isize::MAX`，不做任何处理。
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.buf.shrink(self.len);
     }
 
     /// 详细说明见[`MyVec::drop`]
@@ -115,7 +131,12 @@ impl<T> MyVec<T> {
             self.len = 0;
 
             // 对`[T]`使用`drop_in_place`会对其中的每个元素调用`drop`。
-            ptr::drop_in_place(drop_array);
+            //
+            // release构建中，编译器会将不需要`drop`的情况优化掉，但debug构
+            // 建中仍然会进入`drop_in_place`，因此我们显式地跳过。
+            if mem::needs_drop::<T>() {
+                ptr::drop_in_place(drop_array);
+            }
         }
     }
 
@@ -219,6 +240,150 @@ impl<T> MyVec<T> {
         }
     }
 
+    /// 将长度缩短为`len`，并`drop`多余的元素。如果`len`大于当前长度，
+    /// 则什么也不做。
+    ///
+    /// 与[`MyVec::clear`]相同，我们先修改长度，再`drop`元素。
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), self.len - len);
+            self.len = len;
+            if mem::needs_drop::<T>() {
+                ptr::drop_in_place(tail);
+            }
+        }
+    }
+
+    /// 移除连续的、满足`same_bucket`的元素，只保留第一个。
+    ///
+    /// `same_bucket(a, b)`中，`a`是当前检查的元素，`b`是上一个被保留
+    /// 的元素，与[`Vec::dedup_by`]相同。
+    ///
+    /// 我们使用两个下标：`read`表示下一个待检查的元素，`write`表示下一
+    /// 个被保留的元素应当写入的位置。`write..read`之间是空洞（其中的
+    /// 元素已经被移走或者`drop`）。如果`same_bucket`或者某个元素的
+    /// `drop`发生了`panic`，守卫会将`read..len`中尚未检查的元素移动到
+    /// 空洞中并修正长度，从而保证不会重复`drop`。
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let len = self.len;
+        if len <= 1 {
+            return;
+        }
+
+        let mut gap = FillGapOnDrop {
+            read: 1,
+            write: 1,
+            vec: self,
+        };
+        let ptr = gap.vec.as_mut_ptr();
+
+        unsafe {
+            while gap.read < len {
+                let read_ptr = ptr.add(gap.read);
+                let prev_ptr = ptr.add(gap.write - 1);
+
+                if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                    // 先增加`read`，即使`drop`发生了`panic`，该元素也不会再次被`drop`
+                    gap.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                } else {
+                    ptr::copy(read_ptr, ptr.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+
+            gap.vec.len = gap.write;
+            mem::forget(gap);
+        }
+    }
+
+    /// 只保留满足`f`的元素，保持它们原有的顺序。
+    ///
+    /// 与[`MyVec::dedup_by`]相同，使用`read`和`write`两个下标，`panic`
+    /// 时由守卫填补空洞。
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let len = self.len;
+        let mut gap = FillGapOnDrop {
+            read: 0,
+            write: 0,
+            vec: self,
+        };
+        let ptr = gap.vec.as_mut_ptr();
+
+        unsafe {
+            while gap.read < len {
+                let read_ptr = ptr.add(gap.read);
+
+                if f(&mut *read_ptr) {
+                    if gap.read != gap.write {
+                        ptr::copy_nonoverlapping(read_ptr, ptr.add(gap.write), 1);
+                    }
+                    gap.write += 1;
+                    gap.read += 1;
+                } else {
+                    gap.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                }
+            }
+
+            gap.vec.len = gap.write;
+            mem::forget(gap);
+        }
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// 将`other`中的所有元素移动到`self`的末尾，`other`被清空，但保留其容量。
+    ///
+    /// 至多申请一次内存，然后使用[`ptr::copy_nonoverlapping`]一次性复制所有
+    /// 元素，不会克隆。复制之后直接将`other`的长度设为0，相当于`forget`了
+    /// 其中已经被移走的元素。
+    pub fn append(&mut self, other: &mut MyVec<T>) {
+        let count = other.len;
+        if count > self.capacity() - self.len {
+            self.reserve(count);
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.as_mut_ptr().add(self.len), count);
+            other.len = 0;
+        }
+        self.len += count;
+    }
+
+    /// 见[`collection::slice::rotate_left`]。
+    #[inline]
+    pub fn my_rotate_left(&mut self, mid: usize) {
+        collection::slice::rotate_left(self, mid);
+    }
+
+    /// 见[`collection::slice::rotate_right`]。
+    #[inline]
+    pub fn my_rotate_right(&mut self, k: usize) {
+        collection::slice::rotate_right(self, k);
+    }
+
     /// # Safety
     /// - ptr must have been allocated using the global allocator,
     ///   such as via the alloc::alloc function.
@@ -237,7 +402,7 @@ impl<T> MyVec<T> {
     #[inline]
     pub unsafe fn from_parts(ptr: NonNull<T>, length: usize, capacity: usize) -> Self {
         Self {
-            buf: unsafe { MyRawVec::from_parts(ptr, capacity) },
+            buf: unsafe { RawBuf::from_parts(ptr, capacity) },
             len: length,
         }
     }
@@ -246,12 +411,33 @@ impl<T> MyVec<T> {
     /// TODO: Finish safety doc
     pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Self {
         Self {
-            buf: unsafe { MyRawVec::from_raw_parts(ptr, capacity) },
+            buf: unsafe { RawBuf::from_raw_parts(ptr, capacity) },
             len: length,
         }
     }
 }
 
+/// [`MyVec::dedup_by`]和[`MyVec::retain_mut`]使用的守卫。
+///
+/// `write..read`之间是空洞，`drop`时将`read..len`中尚未检查的元素
+/// 移动到空洞中并修正长度。
+struct FillGapOnDrop<'a, T> {
+    read: usize,
+    write: usize,
+    vec: &'a mut MyVec<T>,
+}
+
+impl<T> Drop for FillGapOnDrop<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            let len = self.vec.len;
+            ptr::copy(ptr.add(self.read), ptr.add(self.write), len - self.read);
+            self.vec.len = self.write + (len - self.read);
+        }
+    }
+}
+
 impl<'a, T: Clone + 'a> MyVec<T> {
     fn extend_from_iter_ref<I: Iterator<Item = &'a T>>(&mut self, mut iter: I) {
         while let Some(refer) = iter.next() {
@@ -274,7 +460,8 @@ impl<T: Clone> MyVec<T> {
         let remain = self.capacity() - self.len();
         let needs = other.len();
         if needs > remain {
-            self.reserve(unsafe { needs.unchecked_sub(remain) });
+            // `reserve`的参数是相对于`len`而言的
+            self.reserve(needs);
         }
         unsafe { self.unchecked_extend_from_slice(other) }
     }
@@ -349,43 +536,22 @@ impl<T> Drop for MyVec<T> {
             ptr::drop_in_place(self.as_mut_slice());
         }
     }
-    // `MyRawVec`会自动帮助释放内存空间
+    // `RawBuf`会自动帮助释放内存空间
 }
 
+/// 每写入一个克隆就增加一次长度，而不是在最后一次性设置长度，这样即使
+/// `T::clone`发生了`panic`，已经克隆出来的元素也会随着`MyVec`被`drop`，
+/// 而不会被泄漏。
 impl<T: Clone> Clone for MyVec<T> {
     fn clone(&self) -> Self {
-        let raw = MyRawVec::<T>::with_capacity(self.len);
-        let ptr = raw.ptr().as_ptr();
-
-        for (idx, element) in self.iter().enumerate() {
-            unsafe {
-                let ptr = ptr.add(idx);
-                ptr::write(ptr, element.clone());
-            }
-        }
-
-        MyVec {
-            buf: raw,
-            len: self.len,
-        }
+        let mut vec = MyVec::with_capacity(self.len);
+        unsafe { vec.unchecked_extend_from_slice(self) };
+        vec
     }
 
     fn clone_from(&mut self, source: &Self) {
-        if self.capacity() < source.len() {
-            self.reserve(source.len() - self.capacity());
-        }
         self.clear();
-
-        let ptr = self.as_mut_ptr();
-        for (idx, refer) in source.iter().enumerate() {
-            unsafe {
-                ptr::write(ptr.add(idx), refer.clone());
-            }
-        }
-
-        unsafe {
-            self.set_len(source.len());
-        }
+        self.extend_from_slice(source);
     }
 }
 
@@ -421,6 +587,30 @@ impl<T: PartialEq, const N: usize> PartialEq<&[T; N]> for MyVec<T> {
     }
 }
 
+impl<T: PartialEq> PartialEq<MyVec<T>> for [T] {
+    fn eq(&self, other: &MyVec<T>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+impl<T: PartialEq> PartialEq<MyVec<T>> for &[T] {
+    fn eq(&self, other: &MyVec<T>) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<MyVec<T>> for [T; N] {
+    fn eq(&self, other: &MyVec<T>) -> bool {
+        self.as_slice().eq(&**other)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<MyVec<T>> for &[T; N] {
+    fn eq(&self, other: &MyVec<T>) -> bool {
+        self.as_slice().eq(&**other)
+    }
+}
+
 impl<T> Extend<T> for MyVec<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         self.extend_from_iter(iter.into_iter());
@@ -433,6 +623,42 @@ impl<'a, T: Clone> Extend<&'a T> for MyVec<T> {
     }
 }
 
+/// 拼接两个`MyVec`，`rhs`中的元素被移动而不是克隆，见[`MyVec::append`]。
+impl<T> Add for MyVec<T> {
+    type Output = MyVec<T>;
+
+    #[inline]
+    fn add(mut self, mut rhs: MyVec<T>) -> MyVec<T> {
+        self.append(&mut rhs);
+        self
+    }
+}
+
+impl<T> AddAssign for MyVec<T> {
+    #[inline]
+    fn add_assign(&mut self, mut rhs: MyVec<T>) {
+        self.append(&mut rhs);
+    }
+}
+
+/// 克隆`rhs`中的元素并追加到末尾，见[`MyVec::extend_from_slice`]。
+impl<T: Clone> Add<&[T]> for MyVec<T> {
+    type Output = MyVec<T>;
+
+    #[inline]
+    fn add(mut self, rhs: &[T]) -> MyVec<T> {
+        self.extend_from_slice(rhs);
+        self
+    }
+}
+
+impl<T: Clone> AddAssign<&[T]> for MyVec<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &[T]) {
+        self.extend_from_slice(rhs);
+    }
+}
+
 impl<T> FromIterator<T> for MyVec<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let iter = iter.into_iter();
@@ -508,6 +734,54 @@ impl<T: PartialOrd> PartialOrd<MyVec<T>> for MyVec<T> {
     }
 }
 
+impl<T: PartialOrd> PartialOrd<[T]> for MyVec<T> {
+    fn partial_cmp(&self, other: &[T]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(other)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<&[T]> for MyVec<T> {
+    fn partial_cmp(&self, other: &&[T]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(*other)
+    }
+}
+
+impl<T: PartialOrd, const N: usize> PartialOrd<[T; N]> for MyVec<T> {
+    fn partial_cmp(&self, other: &[T; N]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialOrd, const N: usize> PartialOrd<&[T; N]> for MyVec<T> {
+    fn partial_cmp(&self, other: &&[T; N]) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<MyVec<T>> for [T] {
+    fn partial_cmp(&self, other: &MyVec<T>) -> Option<cmp::Ordering> {
+        self.partial_cmp(&**other)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<MyVec<T>> for &[T] {
+    fn partial_cmp(&self, other: &MyVec<T>) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: PartialOrd, const N: usize> PartialOrd<MyVec<T>> for [T; N] {
+    fn partial_cmp(&self, other: &MyVec<T>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(&**other)
+    }
+}
+
+impl<T: PartialOrd, const N: usize> PartialOrd<MyVec<T>> for &[T; N] {
+    fn partial_cmp(&self, other: &MyVec<T>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(&**other)
+    }
+}
+
 impl<T: Ord> Ord for MyVec<T> {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         (**self).cmp(&**other)
@@ -538,9 +812,13 @@ impl<T> AsRef<MyVec<T>> for MyVec<T> {
     }
 }
 
+/// 与切片的哈希值相同，这是[`Borrow<[T]>`](Borrow)所要求的。
+///
+/// 注意不能只使用[`Hash::hash_slice`]：它不包含长度，对于ZST，不同长度
+/// 的容器会得到相同的哈希值。
 impl<T: Hash> Hash for MyVec<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        <T as Hash>::hash_slice(self, state);
+        (**self).hash(state);
     }
 }
 
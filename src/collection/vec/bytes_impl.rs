@@ -0,0 +1,153 @@
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut};
+
+use crate::collection::vec::MyVec;
+
+/// 与标准库的`Vec<u8>`相同，`MyVec<u8>`可以作为[`BufMut`]的写入目标，
+/// 容量不足时自动扩容。
+///
+/// ```rust
+/// use bytes::BufMut;
+/// use rust_practice::collection::vec::MyVec;
+///
+/// let mut vec = MyVec::new();
+/// vec.put_u16(0x0102);
+/// vec.put_slice(b"abc");
+/// assert_eq!(vec, [1, 2, b'a', b'b', b'c']);
+/// ```
+unsafe impl BufMut for MyVec<u8> {
+    /// 一次分配不能超过`isize::MAX`字节，因此最多还能写入
+    /// `isize::MAX - len`个字节。
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        isize::MAX as usize - self.len
+    }
+
+    /// ## Safety
+    ///
+    /// 调用者必须已经初始化了`len..len + cnt`，通常是通过
+    /// [`BufMut::chunk_mut`]写入的。
+    ///
+    /// ## Panics
+    ///
+    /// `cnt`超过剩余的容量时`panic`，而不是扩容：超出容量的部分不可能
+    /// 已经被初始化。
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let remaining = self.capacity() - self.len;
+        assert!(
+            cnt <= remaining,
+            "advance out of bounds: the remaining capacity is {remaining} but advancing by {cnt}"
+        );
+        self.len += cnt;
+    }
+
+    /// 返回尚未使用的容量，没有剩余容量时先扩容。扩容是按倍数进行的，
+    /// 因此连续写入的均摊复杂度为`O(1)`。
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.capacity() == self.len {
+            self.buf.reserve(self.len, 64);
+        }
+
+        let spare = self.capacity() - self.len;
+        // SAFETY: `len..cap`位于分配的内存之内
+        unsafe { UninitSlice::from_raw_parts_mut(self.as_mut_ptr().add(self.len), spare) }
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.extend_from_slice(src);
+    }
+}
+
+/// 以[`Buf`]的方式读取`MyVec<u8>`。
+///
+/// `MyVec`本身没有读取位置，因此我们将其与一个游标包装在一起：已经读
+/// 取的字节不会被移除，[`Buf::chunk`]直接返回`vec[pos..]`，不会复制。
+///
+/// ```rust
+/// use bytes::{Buf, BufMut};
+/// use rust_practice::collection::vec::{MyVec, MyVecReader};
+///
+/// let mut vec = MyVec::new();
+/// vec.put_u32(7);
+/// vec.put_slice(b"hi");
+///
+/// let mut reader = MyVecReader::new(vec);
+/// assert_eq!(reader.get_u32(), 7);
+/// assert_eq!(reader.chunk(), b"hi");
+/// assert_eq!(reader.position(), 4);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MyVecReader {
+    vec: MyVec<u8>,
+    pos: usize,
+}
+
+impl MyVecReader {
+    #[inline]
+    pub fn new(vec: MyVec<u8>) -> Self {
+        Self { vec, pos: 0 }
+    }
+
+    /// 已经读取的字节数。
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// 将游标移动到`pos`处，之前读取过的字节可以再次读取。
+    ///
+    /// ## Panics
+    ///
+    /// `pos > len`时`panic`。
+    #[inline]
+    pub fn set_position(&mut self, pos: usize) {
+        assert!(pos <= self.vec.len(), "position out of bounds");
+        self.pos = pos;
+    }
+
+    #[inline]
+    pub fn get_ref(&self) -> &MyVec<u8> {
+        &self.vec
+    }
+
+    /// 返回包装的`MyVec`，包括已经读取的字节。
+    #[inline]
+    pub fn into_inner(self) -> MyVec<u8> {
+        self.vec
+    }
+}
+
+impl From<MyVec<u8>> for MyVecReader {
+    #[inline]
+    fn from(vec: MyVec<u8>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl Buf for MyVecReader {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.vec.len() - self.pos
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        &self.vec[self.pos..]
+    }
+
+    /// ## Panics
+    ///
+    /// `cnt`超过剩余的字节数时`panic`。
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        let remaining = self.remaining();
+        assert!(
+            cnt <= remaining,
+            "advance out of bounds: the remaining length is {remaining} but advancing by {cnt}"
+        );
+        self.pos += cnt;
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::{mem, ptr, slice};
+
+use crate::collection::raw::RawBuf;
+use crate::collection::vec::MyVec;
+
+/// [`MyVec::into_chunk_by`]返回的迭代器，按值产出由连续元素组成的分组。
+///
+/// 与[`IntoIter`](crate::collection::vec::IntoIter)相同，`IntoChunkBy`
+/// 取得`MyVec`的缓冲区，`start..end`是尚未产出的元素。每次产出一组时，先
+/// 用`pred`找到这一组的末尾，然后申请一个恰好能容纳这一组的`MyVec`，
+/// 将这些元素一次性复制过去，并将`start`移动到这一组之后，因此每个元素只
+/// 会被移动一次。
+///
+/// 如果迭代器在中途被丢弃，或者`pred`发生了`panic`，`drop`时只会释放
+/// `start..end`中的元素，已经产出的元素由调用者负责，不会被重复`drop`。
+pub struct IntoChunkBy<T, F> {
+    buf: RawBuf<T>,
+    start: usize,
+    end: usize,
+    pred: F,
+}
+
+impl<T> MyVec<T> {
+    /// 按值将数组分为若干组，`pred(a, b)`对每一组中所有相邻的元素都成立，
+    /// 与切片的`chunk_by`类似，但产出的是`MyVec<T>`，可以发送到其他线程。
+    ///
+    /// 空数组不产生任何分组，最后一组即使只有一个元素也会被产出。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let vec = my_vec![1, 1, 2, 3, 3, 3, 1];
+    /// let groups: Vec<_> = vec.into_chunk_by(|a, b| a == b).collect();
+    /// assert_eq!(groups.len(), 4);
+    /// assert_eq!(groups[2], [3, 3, 3]);
+    /// assert_eq!(groups[3], [1]);
+    /// ```
+    pub fn into_chunk_by<F: FnMut(&T, &T) -> bool>(self, pred: F) -> IntoChunkBy<T, F> {
+        let this = mem::ManuallyDrop::new(self);
+        IntoChunkBy {
+            // SAFETY: `this`不会再被使用，缓冲区的所有权转移给迭代器
+            buf: unsafe { ptr::read(&this.buf) },
+            start: 0,
+            end: this.len,
+            pred,
+        }
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> Iterator for IntoChunkBy<T, F> {
+    type Item = MyVec<T>;
+
+    fn next(&mut self) -> Option<MyVec<T>> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let base = self.buf.ptr().as_ptr();
+        let mut run_end = self.start + 1;
+        // SAFETY: `start..end`中的元素都是有效的
+        while run_end < self.end
+            && unsafe { (self.pred)(&*base.add(run_end - 1), &*base.add(run_end)) }
+        {
+            run_end += 1;
+        }
+
+        let len = run_end - self.start;
+        let mut group = MyVec::with_capacity(len);
+        unsafe {
+            ptr::copy_nonoverlapping(base.add(self.start), group.as_mut_ptr(), len);
+            group.set_len(len);
+        }
+        self.start = run_end;
+        Some(group)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining.min(1), Some(remaining))
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> FusedIterator for IntoChunkBy<T, F> {}
+
+impl<T, F> Drop for IntoChunkBy<T, F> {
+    /// 释放尚未产出的元素，缓冲区由`RawBuf`释放。
+    fn drop(&mut self) {
+        unsafe {
+            let rest = ptr::slice_from_raw_parts_mut(
+                self.buf.ptr().as_ptr().add(self.start),
+                self.end - self.start,
+            );
+            ptr::drop_in_place(rest);
+        }
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for IntoChunkBy<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rest = unsafe {
+            slice::from_raw_parts(
+                self.buf.ptr().as_ptr().add(self.start),
+                self.end - self.start,
+            )
+        };
+        f.debug_tuple("IntoChunkBy").field(&rest).finish()
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+use std::ptr;
+use std::slice;
+
+use crate::collection::vec::MyVec;
+
+/// [`MyVec::cursor_mut`]返回的光标，用于在同一位置附近连续地插入和删除
+/// 元素。
+///
+/// ## 间隙
+///
+/// 与[`GapBuffer`]相同，光标在缓冲区中维护一个间隙：光标之前的元素位于
+/// `0..vec.len`，光标处及之后的元素被移动到了缓冲区的末尾，即
+/// `gap_end..cap`，两者之间是未初始化的空间：
+///
+/// ```text
+///   [a b c . . . . d e]
+///          ^       ^
+///       vec.len  gap_end（当前元素为d）
+/// ```
+///
+/// 于是在光标处插入或删除元素只需要移动间隙的边界，不需要移动尾部的元
+/// 素；移动光标也只需要将一个元素从间隙的一侧移动到另一侧。间隙不足时
+/// 按倍数扩容，因此均摊复杂度为`O(1)`。
+///
+/// ## 泄漏安全
+///
+/// 我们始终将`vec.len`设置为光标之前的元素个数，因此即使光标被
+/// [`mem::forget`](std::mem::forget)，`MyVec`仍然是合法的，只是间隙之后
+/// 的元素会被泄漏。光标正常`drop`（包括`panic`导致的unwind）时，会将间
+/// 隙之后的元素移回来，关闭间隙并恢复长度。
+///
+/// [`GapBuffer`]: crate::collection::gap_buffer::GapBuffer
+pub struct CursorMut<'a, T> {
+    vec: &'a mut MyVec<T>,
+    /// 光标处及之后的元素位于`gap_end..cap`
+    gap_end: usize,
+}
+
+impl<T> MyVec<T> {
+    /// 创建一个位于`at`处的光标，`at == len`时光标位于末尾，没有当前元
+    /// 素。
+    ///
+    /// 创建时需要将`at..len`移动到缓冲区的末尾，需要`O(len - at)`，与一
+    /// 次[`MyVec::insert`]相同，之后在光标附近的编辑都是`O(1)`的。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let mut vec = my_vec![1, 2, 5];
+    /// let mut cursor = vec.cursor_mut(2);
+    /// cursor.insert_before(3);
+    /// cursor.insert_before(4);
+    /// assert_eq!(cursor.current(), Some(&mut 5));
+    /// drop(cursor);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// `at > len`时`panic`。
+    pub fn cursor_mut(&mut self, at: usize) -> CursorMut<'_, T> {
+        assert!(at <= self.len, "cursor index out of bounds");
+
+        let tail_len = self.len - at;
+        let gap_end = self.capacity() - tail_len;
+        unsafe {
+            let base = self.as_mut_ptr();
+            ptr::copy(base.add(at), base.add(gap_end), tail_len);
+        }
+        self.len = at;
+        CursorMut { vec: self, gap_end }
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// 光标的位置，即光标之前的元素个数。
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.vec.len
+    }
+
+    /// 所有元素的个数，不包括间隙。
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len + self.back_len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn back_len(&self) -> usize {
+        self.vec.capacity() - self.gap_end
+    }
+
+    #[inline]
+    fn gap_len(&self) -> usize {
+        self.gap_end - self.vec.len
+    }
+
+    /// 光标处的元素，光标位于末尾时返回`None`。
+    pub fn current(&mut self) -> Option<&mut T> {
+        if self.back_len() == 0 {
+            None
+        } else {
+            unsafe { Some(&mut *self.vec.as_mut_ptr().add(self.gap_end)) }
+        }
+    }
+
+    /// 光标之前的元素，以及当前元素及之后的元素。
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        unsafe {
+            let base = self.vec.as_ptr();
+            (
+                slice::from_raw_parts(base, self.vec.len),
+                slice::from_raw_parts(base.add(self.gap_end), self.back_len()),
+            )
+        }
+    }
+
+    /// 将光标移动到下一个元素，光标位于末尾时什么也不做。
+    pub fn move_next(&mut self) {
+        if self.back_len() == 0 {
+            return;
+        }
+        unsafe {
+            let base = self.vec.as_mut_ptr();
+            // 间隙为空时两者是同一个位置
+            ptr::copy(base.add(self.gap_end), base.add(self.vec.len), 1);
+        }
+        self.gap_end += 1;
+        self.vec.len += 1;
+    }
+
+    /// 将光标移动到上一个元素，光标位于开头时什么也不做。
+    pub fn move_prev(&mut self) {
+        if self.vec.len == 0 {
+            return;
+        }
+        self.vec.len -= 1;
+        self.gap_end -= 1;
+        unsafe {
+            let base = self.vec.as_mut_ptr();
+            ptr::copy(base.add(self.vec.len), base.add(self.gap_end), 1);
+        }
+    }
+
+    /// 在光标之前插入`value`，光标仍然指向原来的元素。
+    pub fn insert_before(&mut self, value: T) {
+        self.reserve_gap(1);
+        unsafe {
+            ptr::write(self.vec.as_mut_ptr().add(self.vec.len), value);
+        }
+        self.vec.len += 1;
+    }
+
+    /// 在当前元素之后插入`value`，光标仍然指向原来的元素。光标位于末尾
+    /// 时，`value`会成为新的当前元素。
+    pub fn insert_after(&mut self, value: T) {
+        self.reserve_gap(1);
+        unsafe {
+            let base = self.vec.as_mut_ptr();
+            if self.back_len() == 0 {
+                self.gap_end -= 1;
+                ptr::write(base.add(self.gap_end), value);
+            } else {
+                // 将当前元素向间隙移动一个位置，`value`写在它原来的位置上
+                self.gap_end -= 1;
+                ptr::copy_nonoverlapping(base.add(self.gap_end + 1), base.add(self.gap_end), 1);
+                ptr::write(base.add(self.gap_end + 1), value);
+            }
+        }
+    }
+
+    /// 移除并返回当前元素，光标随之指向下一个元素。光标位于末尾时返回
+    /// `None`。
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.back_len() == 0 {
+            return None;
+        }
+        let value = unsafe { ptr::read(self.vec.as_ptr().add(self.gap_end)) };
+        self.gap_end += 1;
+        Some(value)
+    }
+
+    /// 在光标之前依次插入`slice`中的元素的克隆。
+    ///
+    /// 每写入一个元素就增加一次长度，因此即使`clone`发生了`panic`，已经
+    /// 写入的元素也会被保留。
+    pub fn splice_before(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve_gap(slice.len());
+        for elem in slice {
+            unsafe {
+                ptr::write(self.vec.as_mut_ptr().add(self.vec.len), elem.clone());
+            }
+            self.vec.len += 1;
+        }
+    }
+
+    /// 保证间隙中至少有`additional`个位置。
+    ///
+    /// 扩容时只会保证`0..old_cap`中的数据不变，因此扩容后需要将间隙之后
+    /// 的元素移动到新缓冲区的末尾。
+    fn reserve_gap(&mut self, additional: usize) {
+        if self.gap_len() >= additional {
+            return;
+        }
+
+        let old_cap = self.vec.capacity();
+        let back_len = self.back_len();
+        self.vec.buf.reserve(self.len(), additional);
+        let new_gap_end = self.vec.capacity() - back_len;
+        unsafe {
+            let base = self.vec.as_mut_ptr();
+            ptr::copy(
+                base.add(self.gap_end),
+                base.add(new_gap_end),
+                old_cap - self.gap_end,
+            );
+        }
+        self.gap_end = new_gap_end;
+    }
+}
+
+impl<T> Drop for CursorMut<'_, T> {
+    /// 将间隙之后的元素移回来，关闭间隙。
+    fn drop(&mut self) {
+        let back_len = self.back_len();
+        unsafe {
+            let base = self.vec.as_mut_ptr();
+            ptr::copy(base.add(self.gap_end), base.add(self.vec.len), back_len);
+        }
+        self.vec.len += back_len;
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CursorMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (before, after) = self.as_slices();
+        f.debug_struct("CursorMut")
+            .field("before", &before)
+            .field("after", &after)
+            .finish()
+    }
+}
@@ -1,5 +1,5 @@
-use std::ptr;
 use std::{marker::PhantomData, ops::RangeBounds, ptr::NonNull};
+use std::{mem, ptr};
 
 use crate::collection;
 use crate::collection::vec::{MyVec, raw_val_iter::RawValIter};
@@ -17,7 +17,7 @@ use crate::collection::vec::{MyVec, raw_val_iter::RawValIter};
 ///
 /// 编写`Drain`的迭代基本可以套用[`RawValIter`]，我们将值移出
 /// 缓冲区之后，就将那块内存当作未初始化的内存。编写析构的时
-/// 候，只需要把未移出缓冲区的元素全部移出缓冲区，然后将`MyRawVec`
+/// 候，只需要把未移出缓冲区的元素全部移出缓冲区，然后将`RawBuf`
 /// 中后面的元素向前移动补位，并设置合适的长度即可。
 ///
 /// 根据Rustonomicon，这样编写`Drain`时，可能会存在一个问题：
@@ -88,31 +88,49 @@ impl<'a, T> ExactSizeIterator for Drain<'a, T> {
 
 impl<'a, T> Drop for Drain<'a, T> {
     fn drop(&mut self) {
-        // 这会自动drop剩余元素
-        for _ in &mut *self {}
-
-        let vec_ptr = self.vec.as_ptr();
-
-        let before_len = self.before_len;
-        let after_len = self.after_len;
-
-        // SAFETY:
-        // 此处无论是`before_len`还是`old_len - after_len`都是不超过
-        // `old_len`的，因此不会到分配空间之外。
-        //
-        // 此外，`vec_len - after_len`的结果为创建时的`range.end`，不可能
-        // 下溢。
-        //
-        // 我们始终保证`after_len`记录了`range.end`之后（包括本身）的元素
-        // 个数，所以使用[`copy`]不会越界。`before_len + after_len`正好是
-        // 剩下的元素个数，我们用该值恢复[`MyVec`]的长度。
-        unsafe {
-            let hole_begin = vec_ptr.add(before_len);
-            let hole_end = vec_ptr.add(self.old_len - after_len);
-
-            ptr::copy(hole_end, hole_begin, after_len);
-            self.vec.as_mut().set_len(before_len + after_len);
-        };
+        /// 将尾部元素移回来并恢复长度。放在守卫中，这样即使剩余元素的
+        /// `drop`发生了`panic`，`MyVec`也会在unwind时被恢复，而不是泄漏
+        /// 所有的元素。
+        struct MoveTail<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+        impl<T> Drop for MoveTail<'_, '_, T> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let vec_ptr = unsafe { drain.vec.as_mut().as_mut_ptr() };
+
+                let before_len = drain.before_len;
+                let after_len = drain.after_len;
+
+                // SAFETY:
+                // 此处无论是`before_len`还是`old_len - after_len`都是不超过
+                // `old_len`的，因此不会到分配空间之外。
+                //
+                // 此外，`vec_len - after_len`的结果为创建时的`range.end`，不可能
+                // 下溢。
+                //
+                // 我们始终保证`after_len`记录了`range.end`之后（包括本身）的元素
+                // 个数，所以使用[`copy`]不会越界。`before_len + after_len`正好是
+                // 剩下的元素个数，我们用该值恢复[`MyVec`]的长度。
+                unsafe {
+                    let hole_begin = vec_ptr.add(before_len);
+                    let hole_end = vec_ptr.add(drain.old_len - after_len);
+
+                    ptr::copy(hole_end, hole_begin, after_len);
+                    drain.vec.as_mut().set_len(before_len + after_len);
+                };
+            }
+        }
+
+        let guard = MoveTail(self);
+
+        // 对切片使用`drop_in_place`时，即使某个元素的`drop`发生了`panic`，
+        // 其余的元素仍然会被`drop`。对于不需要`drop`的类型，剩余元素所在
+        // 的位置随后会被尾部元素覆盖，因此直接跳过
+        if mem::needs_drop::<T>() {
+            unsafe {
+                ptr::drop_in_place(guard.0.iter.remaining_mut());
+            }
+        }
     }
 }
 
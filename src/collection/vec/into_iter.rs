@@ -1,4 +1,5 @@
-use crate::collection::vec::{MyVec, raw_val_iter::RawValIter, raw_vec::MyRawVec};
+use crate::collection::raw::RawBuf;
+use crate::collection::vec::{MyVec, raw_val_iter::RawValIter};
 use std::iter::FusedIterator;
 use std::mem;
 use std::ptr;
@@ -15,7 +16,7 @@ use std::slice;
 ///
 /// [`IntoIter`]按值消费`MyVec`，并依序按值产出其中的元素。
 /// 为了获取元素的所有权，需要让`IntoIter`获取`MyVec`分配
-/// 的空间，因此需要一个`MyRawVec`。由于[`Vec`]的特性，
+/// 的空间，因此需要一个`RawBuf`。由于[`Vec`]的特性，
 /// `IntoIter`也应该是[`DoubleEndedIterator`]，为支持该特
 /// 性，可以使用两个指针指向开始和超尾处。
 ///
@@ -65,9 +66,9 @@ use std::slice;
 /// 考虑到接下来[`Drain`]的逻辑中，也存在双向迭代，因此可
 /// 以将这部分的内容放到[`RawValIter`]中。
 pub struct IntoIter<T> {
-    // 我们并不使用`MyRawVec`中的任何逻辑，我们只是希望保有缓冲区，
+    // 我们并不使用`RawBuf`中的任何逻辑，我们只是希望保有缓冲区，
     // 并在使用完后自动释放内存空间。
-    _buf: MyRawVec<T>,
+    _buf: RawBuf<T>,
     iter: RawValIter<T>,
 }
 
@@ -97,9 +98,11 @@ impl<T> FusedIterator for IntoIter<T> {}
 
 impl<T> Drop for IntoIter<T> {
     fn drop(&mut self) {
-        unsafe {
-            let drop_array: *mut [T] = slice::from_raw_parts_mut(self.iter.start_mut(), self.len());
-            ptr::drop_in_place(drop_array);
+        // `len`需要根据两个指针计算，不需要`drop`时连这一步也可以省去
+        if mem::needs_drop::<T>() {
+            unsafe {
+                ptr::drop_in_place(self.iter.remaining_mut());
+            }
         }
     }
 }
@@ -0,0 +1,95 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+
+use crate::collection::vec::MyVec;
+
+/// [`MyVec::map_in_place`]使用的守卫。
+///
+/// `0..mapped`已经是`U`，`mapped`处的元素已经被移入`f`，`mapped + 1..len`
+/// 仍然是`T`。只有在`f`发生`panic`时才会被`drop`，分别释放两段元素，缓冲区
+/// 本身由原来的`MyVec<T>`释放。
+struct MapGuard<T, U> {
+    ptr: *mut T,
+    mapped: usize,
+    len: usize,
+    _marker: PhantomData<U>,
+}
+
+impl<T, U> Drop for MapGuard<T, U> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.ptr.cast::<U>(),
+                self.mapped,
+            ));
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.ptr.add(self.mapped + 1),
+                self.len - self.mapped - 1,
+            ));
+        }
+    }
+}
+
+impl<T> MyVec<T> {
+    /// 与`into_iter().map(f).collect()`相同，但复用原来的缓冲区，不申请任
+    /// 何新的内存。
+    ///
+    /// 要求`T`与`U`的大小和对齐都相同，这一点在单态化时通过inline const
+    /// 检查，不满足时产生编译错误。每次读出一个`T`，将`f`的结果写回同一个
+    /// 位置，最后将缓冲区重新解释为`MyVec<U>`，容量保持不变。
+    ///
+    /// 如果`f`在第`k`个元素处发生了`panic`，已经转换的`0..k`会作为`U`被
+    /// `drop`，尚未转换的`k + 1..len`会作为`T`被`drop`，第`k`个元素已经
+    /// 移入了`f`，由`f`负责。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let vec = my_vec![1u32, 2, 3];
+    /// let ptr = vec.as_ptr();
+    /// let floats = vec.map_in_place(|x| x as f32 / 2.0);
+    /// assert_eq!(floats, [0.5, 1.0, 1.5]);
+    /// assert_eq!(floats.as_ptr().cast(), ptr);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use rust_practice::my_vec;
+    ///
+    /// let _ = my_vec![1u32, 2, 3].map_in_place(|x| x as u64);
+    /// ```
+    pub fn map_in_place<U, F: FnMut(T) -> U>(mut self, mut f: F) -> MyVec<U> {
+        const {
+            assert!(
+                mem::size_of::<T>() == mem::size_of::<U>()
+                    && mem::align_of::<T>() == mem::align_of::<U>(),
+                "map_in_place requires T and U to have the same size and alignment"
+            )
+        };
+
+        let (len, cap) = (self.len, self.capacity());
+        // 元素由守卫管理，`self`只负责在`panic`时释放缓冲区
+        self.len = 0;
+        let mut guard = MapGuard::<T, U> {
+            ptr: self.as_mut_ptr(),
+            mapped: 0,
+            len,
+            _marker: PhantomData,
+        };
+
+        while guard.mapped < len {
+            unsafe {
+                let src = guard.ptr.add(guard.mapped);
+                let value = f(ptr::read(src));
+                ptr::write(src.cast::<U>(), value);
+            }
+            guard.mapped += 1;
+        }
+
+        let ptr = guard.ptr.cast::<U>();
+        mem::forget(guard);
+        mem::forget(self);
+        // SAFETY: 大小和对齐相同，因此原来的分配也是`cap`个`U`的合法分配
+        unsafe { MyVec::from_parts(NonNull::new_unchecked(ptr), len, cap) }
+    }
+}
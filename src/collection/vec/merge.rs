@@ -0,0 +1,73 @@
+use std::cmp::Ordering;
+
+use crate::collection::vec::MyVec;
+
+impl<T> MyVec<T> {
+    /// 将两个有序的`MyVec`合并为一个有序的`MyVec`，消耗两者。
+    ///
+    /// 结果的缓冲区预先申请`self.len() + other.len()`个元素的空间，之后通
+    /// 过两者的[`IntoIter`](crate::collection::vec::IntoIter)逐个移出元素，
+    /// 只需要一趟、`O(n + m)`次比较，不会克隆元素，也不会重新分配内存。
+    ///
+    /// 合并是稳定的：相等的元素中，`self`中的元素排在`other`中的元素之前。
+    ///
+    /// ## 输入必须有序
+    ///
+    /// 我们不会检查`self`和`other`是否按照`compare`有序。如果不是，结果中
+    /// 的元素仍然恰好是两者的所有元素，但是顺序是不确定的。`compare`发生
+    /// `panic`时，所有元素都会被正常地`drop`。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let left = my_vec![(1, 'a'), (3, 'a'), (3, 'b')];
+    /// let right = my_vec![(2, 'c'), (3, 'c')];
+    /// let merged = left.merge_sorted_by(right, |a, b| a.0.cmp(&b.0));
+    /// assert_eq!(merged, [(1, 'a'), (2, 'c'), (3, 'a'), (3, 'b'), (3, 'c')]);
+    /// ```
+    pub fn merge_sorted_by<F>(self, other: MyVec<T>, mut compare: F) -> MyVec<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self
+            .len()
+            .checked_add(other.len())
+            .expect("capacity overflow");
+        let mut merged = MyVec::with_capacity(len);
+        let mut left = self.into_iter().peekable();
+        let mut right = other.into_iter().peekable();
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            // 只有右边严格更小时才取右边，保证稳定
+            let next = if compare(b, a) == Ordering::Less {
+                right.next()
+            } else {
+                left.next()
+            };
+            merged.push(next.unwrap());
+        }
+        // 至多一边还有剩余的元素，它们已经有序
+        merged.extend(left);
+        merged.extend(right);
+        merged
+    }
+
+    /// 见[`MyVec::merge_sorted_by`]。
+    #[inline]
+    pub fn merge_sorted(self, other: MyVec<T>) -> MyVec<T>
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, T::cmp)
+    }
+
+    /// 按照`f`返回的键合并，见[`MyVec::merge_sorted_by`]。
+    #[inline]
+    pub fn merge_sorted_by_key<K: Ord, F: FnMut(&T) -> K>(
+        self,
+        other: MyVec<T>,
+        mut f: F,
+    ) -> MyVec<T> {
+        self.merge_sorted_by(other, |a, b| f(a).cmp(&f(b)))
+    }
+}
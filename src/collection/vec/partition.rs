@@ -0,0 +1,76 @@
+use crate::collection::bit_vec::BitVec;
+use crate::collection::vec::MyVec;
+
+impl<T> MyVec<T> {
+    /// 重新排列元素，使满足`pred`的元素都在不满足的元素之前，返回第一个不
+    /// 满足`pred`的元素的下标。
+    ///
+    /// **不稳定**：同一边的元素不一定保持原来的先后顺序。
+    ///
+    /// 使用两个下标从两端向中间扫描，左边遇到不满足的元素、右边遇到满足的
+    /// 元素时将两者交换。每个元素至多被`pred`检查一次，只交换元素，不申请
+    /// 内存，`T`也不需要实现`Clone`。由于只有交换，即使`pred`发生了
+    /// `panic`，`MyVec`中的元素仍然恰好是原来的那些元素。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let mut vec = my_vec![1, 2, 3, 4, 5, 6];
+    /// let mid = vec.partition_in_place(|x| x % 2 == 0);
+    /// assert_eq!(mid, 3);
+    /// assert!(vec[..mid].iter().all(|x| x % 2 == 0));
+    /// assert!(vec[mid..].iter().all(|x| x % 2 == 1));
+    /// ```
+    pub fn partition_in_place<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> usize {
+        // 循环不变式：`self[..l]`都满足`pred`，`self[r..]`都不满足
+        let (mut l, mut r) = (0, self.len());
+        loop {
+            while l < r && pred(&self[l]) {
+                l += 1;
+            }
+            // 此时若`l < r`，则`self[l]`不满足，不需要再次检查
+            loop {
+                if r - l <= 1 {
+                    return l;
+                }
+                r -= 1;
+                if pred(&self[r]) {
+                    break;
+                }
+            }
+            self.swap(l, r);
+            l += 1;
+        }
+    }
+
+    /// 将元素移动到两个新的`MyVec`中：满足`pred`的元素和不满足的元素，两
+    /// 者都保持原来的先后顺序。
+    ///
+    /// 先对每个元素调用一次`pred`，将结果记录在[`BitVec`]中，于是两个结果
+    /// 都可以按照确切的长度申请一次内存，然后再将元素逐个移入。如果`pred`
+    /// 发生了`panic`，此时还没有移动任何元素，`self`会被正常地`drop`。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let (even, odd) = my_vec![1, 2, 3, 4, 5].partition(|x| x % 2 == 0);
+    /// assert_eq!(even, [2, 4]);
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// assert_eq!((even.capacity(), odd.capacity()), (2, 3));
+    /// ```
+    pub fn partition<P: FnMut(&T) -> bool>(self, pred: P) -> (MyVec<T>, MyVec<T>) {
+        let flags: BitVec = self.iter().map(pred).collect();
+        let matched = flags.count_ones();
+        let mut left = MyVec::with_capacity(matched);
+        let mut right = MyVec::with_capacity(self.len() - matched);
+
+        for (elem, flag) in self.into_iter().zip(&flags) {
+            if flag {
+                left.push(elem);
+            } else {
+                right.push(elem);
+            }
+        }
+        (left, right)
+    }
+}
@@ -164,6 +164,20 @@ impl<T> RawValIter<T> {
     pub fn end(&self) -> *const T {
         self.end
     }
+
+    /// 尚未被迭代的元素，用于`drop`剩余的元素。
+    ///
+    /// 对于ZST，`start`只是一个计数器，不一定是对齐的，因此使用
+    /// [`NonNull::dangling`]作为切片的起始位置。
+    #[inline]
+    pub fn remaining_mut(&self) -> *mut [T] {
+        let start = if mem::size_of::<T>() == 0 {
+            NonNull::dangling().as_ptr()
+        } else {
+            self.start_mut()
+        };
+        ptr::slice_from_raw_parts_mut(start, self.len())
+    }
 }
 
 impl<T> Iterator for RawValIter<T> {
@@ -243,3 +257,35 @@ impl<T> ExactSizeIterator for RawValIter<T> {
         (self.end() as usize - self.start() as usize) / if elem_size == 0 { 1 } else { elem_size }
     }
 }
+
+/// 见`src/verification.rs`。
+#[cfg(kani)]
+mod verification {
+    use super::RawValIter;
+
+    /// 无论以怎样的顺序从两端取出元素，`len`都等于剩余的元素个数，而且
+    /// 在`start == end`之后不会再产出元素，因此`end - start`不会下溢。
+    #[kani::proof]
+    #[kani::unwind(10)]
+    fn raw_val_iter_len_never_underflows() {
+        let mut arr: [u32; 8] = kani::any();
+        let len: usize = kani::any();
+        kani::assume(len <= arr.len());
+
+        let mut iter = unsafe { RawValIter::new(&mut arr[..len]) };
+        let mut remaining = len;
+        while remaining > 0 {
+            assert_eq!(iter.len(), remaining);
+            let elem = if kani::any() {
+                iter.next()
+            } else {
+                iter.next_back()
+            };
+            assert!(elem.is_some());
+            remaining -= 1;
+        }
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+}
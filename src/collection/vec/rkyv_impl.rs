@@ -0,0 +1,40 @@
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Serialize};
+
+use crate::collection::vec::MyVec;
+
+/// 与标准库的`Vec`相同，归档为[`ArchivedVec`]，因此两者的归档格式完全
+/// 一致，可以互相反序列化。
+impl<T: Archive> Archive for MyVec<T> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self, resolver, out);
+    }
+}
+
+impl<T: Serialize<S>, S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for MyVec<T> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self, serializer)
+    }
+}
+
+/// 先按照归档的长度申请一次内存，再逐个反序列化元素。如果某个元素反序
+/// 列化失败，已经得到的元素会随着`MyVec`正常地`drop`。
+impl<T, D> Deserialize<MyVec<T>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<MyVec<T>, D::Error> {
+        let mut vec = MyVec::with_capacity(self.len());
+        for item in self.iter() {
+            vec.push(item.deserialize(deserializer)?);
+        }
+        Ok(vec)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::collection::rng::RngCore;
+use crate::collection::vec::MyVec;
+
+impl<T> MyVec<T> {
+    /// 使用Fisher–Yates算法随机打乱所有元素，每一种排列出现的概率相同。
+    ///
+    /// 从后向前，将`self[i]`与`self[..=i]`中随机的一个元素交换。随机数由
+    /// [`RngCore::next_below`]生成，没有取模带来的偏差，相同的种子总是得到
+    /// 相同的排列。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::rng::XorShift64;
+    /// use rust_practice::collection::vec::MyVec;
+    ///
+    /// let mut a: MyVec<i32> = (0..10).collect();
+    /// let mut b = a.clone();
+    /// a.shuffle(&mut XorShift64::seed_from_u64(7));
+    /// b.shuffle(&mut XorShift64::seed_from_u64(7));
+    /// assert_eq!(a, b);
+    /// a.sort_stable();
+    /// assert!(a.iter().copied().eq(0..10));
+    /// ```
+    pub fn shuffle<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.len()).rev() {
+            let j = rng.next_below(i as u64 + 1) as usize;
+            self.swap(i, j);
+        }
+    }
+
+    /// 只随机选出`amount`个元素，放在最前面，返回选出的元素和其余的元素。
+    ///
+    /// 选出的元素是均匀随机的一个排列，其余的元素的顺序不确定。只需要
+    /// `amount`次交换，适合从很多元素中随机抽取少量元素。`amount`大于长度
+    /// 时，等价于[`MyVec::shuffle`]。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::rng::XorShift64;
+    /// use rust_practice::collection::vec::MyVec;
+    ///
+    /// let mut vec: MyVec<i32> = (0..100).collect();
+    /// let (chosen, rest) = vec.partial_shuffle(&mut XorShift64::seed_from_u64(1), 3);
+    /// assert_eq!((chosen.len(), rest.len()), (3, 97));
+    /// ```
+    pub fn partial_shuffle<R: RngCore + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        amount: usize,
+    ) -> (&mut [T], &mut [T]) {
+        let len = self.len();
+        let amount = amount.min(len);
+        for i in 0..amount {
+            let j = i + rng.next_below((len - i) as u64) as usize;
+            self.swap(i, j);
+        }
+        self.split_at_mut(amount)
+    }
+}
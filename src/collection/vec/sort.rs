@@ -0,0 +1,240 @@
+use std::cmp::{self, Ordering};
+use std::{mem, ptr};
+
+use crate::collection::raw::RawBuf;
+use crate::collection::vec::MyVec;
+
+/// 长度不超过该值的区间直接使用插入排序。
+pub(super) const INSERTION_LEN: usize = 20;
+
+impl<T> MyVec<T> {
+    /// 稳定排序：相等的元素保持原有的先后顺序。
+    ///
+    /// 我们没有调用切片的排序，而是自己实现了自底向上的归并排序：
+    ///
+    /// 1. 先将每`20`个元素为一组，使用插入排序；
+    /// 2. 然后将相邻的两组合并，每一轮组的大小翻倍，直到覆盖整个数组。
+    ///    如果左边一组的最后一个元素不大于右边一组的第一个元素，则两组已经
+    ///    有序，跳过合并，因此已经有序的输入只需要`O(n)`次比较。
+    ///
+    /// 合并时只把两组中较短的那一组复制到临时缓冲区中，因此缓冲区只需要
+    /// `len / 2`个元素的空间，我们使用[`RawBuf`]申请，最坏时间复杂度为
+    /// `O(n log n)`。
+    ///
+    /// ## Panic safety
+    ///
+    /// 如果`compare`发生了`panic`，`MyVec`中的元素仍然恰好是原来的那些元
+    /// 素（顺序不确定），不会丢失或者重复。插入排序和合并都使用一个守卫
+    /// 记录“空洞”，`drop`时将临时取出的元素写回空洞中。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let mut vec = my_vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    /// vec.sort_stable_by(|a, b| a.0.cmp(&b.0));
+    /// assert_eq!(vec, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+    /// ```
+    pub fn sort_stable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        merge_sort(self, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// 见[`MyVec::sort_stable_by`]。
+    #[inline]
+    pub fn sort_stable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_stable_by(T::cmp);
+    }
+
+    /// 按照`f`返回的键进行稳定排序，每次比较都会调用两次`f`。
+    #[inline]
+    pub fn sort_stable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_stable_by(|a, b| f(a).cmp(&f(b)));
+    }
+}
+
+fn merge_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let len = v.len();
+    // ZST的所有值都相同，排序没有可观察的效果
+    if mem::size_of::<T>() == 0 || len < 2 {
+        return;
+    }
+
+    for chunk in v.chunks_mut(INSERTION_LEN) {
+        insertion_sort(chunk, is_less);
+    }
+    if len <= INSERTION_LEN {
+        return;
+    }
+
+    let buf = RawBuf::<T>::with_capacity(len / 2);
+    let mut width = INSERTION_LEN;
+    while width < len {
+        let mut start = 0;
+        // 对于非ZST，`len <= isize::MAX`，`start + 2 * width`不会溢出
+        while start + width < len {
+            let end = cmp::min(start + 2 * width, len);
+            // SAFETY: 两组中较短的一组不超过`(end - start) / 2 <= len / 2`
+            unsafe {
+                merge(&mut v[start..end], width, buf.ptr().as_ptr(), is_less);
+            }
+            start = end;
+        }
+        width *= 2;
+    }
+}
+
+pub(super) fn insertion_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    for i in 1..v.len() {
+        // SAFETY: `i >= 1`，`v[..=i]`至少有两个元素
+        unsafe {
+            insert_tail(&mut v[..=i], is_less);
+        }
+    }
+}
+
+/// 在`panic`或者正常结束时，将`src`中的元素写入`dest`。
+struct InsertionHole<T> {
+    src: *const T,
+    dest: *mut T,
+}
+
+impl<T> Drop for InsertionHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::copy_nonoverlapping(self.src, self.dest, 1);
+        }
+    }
+}
+
+/// `v[..len - 1]`已经有序，将最后一个元素插入到合适的位置。
+///
+/// ## Safety
+///
+/// `v.len() >= 2`。
+unsafe fn insert_tail<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let arr = v.as_mut_ptr();
+    let i = v.len() - 1;
+
+    unsafe {
+        let tail = arr.add(i);
+        if !is_less(&*tail, &*tail.sub(1)) {
+            return;
+        }
+
+        // 取出最后一个元素，`v`中出现一个空洞，空洞随着元素的后移向前
+        // 移动。`is_less`发生`panic`时，守卫将`tmp`写回空洞
+        let tmp = mem::ManuallyDrop::new(ptr::read(tail));
+        let mut hole = InsertionHole {
+            src: &*tmp,
+            dest: tail.sub(1),
+        };
+        ptr::copy_nonoverlapping(hole.dest, tail, 1);
+
+        for j in (0..i - 1).rev() {
+            let cur = arr.add(j);
+            if !is_less(&*tmp, &*cur) {
+                break;
+            }
+            ptr::copy_nonoverlapping(cur, hole.dest, 1);
+            hole.dest = cur;
+        }
+        // `hole`在此处被`drop`，将`tmp`写入最终的位置
+    }
+}
+
+/// 合并时，`buf`中的`start..end`是尚未被写回的元素，`dest`是它们在`v`
+/// 中应当占据的位置。无论正常结束还是`panic`，都会将它们写回`dest`。
+struct MergeHole<T> {
+    start: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let len = self.end.offset_from(self.start) as usize;
+            ptr::copy_nonoverlapping(self.start, self.dest, len);
+        }
+    }
+}
+
+/// 合并有序的`v[..mid]`和`v[mid..]`。
+///
+/// ## Safety
+///
+/// `0 < mid < v.len()`，`buf`至少可以容纳`min(mid, v.len() - mid)`个元素，
+/// 且不与`v`重叠。
+unsafe fn merge<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T],
+    mid: usize,
+    buf: *mut T,
+    is_less: &mut F,
+) {
+    let len = v.len();
+    let v = v.as_mut_ptr();
+
+    unsafe {
+        let v_mid = v.add(mid);
+        let v_end = v.add(len);
+        if !is_less(&*v_mid, &*v_mid.sub(1)) {
+            return;
+        }
+
+        if mid <= len - mid {
+            // 左边较短：复制到`buf`，从前向后合并。`hole.dest`总是不超过`right`，
+            // 不会覆盖尚未读取的右边的元素
+            ptr::copy_nonoverlapping(v, buf, mid);
+            let mut hole = MergeHole {
+                start: buf,
+                end: buf.add(mid),
+                dest: v,
+            };
+            let mut right = v_mid;
+
+            while hole.start < hole.end && right < v_end {
+                // 相等时先取左边的元素，保证稳定
+                let src = if is_less(&*right, &*hole.start) {
+                    let src = right;
+                    right = right.add(1);
+                    src
+                } else {
+                    let src = hole.start;
+                    hole.start = hole.start.add(1);
+                    src
+                };
+                ptr::copy_nonoverlapping(src, hole.dest, 1);
+                hole.dest = hole.dest.add(1);
+            }
+            // 右边剩余的元素已经在正确的位置上，`hole`写回左边剩余的元素
+        } else {
+            // 右边较短：复制到`buf`，从后向前合并
+            ptr::copy_nonoverlapping(v_mid, buf, len - mid);
+            let mut hole = MergeHole {
+                start: buf,
+                end: buf.add(len - mid),
+                dest: v_mid,
+            };
+            let mut out = v_end;
+
+            while v < hole.dest && hole.start < hole.end {
+                // 相等时先取右边的元素（从后向前），保证稳定
+                let left = hole.dest.sub(1);
+                let right = hole.end.sub(1);
+                let src = if is_less(&*right, &*left) {
+                    hole.dest = left;
+                    left
+                } else {
+                    hole.end = right;
+                    right
+                };
+                out = out.sub(1);
+                ptr::copy_nonoverlapping(src, out, 1);
+            }
+            // 左边剩余的元素已经在正确的位置上，`hole`将右边剩余的元素写
+            // 回`hole.dest`
+        }
+    }
+}
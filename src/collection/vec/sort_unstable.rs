@@ -0,0 +1,247 @@
+use std::cmp::Ordering;
+use std::mem;
+
+use crate::collection::vec::MyVec;
+use crate::collection::vec::sort::{INSERTION_LEN, insertion_sort};
+
+impl<T> MyVec<T> {
+    /// 不稳定排序：相等的元素的先后顺序不确定，但不需要额外的内存。
+    ///
+    /// 我们没有调用切片的排序，而是自己实现了内省排序（introsort）：
+    ///
+    /// - 使用三数取中（首、中、尾）选择枢轴，然后进行Hoare分区。左右两边
+    ///   遇到与枢轴相等的元素时都会停下并交换，因此所有元素都相等时，分区
+    ///   仍然是均衡的；
+    /// - 先递归处理较短的一边，较长的一边在循环中继续处理，递归深度不超过
+    ///   `log2(n)`；
+    /// - 区间长度不超过`20`时改用插入排序；
+    /// - 分区的次数超过`2 * log2(n)`时（例如遇到了针对三数取中构造的输入），
+    ///   改用堆排序，因此最坏时间复杂度为`O(n log n)`。
+    ///
+    /// ## Panic safety
+    ///
+    /// 分区和堆排序只通过交换移动元素，插入排序与
+    /// [`MyVec::sort_stable_by`]相同，使用守卫填补空洞，因此`compare`发生
+    /// `panic`时，`MyVec`中的元素仍然恰好是原来的那些元素。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let mut vec = my_vec![5, 4, 1, 3, 2];
+    /// vec.sort_unstable_impl_by(|a, b| a.cmp(b));
+    /// assert_eq!(vec, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort_unstable_impl_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        sort_unstable(self, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// 见[`MyVec::sort_unstable_impl_by`]。
+    #[inline]
+    pub fn sort_unstable_impl(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_impl_by(T::cmp);
+    }
+
+    /// 重新排列元素，使得下标为`index`的元素恰好是排序之后该位置上的元素，
+    /// 它之前的元素都不大于它，之后的元素都不小于它，返回这三部分。
+    ///
+    /// 与[`MyVec::sort_unstable_impl_by`]使用相同的分区，但每次只继续处理
+    /// 包含`index`的一边（快速选择），平均时间复杂度为`O(n)`；分区次数过
+    /// 多时对剩余的区间进行堆排序，最坏时间复杂度为`O(n log n)`。
+    ///
+    /// ## Panics
+    ///
+    /// `index >= len`时`panic`。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let mut vec = my_vec![-5, 4, 2, -3, 1];
+    /// let (left, median, right) = vec.select_nth_unstable_by(2, |a, b| a.cmp(b));
+    /// assert_eq!(*median, 1);
+    /// assert!(left.iter().all(|x| *x <= 1));
+    /// assert!(right.iter().all(|x| *x >= 1));
+    /// ```
+    pub fn select_nth_unstable_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        index: usize,
+        mut compare: F,
+    ) -> (&mut [T], &mut T, &mut [T]) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "select_nth_unstable index {index} out of range for length {len}"
+        );
+        select_nth_unstable(self, index, &mut |a, b| compare(a, b) == Ordering::Less);
+
+        let (left, rest) = self.split_at_mut(index);
+        let (nth, right) = rest.split_first_mut().unwrap();
+        (left, nth, right)
+    }
+
+    /// 见[`MyVec::select_nth_unstable_by`]。
+    #[inline]
+    pub fn select_nth_unstable_impl(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        self.select_nth_unstable_by(index, T::cmp)
+    }
+}
+
+/// 对切片进行内省排序，见[`MyVec::sort_unstable_impl_by`]。
+pub(super) fn sort_unstable<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    // ZST的所有值都相同，排序没有可观察的效果
+    if mem::size_of::<T>() == 0 {
+        return;
+    }
+    let limit = 2 * v.len().max(1).ilog2();
+    quicksort(v, is_less, limit);
+}
+
+/// 对切片进行快速选择，见[`MyVec::select_nth_unstable_by`]。要求
+/// `index < v.len()`。
+pub(super) fn select_nth_unstable<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T],
+    index: usize,
+    is_less: &mut F,
+) {
+    if mem::size_of::<T>() == 0 {
+        return;
+    }
+    let limit = 2 * v.len().ilog2();
+    quickselect(v, index, is_less, limit);
+}
+
+fn quicksort<T, F: FnMut(&T, &T) -> bool>(mut v: &mut [T], is_less: &mut F, mut limit: u32) {
+    loop {
+        if v.len() <= INSERTION_LEN {
+            insertion_sort(v, is_less);
+            return;
+        }
+        if limit == 0 {
+            heapsort(v, is_less);
+            return;
+        }
+        limit -= 1;
+
+        let mid = partition(v, is_less);
+        let (left, right) = v.split_at_mut(mid);
+        let right = &mut right[1..];
+        if left.len() < right.len() {
+            quicksort(left, is_less, limit);
+            v = right;
+        } else {
+            quicksort(right, is_less, limit);
+            v = left;
+        }
+    }
+}
+
+fn quickselect<T, F: FnMut(&T, &T) -> bool>(
+    mut v: &mut [T],
+    mut index: usize,
+    is_less: &mut F,
+    mut limit: u32,
+) {
+    loop {
+        if v.len() <= INSERTION_LEN {
+            insertion_sort(v, is_less);
+            return;
+        }
+        if limit == 0 {
+            heapsort(v, is_less);
+            return;
+        }
+        limit -= 1;
+
+        let mid = partition(v, is_less);
+        let (left, right) = v.split_at_mut(mid);
+        match index.cmp(&mid) {
+            Ordering::Equal => return,
+            Ordering::Less => v = left,
+            Ordering::Greater => {
+                v = &mut right[1..];
+                index -= mid + 1;
+            }
+        }
+    }
+}
+
+/// 首、中、尾三个元素的中位数的下标。
+fn choose_pivot<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> usize {
+    let (mut a, mut b, mut c) = (0, v.len() / 2, v.len() - 1);
+    // 对三个下标按照对应的元素排序，之后`b`就是中位数
+    if is_less(&v[b], &v[a]) {
+        mem::swap(&mut a, &mut b);
+    }
+    if is_less(&v[c], &v[b]) {
+        mem::swap(&mut b, &mut c);
+    }
+    if is_less(&v[b], &v[a]) {
+        mem::swap(&mut a, &mut b);
+    }
+    b
+}
+
+/// 选择枢轴并进行Hoare分区，返回枢轴最终的下标`mid`，此时
+/// `v[..mid]`都不大于枢轴，`v[mid + 1..]`都不小于枢轴。
+fn partition<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> usize {
+    let pivot = choose_pivot(v, is_less);
+    v.swap(0, pivot);
+
+    let (pivot, rest) = v.split_first_mut().unwrap();
+    let pivot = &*pivot;
+    // 循环不变式：`rest[..l]`都不大于枢轴，`rest[r..]`都不小于枢轴
+    let (mut l, mut r) = (0, rest.len());
+    loop {
+        while l < r && is_less(&rest[l], pivot) {
+            l += 1;
+        }
+        while l < r && is_less(pivot, &rest[r - 1]) {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        // `rest[l]`和`rest[r - 1]`都可能等于枢轴，交换之后两边各前进一步
+        r -= 1;
+        rest.swap(l, r);
+        l += 1;
+    }
+
+    // `v[l]`即`rest[l - 1]`不大于枢轴，与枢轴交换
+    v.swap(0, l);
+    l
+}
+
+fn heapsort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let len = v.len();
+    for i in (0..len / 2).rev() {
+        sift_down(v, i, is_less);
+    }
+    for end in (1..len).rev() {
+        v.swap(0, end);
+        sift_down(&mut v[..end], 0, is_less);
+    }
+}
+
+/// 大根堆的下沉操作。
+fn sift_down<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], mut node: usize, is_less: &mut F) {
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= v.len() {
+            return;
+        }
+        if child + 1 < v.len() && is_less(&v[child], &v[child + 1]) {
+            child += 1;
+        }
+        if !is_less(&v[node], &v[child]) {
+            return;
+        }
+        v.swap(node, child);
+        node = child;
+    }
+}
@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+
+use crate::collection::vec::MyVec;
+use crate::collection::vec::sort_unstable::{select_nth_unstable, sort_unstable};
+
+impl<T> MyVec<T> {
+    /// 重新排列元素，使前`k`个元素恰好是按照`compare`最大的`k`个元素，且
+    /// 按照从大到小的顺序排列，之后的元素的顺序不确定。
+    ///
+    /// 先按照相反的顺序进行快速选择（见[`MyVec::select_nth_unstable_by`]），
+    /// 将最大的`k`个元素移到前面，平均需要`O(n)`；然后只对这`k`个元素排序，
+    /// 需要`O(k log k)`。因此`k`远小于`n`时，比对整个数组排序快得多。
+    ///
+    /// `k >= len`时对整个数组从大到小排序，`k == 0`时什么也不做。相等的元
+    /// 素之间的顺序不确定。
+    ///
+    /// ```rust
+    /// use rust_practice::my_vec;
+    ///
+    /// let mut vec = my_vec![3, 9, 1, 7, 5, 8];
+    /// vec.top_k_by(3, |a, b| a.cmp(b));
+    /// assert_eq!(vec[..3], [9, 8, 7]);
+    /// ```
+    pub fn top_k_by<F: FnMut(&T, &T) -> Ordering>(&mut self, k: usize, mut compare: F) {
+        let k = k.min(self.len());
+        if k == 0 {
+            return;
+        }
+
+        // 按照相反的顺序，“较小”的元素就是较大的元素
+        let is_greater = &mut |a: &T, b: &T| compare(a, b) == Ordering::Greater;
+        if k < self.len() {
+            select_nth_unstable(self, k - 1, is_greater);
+        }
+        sort_unstable(&mut self[..k], is_greater);
+    }
+
+    /// 见[`MyVec::top_k_by`]。
+    #[inline]
+    pub fn top_k(&mut self, k: usize)
+    where
+        T: Ord,
+    {
+        self.top_k_by(k, T::cmp);
+    }
+
+    /// 只保留最大的`k`个元素，按照从大到小的顺序返回，其余的元素恰好被
+    /// `drop`一次。见[`MyVec::top_k_by`]。
+    pub fn into_top_k_by<F: FnMut(&T, &T) -> Ordering>(mut self, k: usize, compare: F) -> Self {
+        self.top_k_by(k, compare);
+        self.truncate(k);
+        self
+    }
+
+    /// 见[`MyVec::into_top_k_by`]。
+    ///
+    /// ```rust
+    /// use rust_practice::collection::vec::MyVec;
+    ///
+    /// let vec: MyVec<u32> = (0..1000).map(|x| x * 7919 % 1000).collect();
+    /// assert_eq!(vec.into_top_k(3), [999, 998, 997]);
+    /// ```
+    #[inline]
+    pub fn into_top_k(self, k: usize) -> Self
+    where
+        T: Ord,
+    {
+        self.into_top_k_by(k, T::cmp)
+    }
+}
@@ -0,0 +1,471 @@
+mod into_iter;
+mod iter;
+
+pub use into_iter::IntoIter;
+pub use iter::{Iter, IterMut};
+
+use std::cmp;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::{Index, IndexMut};
+use std::ptr;
+use std::slice;
+
+use crate::collection::raw::RawBuf;
+
+/// 基于环形缓冲区的双端队列，与[`std::collections::VecDeque`]类似。
+///
+/// 与[`MyVec`]一样，我们使用[`RawBuf`]来管理内存，额外记录了队首
+/// 元素所在的物理位置`head`，逻辑上的第`i`个元素位于物理位置
+/// `(head + i) % cap`。因此元素可能会“绕回”到缓冲区的开头：
+///
+/// ```text
+///          tail_len    head
+///             ↓         ↓
+///   +-+-+-+-+-+-+-+-+-+
+///   |3|4|5|U|U|U|0|1|2|
+///   +-+-+-+-+-+-+-+-+-+
+///   U: 未初始化
+/// ```
+///
+/// 此时元素被分为两段：`head..cap`和`0..tail_len`，[`MyVecDeque::as_slices`]
+/// 依次返回这两段。
+///
+/// ## 关于ZST和空容量
+///
+/// 对于ZST，[`RawBuf`]的容量为[`isize::MAX`]，任何指针偏移都是
+/// 无操作，因此环形的下标计算仍然成立，只是所有元素都“位于”同一
+/// 个地址。对于容量为0的情况，`ptr`是悬垂的，我们保证在此时不会对
+/// 其进行读写，且`head`始终为0。
+///
+/// ```rust
+/// use rust_practice::collection::vec_deque::MyVecDeque;
+///
+/// let mut deque = MyVecDeque::new();
+/// deque.push_back(1);
+/// deque.push_back(2);
+/// deque.push_front(0);
+/// assert_eq!(deque.pop_front(), Some(0));
+/// assert_eq!(deque, [1, 2]);
+/// ```
+///
+/// [`MyVec`]: crate::collection::vec::MyVec
+pub struct MyVecDeque<T> {
+    buf: RawBuf<T>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> MyVecDeque<T> {
+    #[inline]
+    pub fn new() -> Self {
+        MyVecDeque {
+            buf: RawBuf::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        MyVecDeque {
+            buf: RawBuf::with_capacity(capacity),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.cap()
+    }
+
+    #[inline]
+    fn ptr(&self) -> *mut T {
+        self.buf.ptr().as_ptr()
+    }
+
+    /// 将逻辑下标转换为物理下标。
+    ///
+    /// 调用者需要保证`idx <= cap`，由于`head < cap <= isize::MAX`，因此
+    /// `head + idx`不会溢出。
+    #[inline]
+    fn to_physical_idx(&self, idx: usize) -> usize {
+        let idx = self.head + idx;
+        if idx >= self.capacity() {
+            idx - self.capacity()
+        } else {
+            idx
+        }
+    }
+
+    /// 计算`head - 1`在环形缓冲区中的位置，要求`cap > 0`。
+    #[inline]
+    fn wrap_sub_one(&self, idx: usize) -> usize {
+        if idx == 0 {
+            self.capacity() - 1
+        } else {
+            idx - 1
+        }
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    fn grow(&mut self) {
+        let old_cap = self.capacity();
+        self.buf.grow();
+        unsafe {
+            self.handle_capacity_increase(old_cap);
+        }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let old_cap = self.capacity();
+        self.buf.reserve_exact(self.len, additional);
+        if self.capacity() > old_cap {
+            unsafe {
+                self.handle_capacity_increase(old_cap);
+            }
+        }
+    }
+
+    /// 扩容之后，原来绕回到缓冲区开头的元素需要被重新排列。
+    ///
+    /// [`RawBuf`]在扩容时只会保证原有的`old_cap`个元素的位置不变，
+    /// 如果元素没有绕回，那么什么也不用做；否则，我们需要将其中一段
+    /// 移动到新的空间中，使得元素重新成为一个合法的环：
+    ///
+    /// ```text
+    /// 扩容前：
+    ///   [3 4 5 . 0 1 2]
+    /// 扩容后（移动较短的尾部）：
+    ///   [. . . . 0 1 2 3 4 5 . . . .]
+    /// 扩容后（移动较短的头部）：
+    ///   [3 4 5 . . . . . . . . 0 1 2]
+    /// ```
+    ///
+    /// 由于扩容至少会增加`old_cap`（或者足以容纳`len`）个位置，而
+    /// 尾部的长度一定小于`old_cap`，因此两种方式都有足够的空间。
+    ///
+    /// ## Safety
+    ///
+    /// - `old_cap`必须是扩容之前的容量，且`self.capacity() >= old_cap`
+    unsafe fn handle_capacity_increase(&mut self, old_cap: usize) {
+        let new_cap = self.capacity();
+        // ZST永远不会扩容，且对于容量为0的情况，`head`和`len`均为0
+        if self.head <= old_cap - self.len {
+            return;
+        }
+
+        let head_len = old_cap - self.head;
+        let tail_len = self.len - head_len;
+        unsafe {
+            if tail_len < head_len && tail_len <= new_cap - old_cap {
+                ptr::copy_nonoverlapping(self.ptr(), self.ptr().add(old_cap), tail_len);
+            } else {
+                let new_head = new_cap - head_len;
+                ptr::copy(
+                    self.ptr().add(self.head),
+                    self.ptr().add(new_head),
+                    head_len,
+                );
+                self.head = new_head;
+            }
+        }
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        if self.is_full() {
+            self.grow();
+        }
+
+        unsafe {
+            ptr::write(self.ptr().add(self.to_physical_idx(self.len)), value);
+        }
+        self.len += 1;
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        if self.is_full() {
+            self.grow();
+        }
+
+        self.head = self.wrap_sub_one(self.head);
+        unsafe {
+            ptr::write(self.ptr().add(self.head), value);
+        }
+        self.len += 1;
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        unsafe { Some(ptr::read(self.ptr().add(self.to_physical_idx(self.len)))) }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let old_head = self.head;
+        self.head = self.to_physical_idx(1);
+        self.len -= 1;
+        unsafe { Some(ptr::read(self.ptr().add(old_head))) }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            unsafe { Some(&*self.ptr().add(self.to_physical_idx(index))) }
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            unsafe { Some(&mut *self.ptr().add(self.to_physical_idx(index))) }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// 返回两段元素所在的物理范围，第二段可能为空。
+    #[inline]
+    fn slice_ranges(&self) -> (usize, usize, usize) {
+        let head_len = cmp::min(self.len, self.capacity() - self.head);
+        (self.head, head_len, self.len - head_len)
+    }
+
+    /// 按顺序返回两个切片，它们连接起来就是队列中的所有元素。
+    ///
+    /// 只有当元素绕回到缓冲区开头时，第二个切片才不为空。
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (head, head_len, tail_len) = self.slice_ranges();
+        unsafe {
+            (
+                slice::from_raw_parts(self.ptr().add(head), head_len),
+                slice::from_raw_parts(self.ptr(), tail_len),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (head, head_len, tail_len) = self.slice_ranges();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(self.ptr().add(head), head_len),
+                slice::from_raw_parts_mut(self.ptr(), tail_len),
+            )
+        }
+    }
+
+    /// 重新排列元素，使得所有元素在缓冲区中连续，并返回对应的切片。
+    ///
+    /// 我们首先将头部的一段移动到紧跟在尾部之后的位置，此时`0..len`
+    /// 中的元素都是初始化的，只是顺序为“尾部、头部”，然后对其使用
+    /// [`slice::rotate_left`]即可。
+    ///
+    /// ```text
+    ///   [3 4 5 . . 0 1 2]
+    /// → [3 4 5 0 1 2 . .]
+    /// → [0 1 2 3 4 5 . .]
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let (head, head_len, tail_len) = self.slice_ranges();
+        if tail_len != 0 {
+            unsafe {
+                // 元素绕回时，头部一定位于尾部之后，因此`head >= tail_len`
+                ptr::copy(self.ptr().add(head), self.ptr().add(tail_len), head_len);
+                slice::from_raw_parts_mut(self.ptr(), self.len).rotate_left(tail_len);
+            }
+            self.head = 0;
+        }
+        self.as_mut_slices().0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (a, b) = self.as_slices();
+        Iter::new(a.iter(), b.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (a, b) = self.as_mut_slices();
+        IterMut::new(a.iter_mut(), b.iter_mut())
+    }
+
+    /// 详细说明见[`MyVec::clear`]
+    ///
+    /// 与`MyVec`不同的是，元素被分为两段，因此我们使用一个守卫，即使
+    /// 第一段中的某个元素在`drop`时发生了`panic`，第二段也会被`drop`。
+    ///
+    /// [`MyVec::clear`]: crate::collection::vec::MyVec::clear
+    pub fn clear(&mut self) {
+        struct Dropper<T>(*mut [T]);
+
+        impl<T> Drop for Dropper<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(self.0);
+                }
+            }
+        }
+
+        let (front, back) = self.as_mut_slices();
+        let front: *mut [T] = front;
+        let back: *mut [T] = back;
+        self.head = 0;
+        self.len = 0;
+
+        unsafe {
+            let _back_dropper = Dropper(back);
+            ptr::drop_in_place(front);
+        }
+    }
+}
+
+impl<T> Default for MyVecDeque<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for MyVecDeque<T> {
+    /// 只需要`drop`其中的元素，内存由[`RawBuf`]释放。
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
+
+impl<T> Index<usize> for MyVecDeque<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("MyVecDeque index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for MyVecDeque<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("MyVecDeque index out of bounds")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyVecDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for MyVecDeque<T> {
+    fn clone(&self) -> Self {
+        let mut ret = Self::with_capacity(self.len);
+        ret.extend(self.iter().cloned());
+        ret
+    }
+}
+
+impl<T> Extend<T> for MyVecDeque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<'a, T: Clone + 'a> Extend<&'a T> for MyVecDeque<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<T> FromIterator<T> for MyVecDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<T: PartialEq> PartialEq for MyVecDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for MyVecDeque<T> {}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for MyVecDeque<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.len == N && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for MyVecDeque<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for MyVecDeque<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for MyVecDeque<T> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// 与切片的哈希保持一致，即使两个相等的队列在缓冲区中的布局不同，
+/// 也会得到相同的哈希值。
+impl<T: Hash> Hash for MyVecDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        self.iter().for_each(|elem| elem.hash(state));
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+use crate::collection::vec_deque::MyVecDeque;
+
+/// 按值消费[`MyVecDeque`]的迭代器。
+///
+/// 由于[`MyVecDeque::pop_front`]和[`MyVecDeque::pop_back`]已经处理了
+/// 环形的布局，我们直接持有整个队列，剩余的元素和内存会在其`drop`时
+/// 被释放。
+#[derive(Clone)]
+pub struct IntoIter<T> {
+    inner: MyVecDeque<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.inner).finish()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.inner.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for MyVecDeque<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { inner: self }
+    }
+}
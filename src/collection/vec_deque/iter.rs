@@ -0,0 +1,159 @@
+use std::iter::FusedIterator;
+use std::{fmt, mem, slice};
+
+use crate::collection::vec_deque::MyVecDeque;
+
+/// [`MyVecDeque::iter`]和[`InplaceDeque::iter`]返回的迭代器。
+///
+/// 队列中的元素可能被分为两段，我们分别持有两段的切片迭代器。向前
+/// 迭代时，第一段耗尽之后，将第二段交换到第一段的位置；向后迭代时
+/// 则相反。
+///
+/// [`InplaceDeque::iter`]: crate::collection::inplace_deque::InplaceDeque::iter
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    #[inline]
+    pub(crate) fn new(front: slice::Iter<'a, T>, back: slice::Iter<'a, T>) -> Self {
+        Self { front, back }
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&self.front.as_slice())
+            .field(&self.back.as_slice())
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self.front.next() {
+            Some(elem) => Some(elem),
+            None => {
+                mem::swap(&mut self.front, &mut self.back);
+                self.front.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        match self.back.next_back() {
+            Some(elem) => Some(elem),
+            None => {
+                mem::swap(&mut self.front, &mut self.back);
+                self.back.next_back()
+            }
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// [`MyVecDeque::iter_mut`]返回的迭代器，实现方式与[`Iter`]相同。
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    #[inline]
+    pub(crate) fn new(front: slice::IterMut<'a, T>, back: slice::IterMut<'a, T>) -> Self {
+        Self { front, back }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut")
+            .field(&self.front.as_slice())
+            .field(&self.back.as_slice())
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        match self.front.next() {
+            Some(elem) => Some(elem),
+            None => {
+                mem::swap(&mut self.front, &mut self.back);
+                self.front.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        match self.back.next_back() {
+            Some(elem) => Some(elem),
+            None => {
+                mem::swap(&mut self.front, &mut self.back);
+                self.back.next_back()
+            }
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a MyVecDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut MyVecDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,414 @@
+mod entry;
+mod iter;
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+use crate::collection::vec::{self, MyVec};
+
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use iter::{Iter, IterMut, Keys, Values, ValuesMut};
+
+/// 使用按键排序的[`MyVec<(K, V)>`]实现的映射。
+///
+/// 对于元素较少的映射，连续存储的有序数组往往比[`HashMap`]更快：查找
+/// 只需要对一块连续的内存进行二分查找，不需要计算哈希值，也没有额外
+/// 的指针跳转。代价是插入和删除需要移动后面的元素，时间复杂度为
+/// `O(n)`，因此`VecMap`只适合较小的映射。
+///
+/// ```rust
+/// use rust_practice::collection::vec_map::VecMap;
+///
+/// let mut map = VecMap::new();
+/// assert_eq!(map.insert("b", 2), None);
+/// assert_eq!(map.insert("a", 1), None);
+/// assert_eq!(map.insert("b", 3), Some(2));
+/// *map.entry("c").or_insert_with(|| 0) += 4;
+/// assert_eq!(map["b"], 3);
+/// assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "b", "c"]);
+/// ```
+///
+/// [`HashMap`]: std::collections::HashMap
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VecMap<K, V> {
+    vec: MyVec<(K, V)>,
+}
+
+impl<K, V> VecMap<K, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self { vec: MyVec::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: MyVec::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// 按键的顺序排列的键值对。
+    #[inline]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.vec
+    }
+
+    #[inline]
+    pub fn into_my_vec(self) -> MyVec<(K, V)> {
+        self.vec
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.vec.iter())
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(self.vec.iter_mut())
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.vec.iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.vec.iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self.vec.iter_mut())
+    }
+
+    /// 只保留满足`f`的键值对。
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.vec.retain_mut(|(k, v)| f(k, v));
+    }
+
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.vec.first().map(|(k, v)| (k, v))
+    }
+
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.vec.last().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K: Ord, V> VecMap<K, V> {
+    /// 二分查找`key`，找到时返回`Ok(index)`，否则返回`Err(index)`，其中
+    /// `index`为`key`应当插入的位置。
+    #[inline]
+    fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.vec.binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+
+    /// 将无序的键值对排序，重复的键只保留最后出现的值。
+    ///
+    /// 我们使用稳定的排序，因此相同的键按照出现的顺序排列。去重时，
+    /// [`MyVec::dedup_by`]保留的是第一个元素，所以我们把后出现的值交换
+    /// 到被保留的元素中。
+    pub fn from_my_vec(mut vec: MyVec<(K, V)>) -> Self {
+        vec.sort_by(|a, b| a.0.cmp(&b.0));
+        Self::dedup_keep_last(&mut vec);
+        Self { vec }
+    }
+
+    fn dedup_keep_last(vec: &mut MyVec<(K, V)>) {
+        vec.dedup_by(|next, kept| {
+            if next.0 == kept.0 {
+                mem::swap(&mut next.1, &mut kept.1);
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// 将按键排序（可能有重复的键）的`tail`合并进来，键相同时后出现的
+    /// 值替换原有的值。
+    ///
+    /// 合并的结果直接写入`self.vec`，因此即使比较时发生`panic`，已经合并
+    /// 的部分仍然是有序且没有重复的，只是尚未合并的键值对会被丢弃。
+    fn merge_sorted(&mut self, tail: MyVec<(K, V)>) {
+        let capacity = self.len() + tail.len();
+        let mut head = mem::replace(&mut self.vec, MyVec::with_capacity(capacity))
+            .into_iter()
+            .peekable();
+        let mut tail = tail.into_iter().peekable();
+        loop {
+            let (key, value) = match (head.peek(), tail.peek()) {
+                (Some(a), Some(b)) => match a.0.cmp(&b.0) {
+                    Ordering::Less => head.next().unwrap(),
+                    Ordering::Greater => tail.next().unwrap(),
+                    Ordering::Equal => {
+                        let (key, _) = head.next().unwrap();
+                        (key, tail.next().unwrap().1)
+                    }
+                },
+                _ => match head.next().or_else(|| tail.next()) {
+                    Some(pair) => pair,
+                    None => break,
+                },
+            };
+            match self.vec.last_mut() {
+                Some(last) if last.0 == key => last.1 = value,
+                _ => self.vec.push((key, value)),
+            }
+        }
+    }
+
+    /// 插入键值对，如果键已经存在，则替换其值并返回旧值（键不会被替换）。
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search(&key) {
+            Ok(index) => Some(mem::replace(&mut self.vec[index].1, value)),
+            Err(index) => {
+                self.vec.insert(index, (key, value));
+                None
+            }
+        }
+    }
+
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.search(&key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry::new(&mut self.vec, index)),
+            Err(index) => Entry::Vacant(VacantEntry::new(&mut self.vec, index, key)),
+        }
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        let (k, v) = &self.vec[index];
+        Some((k, v))
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(&mut self.vec[index].1)
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(self.vec.remove(index))
+    }
+}
+
+impl<K, V> Default for VecMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for VecMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// ## Panics
+///
+/// `key`不存在时`panic`。
+impl<K, V, Q> Index<&Q> for VecMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in VecMap")
+    }
+}
+
+/// ## Panics
+///
+/// `key`不存在时`panic`。
+impl<K, V, Q> IndexMut<&Q> for VecMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    #[inline]
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key).expect("key not found in VecMap")
+    }
+}
+
+impl<K: Ord, V> From<MyVec<(K, V)>> for VecMap<K, V> {
+    #[inline]
+    fn from(value: MyVec<(K, V)>) -> Self {
+        Self::from_my_vec(value)
+    }
+}
+
+impl<K: Ord, V, const N: usize> From<[(K, V); N]> for VecMap<K, V> {
+    #[inline]
+    fn from(value: [(K, V); N]) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+/// 先收集所有键值对，再排序一次，而不是逐个插入。
+impl<K: Ord, V> FromIterator<(K, V)> for VecMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::from_my_vec(iter.into_iter().collect())
+    }
+}
+
+/// 与[`VecMap::insert`]相同，键已经存在时替换其值。
+///
+/// 元素较少时逐个插入；否则先把新的键值对追加到末尾，只对追加的部分排序，
+/// 再与原有的部分合并。迭代器或者排序发生`panic`时，追加的键值对会被丢弃，
+/// 原有的键值对保持不变。
+impl<K: Ord, V> Extend<(K, V)> for VecMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        const INSERT_LIMIT: usize = 8;
+
+        struct Guard<'a, K, V> {
+            vec: &'a mut MyVec<(K, V)>,
+            len: usize,
+        }
+
+        impl<K, V> Drop for Guard<'_, K, V> {
+            fn drop(&mut self) {
+                self.vec.truncate(self.len);
+            }
+        }
+
+        let iter = iter.into_iter();
+        let (_, upper) = iter.size_hint();
+        if upper.is_some_and(|upper| upper <= INSERT_LIMIT) {
+            for (key, value) in iter {
+                self.insert(key, value);
+            }
+            return;
+        }
+
+        let len = self.vec.len();
+        let guard = Guard {
+            vec: &mut self.vec,
+            len,
+        };
+        guard.vec.extend(iter);
+        let (head, tail) = guard.vec.split_at_mut(len);
+        tail.sort_by(|a, b| a.0.cmp(&b.0));
+        let appendable = match (head.last(), tail.first()) {
+            (Some(last), Some(first)) => last.0 <= first.0,
+            _ => true,
+        };
+        if appendable {
+            mem::forget(guard);
+            // 原有的键没有重复，且都不大于追加的键，因此去重只会合并追加的
+            // 部分，以及可能与之相等的最后一个原有的键
+            Self::dedup_keep_last(&mut self.vec);
+        } else {
+            let tail = guard.vec.drain(len..).collect();
+            drop(guard);
+            self.merge_sorted(tail);
+        }
+    }
+}
+
+impl<K, V> IntoIterator for VecMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = vec::IntoIter<(K, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a VecMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut VecMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,135 @@
+use crate::collection::vec::MyVec;
+
+/// [`VecMap::entry`]返回的视图，表示某个键对应的位置，该位置可能已经
+/// 有值，也可能是空的。
+///
+/// 查找只在创建`Entry`时进行一次，之后的插入直接使用记录下来的下标。
+///
+/// [`VecMap::entry`]: super::VecMap::entry
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    vec: &'a mut MyVec<(K, V)>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    vec: &'a mut MyVec<(K, V)>,
+    index: usize,
+    key: K,
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    #[inline]
+    pub(super) fn new(vec: &'a mut MyVec<(K, V)>, index: usize) -> Self {
+        Self { vec, index }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.vec[self.index].0
+    }
+
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.vec[self.index].1
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.vec[self.index].1
+    }
+
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.vec[self.index].1
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        self.vec.remove(self.index)
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    #[inline]
+    pub(super) fn new(vec: &'a mut MyVec<(K, V)>, index: usize, key: K) -> Self {
+        Self { vec, index, key }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.vec.insert(self.index, (self.key, value));
+        &mut self.vec[self.index].1
+    }
+}
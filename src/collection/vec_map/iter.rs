@@ -0,0 +1,98 @@
+use std::iter::FusedIterator;
+use std::slice;
+
+/// 为包装了切片迭代器的类型实现迭代器相关的trait，`$map`将`(K, V)`
+/// 的引用转换为对应的元素。
+macro_rules! impl_iter {
+    ($name:ident, $inner:ident, $item:ty, $map:expr) => {
+        impl<'a, K, V> $name<'a, K, V> {
+            #[inline]
+            pub(crate) fn new(inner: slice::$inner<'a, (K, V)>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<'a, K, V> Iterator for $name<'a, K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next().map($map)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<'a, K, V> DoubleEndedIterator for $name<'a, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().map($map)
+            }
+        }
+
+        impl<K, V> ExactSizeIterator for $name<'_, K, V> {}
+
+        impl<K, V> FusedIterator for $name<'_, K, V> {}
+    };
+}
+
+/// [`VecMap::iter`]和[`TinyMap::iter`]返回的迭代器。
+///
+/// `VecMap`按键的顺序产生键值对，而`TinyMap`中键值对的顺序是不确定的。
+///
+/// [`VecMap::iter`]: super::VecMap::iter
+/// [`TinyMap::iter`]: crate::collection::tiny_map::TinyMap::iter
+#[derive(Clone, Debug)]
+pub struct Iter<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl_iter!(Iter, Iter, (&'a K, &'a V), |(k, v)| (k, v));
+
+/// [`VecMap::iter_mut`]和[`TinyMap::iter_mut`]返回的迭代器，只能修改值而
+/// 不能修改键。
+///
+/// [`VecMap::iter_mut`]: super::VecMap::iter_mut
+/// [`TinyMap::iter_mut`]: crate::collection::tiny_map::TinyMap::iter_mut
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl_iter!(IterMut, IterMut, (&'a K, &'a mut V), |(k, v)| (&*k, v));
+
+/// [`VecMap::keys`]和[`TinyMap::keys`]返回的迭代器。
+///
+/// [`VecMap::keys`]: super::VecMap::keys
+/// [`TinyMap::keys`]: crate::collection::tiny_map::TinyMap::keys
+#[derive(Clone, Debug)]
+pub struct Keys<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl_iter!(Keys, Iter, &'a K, |(k, _)| k);
+
+/// [`VecMap::values`]和[`TinyMap::values`]返回的迭代器。
+///
+/// [`VecMap::values`]: super::VecMap::values
+/// [`TinyMap::values`]: crate::collection::tiny_map::TinyMap::values
+#[derive(Clone, Debug)]
+pub struct Values<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl_iter!(Values, Iter, &'a V, |(_, v)| v);
+
+/// [`VecMap::values_mut`]和[`TinyMap::values_mut`]返回的迭代器。
+///
+/// [`VecMap::values_mut`]: super::VecMap::values_mut
+/// [`TinyMap::values_mut`]: crate::collection::tiny_map::TinyMap::values_mut
+#[derive(Debug)]
+pub struct ValuesMut<'a, K, V> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl_iter!(ValuesMut, IterMut, &'a mut V, |(_, v)| v);
@@ -1 +1,4 @@
 pub mod collection;
+
+#[cfg(kani)]
+mod verification;
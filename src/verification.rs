@@ -0,0 +1,172 @@
+//! 使用[Kani](https://github.com/model-checking/kani)对核心的`unsafe`代码
+//! 进行有界模型检验。
+//!
+//! 测试和Miri只能检查实际执行到的路径，而Kani会对所有满足`kani::assume`
+//! 的输入进行符号执行，并自动检查每一次指针访问是否越界、算术运算是否溢
+//! 出。因此下面的harness大多只是调用被检查的函数，再断言长度等不变式，
+//! 越界的读写会被Kani直接报告。
+//!
+//! 为了让状态空间足够小，所有的长度都不超过[`MAX_LEN`]，循环的展开次数由
+//! `#[kani::unwind]`限定。
+//!
+//! | harness | 检查的内容 |
+//! | --- | --- |
+//! | `my_vec_insert_in_bounds` | [`MyVec::insert`]只访问`0..cap` |
+//! | `my_vec_remove_in_bounds` | [`MyVec::remove`]只访问`0..len` |
+//! | `slice_range_in_bounds` | [`slice::range`]返回的范围满足`start <= end <= len` |
+//! | `slice_range_rejects_invalid` | 不合法的范围一定会`panic` |
+//! | `inplace_vec_push_pop_in_bounds` | [`InplaceVec::push`]/[`InplaceVec::pop`]不访问`N`及之后 |
+//! | `inplace_vec_insert_remove_in_bounds` | [`InplaceVec::insert`]/[`InplaceVec::remove`]不访问`N`及之后 |
+//!
+//! `RawValIter`是`vec`模块私有的，它的harness
+//! `raw_val_iter_len_never_underflows`位于`collection/vec/raw_val_iter.rs`中。
+//!
+//! 运行方式：
+//!
+//! ```text
+//! cargo kani
+//! ```
+//!
+//! 该模块只在`cfg(kani)`下编译，不会影响正常的构建。
+
+use std::ops::Bound;
+
+use crate::collection::inplace_vec::InplaceVec;
+use crate::collection::slice;
+use crate::collection::vec::MyVec;
+
+/// 所有符号长度的上界。
+const MAX_LEN: usize = 8;
+
+/// 长度为`0..=MAX_LEN`中任意值、元素任意的`MyVec`。
+fn any_my_vec() -> MyVec<u32> {
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN);
+    let mut vec = MyVec::new();
+    for _ in 0..len {
+        vec.push(kani::any());
+    }
+    vec
+}
+
+/// 长度为`0..=MAX_LEN`中任意值、元素任意的`InplaceVec`。
+fn any_inplace_vec() -> InplaceVec<MAX_LEN, u32> {
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN);
+    let mut vec = InplaceVec::new();
+    for _ in 0..len {
+        vec.push(kani::any());
+    }
+    vec
+}
+
+fn any_bound() -> Bound<usize> {
+    match kani::any::<u8>() % 3 {
+        0 => Bound::Unbounded,
+        1 => Bound::Included(kani::any()),
+        _ => Bound::Excluded(kani::any()),
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn my_vec_insert_in_bounds() {
+    let mut vec = any_my_vec();
+    let len = vec.len();
+    let index: usize = kani::any();
+    kani::assume(index <= len);
+    let value: u32 = kani::any();
+
+    vec.insert(index, value);
+    assert_eq!(vec.len(), len + 1);
+    assert!(vec.len() <= vec.capacity());
+    assert_eq!(vec[index], value);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn my_vec_remove_in_bounds() {
+    let mut vec = any_my_vec();
+    let len = vec.len();
+    let index: usize = kani::any();
+    kani::assume(index < len);
+    let expected = vec[index];
+
+    assert_eq!(vec.remove(index), expected);
+    assert_eq!(vec.len(), len - 1);
+    assert!(vec.len() <= vec.capacity());
+}
+
+/// 在不会`panic`的前提下，结果一定是`bounds`的子范围。
+#[kani::proof]
+fn slice_range_in_bounds() {
+    let (lower, upper) = (any_bound(), any_bound());
+    let len: usize = kani::any();
+
+    let left = match lower {
+        Bound::Unbounded => Some(0),
+        Bound::Included(l) => Some(l),
+        Bound::Excluded(l) => l.checked_add(1),
+    };
+    let right = match upper {
+        Bound::Unbounded => Some(len),
+        Bound::Included(u) => u.checked_add(1),
+        Bound::Excluded(u) => Some(u),
+    };
+    kani::assume(matches!((left, right), (Some(l), Some(r)) if l <= r && r <= len));
+
+    let range = slice::range((lower, upper), ..len);
+    assert!(range.start <= range.end);
+    assert!(range.end <= len);
+    assert_eq!(Some(range.start), left);
+    assert_eq!(Some(range.end), right);
+}
+
+#[kani::proof]
+#[kani::should_panic]
+fn slice_range_rejects_invalid() {
+    let (start, end, len): (usize, usize, usize) = (kani::any(), kani::any(), kani::any());
+    kani::assume(start > end || end > len);
+    slice::range(start..end, ..len);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn inplace_vec_push_pop_in_bounds() {
+    let mut vec = any_inplace_vec();
+    let len = vec.len();
+
+    if len < MAX_LEN {
+        let value: u32 = kani::any();
+        vec.push(value);
+        assert_eq!(vec.len(), len + 1);
+        assert_eq!(vec.pop(), Some(value));
+    }
+    assert_eq!(vec.len(), len);
+
+    let last = vec.last().copied();
+    assert_eq!(vec.pop(), last);
+    assert!(vec.len() <= MAX_LEN);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn inplace_vec_insert_remove_in_bounds() {
+    let mut vec = any_inplace_vec();
+    let len = vec.len();
+    let index: usize = kani::any();
+
+    if len < MAX_LEN {
+        kani::assume(index <= len);
+        let value: u32 = kani::any();
+        vec.insert(index, value);
+        assert_eq!(vec.len(), len + 1);
+        assert_eq!(vec.remove(index), value);
+    } else {
+        kani::assume(index < len);
+        let expected = vec[index];
+        assert_eq!(vec.remove(index), expected);
+        assert_eq!(vec.len(), len - 1);
+    }
+    assert!(vec.len() <= MAX_LEN);
+}
@@ -0,0 +1,120 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use rust_practice::collection::arc::MyArc;
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+struct DropCounter;
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// `MyArc<T>`在`T: Send + Sync`时可以跨线程使用，反例见`MyArc`的文档。
+#[allow(dead_code)]
+fn assert_send_sync() {
+    fn check<T: Send + Sync>() {}
+    check::<MyArc<i32>>();
+    check::<MyArc<Mutex<String>>>();
+}
+
+#[test]
+fn arc_basic() {
+    let mut a = MyArc::new(String::from("arc"));
+    MyArc::get_mut(&mut a).unwrap().push('!');
+
+    let b = a.clone();
+    assert!(MyArc::ptr_eq(&a, &b));
+    assert_eq!(MyArc::strong_count(&a), 2);
+    assert!(MyArc::get_mut(&mut a).is_none());
+    assert_eq!(format!("{b} {b:?}"), "arc! \"arc!\"");
+
+    let mut c = b.clone();
+    MyArc::make_mut(&mut c).push('?');
+    assert!(!MyArc::ptr_eq(&b, &c));
+    assert_eq!(*c, "arc!?");
+    let ptr: *const String = &*c;
+    MyArc::make_mut(&mut c).push('?');
+    assert!(std::ptr::eq(ptr, &*c));
+
+    let a = MyArc::try_unwrap(a).unwrap_err();
+    drop(b);
+    assert_eq!(MyArc::try_unwrap(a).unwrap(), "arc!");
+}
+
+#[test]
+fn arc_threads_stress() {
+    const THREADS: usize = 8;
+    const ROUNDS: usize = 10_000;
+
+    let drops_before = DROPS.load(Ordering::Relaxed);
+    let shared = MyArc::new((Mutex::new(0usize), DropCounter));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let local = shared.clone();
+            thread::spawn(move || {
+                let mut kept = Vec::new();
+                for i in 0..ROUNDS {
+                    let c = local.clone();
+                    if i % 3 == 0 {
+                        kept.push(c);
+                    }
+                }
+                *local.0.lock().unwrap() += 1;
+                // `kept`和`local`在其他线程仍在运行时被`drop`
+            })
+        })
+        .collect();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+    assert_eq!(MyArc::strong_count(&shared), 1);
+    assert_eq!(*shared.0.lock().unwrap(), THREADS);
+    assert_eq!(DROPS.load(Ordering::Relaxed), drops_before);
+
+    drop(shared);
+    assert_eq!(DROPS.load(Ordering::Relaxed), drops_before + 1);
+}
+
+#[test]
+fn arc_last_drop_on_other_thread() {
+    let a = MyArc::new(vec![1, 2, 3]);
+    let clones: Vec<_> = (0..4).map(|_| a.clone()).collect();
+    drop(a);
+
+    // 每个线程都可能是最后一个引用
+    let sums: Vec<i32> = clones
+        .into_iter()
+        .map(|c| thread::spawn(move || c.iter().sum::<i32>()))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .collect();
+    assert_eq!(sums, [6; 4]);
+}
+
+#[test]
+fn arc_try_unwrap_race() {
+    // 多个线程同时尝试取出值，恰好有一个成功
+    for _ in 0..100 {
+        let a = MyArc::new(String::from("once"));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let c = a.clone();
+                thread::spawn(move || MyArc::try_unwrap(c).ok())
+            })
+            .collect();
+        drop(a);
+
+        let won: Vec<_> = handles
+            .into_iter()
+            .filter_map(|h| h.join().unwrap())
+            .collect();
+        assert!(won.len() <= 1);
+    }
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use rust_practice::collection::bit_vec::BitVec;
+use rust_practice::collection::rng::RngCore;
+
+const BITS: usize = usize::BITS as usize;
+
+/// 检查不变量，并与模型比较。
+fn check(bits: &BitVec, model: &[bool]) {
+    let words = bits.as_words();
+    assert_eq!(words.len(), model.len().div_ceil(BITS));
+    if !model.len().is_multiple_of(BITS) {
+        let unused = words.last().unwrap() >> (model.len() % BITS);
+        assert_eq!(unused, 0, "unused high bits must stay zeroed");
+    }
+    assert_eq!(bits.len(), model.len());
+    assert_eq!(bits.count_ones(), model.iter().filter(|&&b| b).count());
+    assert!(bits.iter().eq(model.iter().copied()));
+    // 由不变量保证，逐字比较等价于逐比特比较
+    assert_eq!(*bits, model.iter().copied().collect::<BitVec>());
+}
+
+#[test]
+fn bit_vec_model() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..20 {
+        let mut bits = BitVec::new();
+        let mut model = Vec::new();
+        for _ in 0..400 {
+            let value = rng.next_u64().is_multiple_of(2);
+            match rng.next_u64() % 9 {
+                0 | 1 => {
+                    bits.push(value);
+                    model.push(value);
+                }
+                2 => assert_eq!(bits.pop(), model.pop()),
+                3 => {
+                    if !model.is_empty() {
+                        let index = rng.next_u64() as usize % model.len();
+                        bits.set(index, value);
+                        model[index] = value;
+                    }
+                }
+                4 => {
+                    let n = rng.next_u64() as usize % (2 * BITS);
+                    bits.grow(n, value);
+                    model.resize(model.len() + n, value);
+                }
+                5 => {
+                    let len = rng.next_u64() as usize % (model.len() + 4);
+                    bits.truncate(len);
+                    model.truncate(len);
+                }
+                6 => {
+                    bits.negate();
+                    model.iter_mut().for_each(|b| *b = !*b);
+                }
+                7 => {
+                    let other: Vec<bool> = model
+                        .iter()
+                        .map(|_| rng.next_u64().is_multiple_of(3))
+                        .collect();
+                    let other_bits: BitVec = other.iter().copied().collect();
+                    match rng.next_u64() % 3 {
+                        0 => {
+                            bits &= &other_bits;
+                            model.iter_mut().zip(&other).for_each(|(a, b)| *a &= b);
+                        }
+                        1 => {
+                            bits |= &other_bits;
+                            model.iter_mut().zip(&other).for_each(|(a, b)| *a |= b);
+                        }
+                        _ => {
+                            bits ^= &other_bits;
+                            model.iter_mut().zip(&other).for_each(|(a, b)| *a ^= b);
+                        }
+                    }
+                }
+                _ => {
+                    let index = rng.next_u64() as usize % (model.len() + 2);
+                    assert_eq!(bits.get(index), model.get(index).copied());
+                }
+            }
+            check(&bits, &model);
+        }
+        assert!(bits.iter().rev().eq(model.iter().rev().copied()));
+    }
+}
+
+#[test]
+fn bit_vec_word_boundaries() {
+    for len in [0, 1, BITS - 1, BITS, BITS + 1, 3 * BITS] {
+        let mut bits = BitVec::from_elem(len, true);
+        check(&bits, &vec![true; len]);
+        assert!(bits.all());
+        bits.negate();
+        assert!(!bits.any());
+        assert_eq!(bits.count_zeros(), len);
+        bits.push(true);
+        assert_eq!(bits.pop(), Some(true));
+        check(&bits, &vec![false; len]);
+    }
+}
+
+#[test]
+fn bit_vec_debug_and_index() {
+    let bits: BitVec = "1101".chars().map(|c| c == '1').collect();
+    assert_eq!(format!("{bits:?}"), "1101");
+    assert_eq!(format!("{:?}", BitVec::new()), "");
+    assert!(bits[0] && !bits[2]);
+    assert_eq!(bits.iter().len(), 4);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn bit_vec_mismatched_len() {
+    let mut a = BitVec::from_elem(3, true);
+    a |= &BitVec::from_elem(4, true);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn bit_vec_set_out_of_bounds() {
+    let mut a = BitVec::from_elem(BITS, true);
+    a.set(BITS, false);
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use common::DropLog;
+use rust_practice::collection::boxed_slice::MyBoxedSlice;
+use rust_practice::collection::vec::MyVec;
+
+/// 统计当前线程中`realloc`的调用次数。测试是并行执行的，因此计数器是线
+/// 程局部的。
+struct CountingAlloc;
+
+thread_local! {
+    static REALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = REALLOCS.try_with(|c| c.set(c.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn reallocs() -> usize {
+    REALLOCS.with(Cell::get)
+}
+
+#[test]
+fn boxed_slice_round_trip_exact() {
+    let mut vec = MyVec::with_capacity(4);
+    vec.extend([1, 2, 3, 4]);
+    let ptr = vec.as_ptr();
+
+    // 容量等于长度，不会重新分配内存
+    let before = reallocs();
+    let slice = MyBoxedSlice::from_my_vec(vec);
+    assert_eq!(slice.as_ptr(), ptr);
+    let vec = slice.into_my_vec();
+    assert_eq!(reallocs(), before);
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec.capacity(), 4);
+    assert_eq!(vec, [1, 2, 3, 4]);
+}
+
+#[test]
+fn boxed_slice_round_trip_shrink() {
+    let mut vec = MyVec::with_capacity(16);
+    vec.extend((0..5).map(|i| i.to_string()));
+
+    // 容量大于长度，恰好重新分配一次
+    let before = reallocs();
+    let slice: MyBoxedSlice<_> = vec.into();
+    assert_eq!(reallocs(), before + 1);
+    assert_eq!(slice.len(), 5);
+
+    let vec: MyVec<_> = slice.into();
+    assert_eq!(reallocs(), before + 1);
+    assert_eq!(vec.capacity(), 5);
+    assert_eq!(vec, ["0", "1", "2", "3", "4"].map(String::from));
+
+    // 空数组和ZST不申请内存
+    let empty = MyBoxedSlice::from_my_vec(MyVec::<u64>::with_capacity(8));
+    assert!(empty.is_empty());
+    assert_eq!(empty.into_my_vec().capacity(), 0);
+    let zst = MyBoxedSlice::filled(3, ());
+    assert_eq!(zst.len(), 3);
+    assert_eq!(zst.into_my_vec().len(), 3);
+}
+
+#[test]
+fn boxed_slice_constructors() {
+    let squares = MyBoxedSlice::from_fn(5, |i| i * i);
+    assert_eq!(squares, [0, 1, 4, 9, 16]);
+    assert_eq!(format!("{squares:?}"), "[0, 1, 4, 9, 16]");
+
+    let mut words = MyBoxedSlice::filled(3, String::from("ab"));
+    words[1].push('c');
+    assert_eq!(words, ["ab", "abc", "ab"].map(String::from));
+
+    let mut copy = MyBoxedSlice::filled(3, String::new());
+    copy.clone_from(&words);
+    assert_eq!(copy, words);
+    let collected: MyBoxedSlice<_> = words.iter().rev().cloned().collect();
+    assert_eq!(&*collected, &["ab", "abc", "ab"].map(String::from)[..]);
+
+    for s in &mut copy {
+        s.clear();
+    }
+    assert!(copy.iter().all(String::is_empty));
+    assert_eq!(MyBoxedSlice::from(&[1, 2][..]).into_iter().sum::<i32>(), 3);
+    assert!(MyBoxedSlice::<i32>::default().is_empty());
+}
+
+#[test]
+fn boxed_slice_drops() {
+    let log = DropLog::new();
+    let slice = MyBoxedSlice::from_fn(4, |_| log.track(0));
+    drop(slice);
+    assert_eq!(log.dropped(), 4);
+
+    let slice = MyBoxedSlice::from_fn(4, |_| log.track(0));
+    let mut iter = slice.into_iter();
+    drop(iter.next());
+    assert_eq!(log.dropped(), 5);
+    drop(iter);
+    assert_eq!(log.dropped(), 8);
+
+    // `from_fn`中途`panic`时，已经构造的元素被`drop`
+    let result = std::panic::catch_unwind(|| {
+        let log = DropLog::new();
+        let inner = log.clone();
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            MyBoxedSlice::from_fn(4, |i| {
+                if i == 2 {
+                    panic!("boom");
+                }
+                inner.track(0)
+            })
+        }));
+        log.dropped()
+    });
+    assert_eq!(result.unwrap(), 2);
+}
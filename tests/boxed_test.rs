@@ -0,0 +1,96 @@
+mod common;
+
+use std::cell::Cell;
+use std::collections::HashSet;
+
+use common::DropLog;
+use rust_practice::collection::boxed::MyBox;
+
+/// 如果`MyBox<T>`在`T`上不是协变的，则无法通过编译。
+#[allow(dead_code)]
+fn assert_covariant<'a>(b: MyBox<&'static str>) -> MyBox<&'a str> {
+    b
+}
+
+#[test]
+fn box_basic() {
+    let mut b = MyBox::new(String::from("hello"));
+    b.push_str(", world");
+    assert_eq!(b.len(), 12);
+    assert_eq!(format!("{b} {b:?}"), "hello, world \"hello, world\"");
+
+    let c = b.clone();
+    assert_ne!(MyBox::as_ptr(&b), MyBox::as_ptr(&c));
+    assert_eq!(b, c);
+    assert!(MyBox::new(1) < MyBox::new(2));
+    let set: HashSet<_> = [MyBox::new(1), MyBox::new(1)].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert_eq!(MyBox::into_inner(c), "hello, world");
+
+    assert_eq!(size_of::<Option<MyBox<u64>>>(), size_of::<usize>());
+}
+
+#[test]
+fn box_raw_round_trip() {
+    let log = DropLog::new();
+    let b = MyBox::new(log.track(0));
+    let raw = MyBox::into_raw(b);
+    assert_eq!(log.dropped(), 0);
+    let b = unsafe { MyBox::from_raw(raw) };
+    drop(b);
+    assert_eq!(log.dropped(), 1);
+
+    let inner = MyBox::into_inner(MyBox::new(log.track(0)));
+    assert_eq!(log.dropped(), 1);
+    drop(inner);
+    assert_eq!(log.dropped(), 2);
+
+    let leaked: &'static mut i32 = MyBox::leak(MyBox::new(41));
+    *leaked += 1;
+    assert_eq!(*leaked, 42);
+    // 重新接管被泄露的内存，避免测试中的内存泄露
+    drop(unsafe { MyBox::from_raw(leaked) });
+}
+
+#[test]
+fn box_zst() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Zst;
+
+    let b = MyBox::new(Zst);
+    assert_eq!(MyBox::as_ptr(&b) as usize, align_of::<Zst>());
+    let c = b.clone();
+    assert_eq!(b, c);
+    let raw = MyBox::into_raw(c);
+    let c = unsafe { MyBox::from_raw(raw) };
+    assert_eq!(MyBox::into_inner(c), Zst);
+
+    // ZST的`drop`同样需要被调用
+    thread_local! {
+        static ZST_DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+    struct ZstDrop;
+    impl Drop for ZstDrop {
+        fn drop(&mut self) {
+            ZST_DROPS.with(|d| d.set(d.get() + 1));
+        }
+    }
+    drop(MyBox::new(ZstDrop));
+    let inner = MyBox::into_inner(MyBox::new(ZstDrop));
+    assert_eq!(ZST_DROPS.with(Cell::get), 1);
+    drop(inner);
+    assert_eq!(ZST_DROPS.with(Cell::get), 2);
+
+    let mut b = MyBox::new(());
+    *b = ();
+    assert_eq!(*b, ());
+}
+
+#[test]
+fn box_clone_from() {
+    let mut a = MyBox::new(vec![0; 16]);
+    let ptr = MyBox::as_ptr(&a);
+    a.clone_from(&MyBox::new(vec![1, 2]));
+    assert_eq!(MyBox::as_ptr(&a), ptr);
+    assert_eq!(*a, [1, 2]);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{Buf, BufMut, BytesMut};
+use rust_practice::collection::vec::{MyVec, MyVecReader};
+
+/// 一个简单的帧格式：类型、长度前缀、负载以及校验和。
+fn write_frame<B: BufMut>(buf: &mut B, kind: u8, payload: &[u8]) {
+    buf.put_u8(kind);
+    buf.put_u32(payload.len() as u32);
+    buf.put_slice(payload);
+    buf.put_u64_le(payload.iter().map(|&b| b as u64).sum());
+}
+
+fn read_frame<B: Buf>(buf: &mut B) -> (u8, Vec<u8>, u64) {
+    let kind = buf.get_u8();
+    let len = buf.get_u32() as usize;
+    let payload = buf.copy_to_bytes(len).to_vec();
+    (kind, payload, buf.get_u64_le())
+}
+
+#[test]
+fn put_matches_bytes_mut() {
+    let mut vec = MyVec::new();
+    let mut expected = BytesMut::new();
+    for i in 0..100u32 {
+        vec.put_u32(i * 7919);
+        expected.put_u32(i * 7919);
+        vec.put_slice(&i.to_le_bytes()[..(i % 4) as usize]);
+        expected.put_slice(&i.to_le_bytes()[..(i % 4) as usize]);
+    }
+    vec.put_bytes(0xab, 5);
+    expected.put_bytes(0xab, 5);
+
+    assert_eq!(vec.as_slice(), &expected[..]);
+    assert_eq!(vec.remaining_mut(), isize::MAX as usize - vec.len());
+}
+
+#[test]
+fn codec_round_trip() {
+    let frames: [(u8, &[u8]); 3] = [(1, b"hello"), (2, b""), (3, &[0xff; 300])];
+
+    let mut vec = MyVec::new();
+    let mut expected = BytesMut::new();
+    for (kind, payload) in frames {
+        write_frame(&mut vec, kind, payload);
+        write_frame(&mut expected, kind, payload);
+    }
+    assert_eq!(vec.as_slice(), &expected[..]);
+
+    let mut reader = MyVecReader::new(vec);
+    let mut bytes = expected.freeze();
+    for (kind, payload) in frames {
+        let frame = read_frame(&mut reader);
+        assert_eq!(frame, read_frame(&mut bytes));
+        assert_eq!((frame.0, frame.1.as_slice()), (kind, payload));
+    }
+    assert!(!reader.has_remaining());
+    assert_eq!(reader.position(), reader.get_ref().len());
+
+    // 读取过的字节仍然保留，可以回到开头重新读取
+    reader.set_position(0);
+    assert_eq!(read_frame(&mut reader).1, b"hello");
+}
+
+#[test]
+fn chunk_mut_grows_and_advance_mut_bumps_len() {
+    let mut vec: MyVec<u8> = MyVec::new();
+    let chunk = vec.chunk_mut();
+    assert!(chunk.len() >= 64);
+    chunk[..3].copy_from_slice(b"abc");
+    unsafe { vec.advance_mut(3) };
+    assert_eq!(vec, *b"abc");
+
+    // 写满之后再次调用`chunk_mut`会扩容
+    let spare = vec.capacity() - vec.len();
+    vec.put_bytes(b'x', spare);
+    assert!(vec.chunk_mut().len() > 0);
+    assert_eq!(vec.len(), 3 + spare);
+
+    let result = std::panic::catch_unwind(move || {
+        let spare = vec.capacity() - vec.len();
+        unsafe { vec.advance_mut(spare + 1) };
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "advance out of bounds")]
+fn reader_advance_past_end() {
+    let mut reader: MyVecReader = MyVec::from(&b"abc"[..]).into();
+    reader.advance(2);
+    assert_eq!(reader.remaining(), 1);
+    reader.advance(2);
+}
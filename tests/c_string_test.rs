@@ -0,0 +1,80 @@
+use std::ffi::{CStr, CString};
+
+use rust_practice::collection::c_string::MyCString;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+#[test]
+fn c_string_rejects_interior_nul() {
+    for (bytes, position) in [(&b"\0"[..], 0), (b"ab\0", 2), (b"a\0b\0", 1)] {
+        let err = MyCString::new(bytes).unwrap_err();
+        assert_eq!(err.nul_position(), position);
+        assert_eq!(err.as_bytes(), bytes);
+        assert_eq!(
+            err.to_string(),
+            format!("nul byte found in provided data at position: {position}")
+        );
+    }
+
+    let mut s = MyCString::new(b"ok").unwrap();
+    let err = s.push_str("x\0y").unwrap_err();
+    assert_eq!(err.nul_position(), 1);
+    assert_eq!(err.into_bytes(), *b"x\0y");
+    assert!(s.push(0).is_err());
+    // 失败的追加不会修改原来的内容
+    assert_eq!(s.as_bytes_with_nul(), b"ok\0");
+}
+
+#[test]
+fn c_string_empty() {
+    let empty = MyCString::new(MyVec::<u8>::new()).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.as_bytes(), b"");
+    assert_eq!(empty.as_bytes_with_nul(), b"\0");
+    assert_eq!(empty, MyCString::default());
+    assert_eq!(empty.as_c_str(), c"");
+    assert!(empty.into_my_vec().is_empty());
+
+    let mut s = MyCString::new(b"abc").unwrap();
+    s.clear();
+    assert!(s.is_empty());
+    s.push(b'z').unwrap();
+    assert_eq!(s, c"z");
+}
+
+#[test]
+fn c_string_std_conversions() {
+    // 接管`MyVec`的内存
+    let mut vec = my_vec![b'h', b'i'];
+    vec.reserve(4);
+    let ptr = vec.as_ptr();
+    let s = MyCString::new(vec).unwrap();
+    assert_eq!(s.as_ptr().cast(), ptr);
+
+    let mut s = MyCString::from(CString::new("from std").unwrap());
+    s.push_bytes(b"!").unwrap();
+    let std: CString = s.clone().into();
+    assert_eq!(std.as_bytes(), b"from std!");
+    assert_eq!(MyCString::from(std.as_c_str()), s);
+    assert_eq!(format!("{s:?}"), r#""from std!""#);
+    assert_eq!(s.to_str(), Ok("from std!"));
+    assert_eq!(s.into_my_vec(), *b"from std!");
+
+    // `CString -> MyCString`不复制
+    let std = CString::new("no copy").unwrap();
+    let ptr = std.as_ptr();
+    assert_eq!(MyCString::from(std).as_ptr(), ptr);
+}
+
+#[cfg(unix)]
+#[test]
+fn c_string_strlen_round_trip() {
+    let mut s = MyCString::new(b"strlen").unwrap();
+    for _ in 0..100 {
+        s.push_str("-more").unwrap();
+        let len = unsafe { libc::strlen(s.as_ptr()) };
+        assert_eq!(len, s.len());
+        let back = unsafe { CStr::from_ptr(s.as_ptr()) };
+        assert_eq!(back.to_bytes(), s.as_bytes());
+    }
+}
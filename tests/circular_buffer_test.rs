@@ -0,0 +1,78 @@
+mod common;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::circular_buffer::CircularBuffer;
+
+#[test]
+fn circular_buffer_eviction_order() {
+    const N: usize = 5;
+    let mut buf = CircularBuffer::<N, usize>::new();
+    let mut evicted = Vec::new();
+    for i in 0..3 * N {
+        if let Some(old) = buf.push(i) {
+            evicted.push(old);
+        }
+        assert_eq!(buf.len(), (i + 1).min(N));
+        assert_eq!(buf.latest(), Some(&i));
+    }
+
+    // 被逐出的元素按从旧到新的顺序返回
+    assert_eq!(evicted, (0..2 * N).collect::<Vec<_>>());
+    assert!(buf.is_full());
+    assert_eq!(buf.oldest(), Some(&(2 * N)));
+    assert_eq!(buf.to_my_vec(), [10, 11, 12, 13, 14]);
+    assert!(buf.iter().copied().eq(2 * N..3 * N));
+    assert!(buf.iter().rev().copied().eq((2 * N..3 * N).rev()));
+    assert_eq!(buf[1], 11);
+    assert_eq!(buf.get(N), None);
+}
+
+#[test]
+fn circular_buffer_pop_and_clear() {
+    let mut buf: CircularBuffer<3, _> = (0..7).collect();
+    assert_eq!(buf, [4, 5, 6]);
+    assert_eq!(format!("{buf:?}"), "[4, 5, 6]");
+    assert_eq!(buf.pop_oldest(), Some(4));
+    assert_eq!(buf.pop_latest(), Some(6));
+    assert_eq!(buf.push(7), None);
+    assert_eq!(buf.push(8), None);
+    assert_eq!(buf.push(9), Some(5));
+    for x in &mut buf {
+        *x *= 10;
+    }
+    assert_eq!(buf.clone().to_my_vec(), [70, 80, 90]);
+
+    buf.clear();
+    assert!(buf.is_empty());
+    assert_eq!((buf.oldest(), buf.latest()), (None, None));
+
+    let mut zero = CircularBuffer::<0, i32>::new();
+    assert_eq!(zero.push(1), Some(1));
+    assert!(zero.is_empty());
+}
+
+#[test]
+fn circular_buffer_drop_count() {
+    let log = DropLog::new();
+    let mut buf = CircularBuffer::<4, DropTracker>::new();
+    for _ in 0..4 {
+        assert!(buf.push(log.track(0)).is_none());
+    }
+
+    // 返回的旧元素由调用者`drop`
+    let old = buf.push(log.track(0));
+    assert_eq!(log.dropped(), 0);
+    drop(old);
+    assert_eq!(log.dropped(), 1);
+
+    // `Extend`直接`drop`被覆盖的元素
+    buf.extend(log.track_n(6));
+    assert_eq!(log.dropped(), 7);
+    assert_eq!(buf.len(), 4);
+
+    // 已初始化的区域跨越了数组的末尾
+    drop(buf.pop_oldest());
+    assert_eq!(log.dropped(), 8);
+    drop(buf);
+    assert_eq!(log.dropped(), 11);
+}
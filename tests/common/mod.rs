@@ -0,0 +1,147 @@
+//! 集成测试共用的辅助类型。
+//!
+//! 每个测试文件通过`mod common;`引入，`tests/common`目录本身不会被当作
+//! 一个测试编译。由于并非每个测试文件都会用到所有的辅助函数，这里
+//! 允许`dead_code`。
+
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use rust_practice::collection::rng::XorShift64;
+
+/// 以固定的种子创建随机数生成器，测试失败时用同一个种子即可复现。
+pub fn rng(seed: u64) -> XorShift64 {
+    XorShift64::seed_from_u64(seed)
+}
+
+/// 记录由它创建的[`DropTracker`]的构造次数，以及被drop的次数和顺序。
+///
+/// 每个测试各自创建一个`DropLog`，因此测试之间并行运行也不会互相影响。
+///
+/// 通过[`DropLog::panic_on_clone`]和[`DropLog::panic_on_drop`]可以让
+/// 之后第`k`次`clone`或`drop`发生`panic`，用于测试unwind时的行为。
+#[derive(Clone, Default)]
+pub struct DropLog(Rc<Inner>);
+
+#[derive(Default)]
+struct Inner {
+    created: Cell<usize>,
+    dropped: RefCell<Vec<usize>>,
+    clone_countdown: Cell<Option<usize>>,
+    drop_countdown: Cell<Option<usize>>,
+}
+
+/// 倒数到0时返回`true`，并且只会触发一次。
+fn count_down(countdown: &Cell<Option<usize>>) -> bool {
+    match countdown.get() {
+        Some(1) => {
+            countdown.set(None);
+            true
+        }
+        Some(k) => {
+            countdown.set(Some(k - 1));
+            false
+        }
+        None => false,
+    }
+}
+
+impl DropLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建一个编号为`id`的追踪器。
+    pub fn track(&self, id: usize) -> DropTracker {
+        self.0.created.set(self.0.created.get() + 1);
+        DropTracker {
+            id,
+            log: self.clone(),
+        }
+    }
+
+    /// 创建编号为`0..n`的追踪器。
+    pub fn track_n(&self, n: usize) -> impl Iterator<Item = DropTracker> + '_ {
+        (0..n).map(|id| self.track(id))
+    }
+
+    /// 从现在起第`k`次`clone`时`panic`，`k`从1开始计数。
+    pub fn panic_on_clone(&self, k: usize) {
+        assert!(k > 0);
+        self.0.clone_countdown.set(Some(k));
+    }
+
+    /// 从现在起第`k`次`drop`时`panic`，`k`从1开始计数。发生`panic`的
+    /// 追踪器仍然被记为已经drop。
+    pub fn panic_on_drop(&self, k: usize) {
+        assert!(k > 0);
+        self.0.drop_countdown.set(Some(k));
+    }
+
+    /// 包括克隆在内，一共构造了多少个追踪器。
+    pub fn created(&self) -> usize {
+        self.0.created.get()
+    }
+
+    /// 一共drop了多少次。
+    pub fn dropped(&self) -> usize {
+        self.0.dropped.borrow().len()
+    }
+
+    /// 按drop的先后顺序返回被drop的编号。
+    pub fn order(&self) -> Vec<usize> {
+        self.0.dropped.borrow().clone()
+    }
+
+    /// 尚未被drop的追踪器个数，重复drop会使其下溢并`panic`。
+    pub fn alive(&self) -> usize {
+        self.created()
+            .checked_sub(self.dropped())
+            .expect("some tracker was dropped twice")
+    }
+}
+
+/// 在`drop`时向[`DropLog`]记录自己的编号，克隆得到的追踪器编号相同。
+pub struct DropTracker {
+    id: usize,
+    log: DropLog,
+}
+
+impl DropTracker {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+}
+
+impl Clone for DropTracker {
+    fn clone(&self) -> Self {
+        if count_down(&self.log.0.clone_countdown) {
+            panic!("DropTracker({}) panicked on clone", self.id);
+        }
+        self.log.track(self.id)
+    }
+}
+
+impl Drop for DropTracker {
+    fn drop(&mut self) {
+        self.log.0.dropped.borrow_mut().push(self.id);
+        if count_down(&self.log.0.drop_countdown) {
+            panic!("DropTracker({}) panicked on drop", self.id);
+        }
+    }
+}
+
+impl PartialEq for DropTracker {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl fmt::Debug for DropTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DropTracker({})", self.id)
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use rust_practice::collection::concurrent_vec::ConcurrentVec;
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+struct DropCounter;
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[allow(dead_code)]
+fn assert_send_sync() {
+    fn check<T: Send + Sync>() {}
+    check::<ConcurrentVec<String>>();
+}
+
+#[test]
+fn concurrent_vec_push_stress() {
+    const THREADS: u64 = 8;
+    const PER_THREAD: u64 = 10_000;
+
+    let vec = ConcurrentVec::new();
+    let indices: Vec<Vec<usize>> = thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let vec = &vec;
+                s.spawn(move || {
+                    let mut indices = Vec::new();
+                    for i in 0..PER_THREAD {
+                        let value = (t << 32) | i;
+                        let index = vec.push(value);
+                        assert_eq!(vec.get(index), Some(&value));
+                        // 读取其他线程可能正在写入的位置，看到的值必须是完整的
+                        if let Some(&other) = vec.get(index / 2) {
+                            assert!(other >> 32 < THREADS && (other & 0xffff_ffff) < PER_THREAD);
+                        }
+                        indices.push(index);
+                    }
+                    indices
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    let total = (THREADS * PER_THREAD) as usize;
+    assert_eq!(vec.len(), total);
+    assert_eq!(vec.iter().count(), total);
+    for (t, indices) in indices.iter().enumerate() {
+        // 同一个线程认领的下标是递增的
+        assert!(indices.is_sorted());
+        for (i, &index) in indices.iter().enumerate() {
+            assert_eq!(vec.get(index), Some(&(((t as u64) << 32) | i as u64)));
+        }
+    }
+
+    let mut all = vec.into_my_vec();
+    all.sort();
+    let expected: Vec<u64> = (0..THREADS)
+        .flat_map(|t| (0..PER_THREAD).map(move |i| (t << 32) | i))
+        .collect();
+    assert_eq!(*all, *expected);
+}
+
+#[test]
+fn concurrent_vec_single_thread() {
+    let mut vec = ConcurrentVec::new();
+    assert!(vec.is_empty());
+    assert_eq!(vec.get(0), None);
+    assert_eq!(vec.get(usize::MAX), None);
+
+    for i in 0..100 {
+        assert_eq!(vec.push(i.to_string()), i);
+    }
+    // 跨越前两个分段的边界
+    assert_eq!(vec.get(31).map(String::as_str), Some("31"));
+    assert_eq!(vec.get(32).map(String::as_str), Some("32"));
+    assert_eq!(vec.get(95).map(String::as_str), Some("95"));
+    assert_eq!(vec.get(100), None);
+
+    vec.get_mut(3).unwrap().push('!');
+    assert_eq!(vec.iter().nth(3).map(String::as_str), Some("3!"));
+    assert_eq!(
+        format!("{:?}", vec.iter().take(2).collect::<Vec<_>>()),
+        r#"["0", "1"]"#
+    );
+}
+
+#[test]
+fn concurrent_vec_drops_committed() {
+    let before = DROPS.load(Ordering::Relaxed);
+    let vec = ConcurrentVec::new();
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..250 {
+                    vec.push(DropCounter);
+                }
+            });
+        }
+    });
+    drop(vec);
+    assert_eq!(DROPS.load(Ordering::Relaxed) - before, 1000);
+
+    // `into_my_vec`取出的元素不会被再次`drop`
+    let vec = ConcurrentVec::new();
+    for _ in 0..40 {
+        vec.push(DropCounter);
+    }
+    let taken = vec.into_my_vec();
+    assert_eq!(DROPS.load(Ordering::Relaxed) - before, 1000);
+    drop(taken);
+    assert_eq!(DROPS.load(Ordering::Relaxed) - before, 1040);
+
+    let zst = ConcurrentVec::new();
+    for _ in 0..70 {
+        zst.push(());
+    }
+    assert_eq!(zst.into_my_vec().len(), 70);
+}
@@ -0,0 +1,169 @@
+//! [`MyVec`]与[`InplaceVec`]的drop正确性测试。
+//!
+//! 同一组用例通过`drop_matrix!`分别实例化到两种容器上，每个用例都断言
+//! 精确的drop次数，以及在能够确定时的drop顺序。
+
+mod common;
+
+use std::mem;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::collection::vec::MyVec;
+
+fn ids<'a>(iter: impl IntoIterator<Item = &'a DropTracker>) -> Vec<usize> {
+    iter.into_iter().map(DropTracker::id).collect()
+}
+
+macro_rules! drop_matrix {
+    ($name:ident, $vec:ty) => {
+        mod $name {
+            use super::*;
+
+            fn filled(log: &DropLog, n: usize) -> $vec {
+                let mut vec = <$vec>::new();
+                for tracker in log.track_n(n) {
+                    vec.push(tracker);
+                }
+                vec
+            }
+
+            #[test]
+            fn push_pop_insert_remove() {
+                let log = DropLog::new();
+                let mut vec = filled(&log, 4);
+
+                let popped = vec.pop().unwrap();
+                assert_eq!((popped.id(), log.dropped()), (3, 0));
+                drop(popped);
+
+                vec.insert(1, log.track(10));
+                let removed = vec.remove(2);
+                assert_eq!(removed.id(), 1);
+                drop(removed);
+                assert_eq!(log.order(), [3, 1]);
+                assert_eq!(ids(&vec), [0, 10, 2]);
+
+                drop(vec);
+                assert_eq!(log.order(), [3, 1, 0, 10, 2]);
+                assert_eq!(log.alive(), 0);
+            }
+
+            #[test]
+            fn clear_then_reuse() {
+                let log = DropLog::new();
+                let mut vec = filled(&log, 5);
+                vec.clear();
+                assert_eq!(log.order(), [0, 1, 2, 3, 4]);
+
+                vec.push(log.track(5));
+                vec.push(log.track(6));
+                drop(vec);
+                assert_eq!(log.order(), [0, 1, 2, 3, 4, 5, 6]);
+                assert_eq!(log.alive(), 0);
+            }
+
+            #[test]
+            fn drain_consumed_and_dropped() {
+                // 完全消费
+                let log = DropLog::new();
+                let mut vec = filled(&log, 6);
+                let drained: Vec<usize> = vec.drain(1..4).map(|t| t.id()).collect();
+                assert_eq!(drained, [1, 2, 3]);
+                assert_eq!(log.order(), [1, 2, 3]);
+                assert_eq!(ids(&vec), [0, 4, 5]);
+
+                // 部分消费后drop，剩下的元素按顺序drop
+                let mut drain = vec.drain(..);
+                let back = drain.next_back().unwrap();
+                drop(drain);
+                assert_eq!(log.order(), [1, 2, 3, 0, 4]);
+                assert_eq!(back.id(), 5);
+                assert!(vec.is_empty());
+
+                // 空范围什么也不drop
+                vec.push(back);
+                drop(vec.drain(1..));
+                assert_eq!(log.dropped(), 5);
+
+                drop(vec);
+                assert_eq!(log.order(), [1, 2, 3, 0, 4, 5]);
+                assert_eq!(log.alive(), 0);
+            }
+
+            #[test]
+            fn drain_forgotten_leaks_without_double_drop() {
+                let log = DropLog::new();
+                let mut vec = filled(&log, 5);
+                let mut drain = vec.drain(1..3);
+                drop(drain.next());
+                mem::forget(drain);
+
+                // 整个容器被泄漏，而不是重复drop
+                assert!(vec.is_empty());
+                drop(vec);
+                assert_eq!(log.order(), [1]);
+                assert_eq!(log.alive(), 4);
+            }
+
+            #[test]
+            fn into_iter_consumed_partially_or_not_at_all() {
+                let log = DropLog::new();
+                let ids: Vec<usize> = filled(&log, 3).into_iter().map(|t| t.id()).collect();
+                assert_eq!(ids, [0, 1, 2]);
+                assert_eq!(log.order(), [0, 1, 2]);
+
+                let log = DropLog::new();
+                let mut iter = filled(&log, 5).into_iter();
+                let (front, back) = (iter.next().unwrap(), iter.next_back().unwrap());
+                assert_eq!(log.dropped(), 0);
+                drop(iter);
+                assert_eq!(log.order(), [1, 2, 3]);
+                drop((front, back));
+                assert_eq!(log.order(), [1, 2, 3, 0, 4]);
+
+                let log = DropLog::new();
+                drop(filled(&log, 4).into_iter());
+                assert_eq!(log.order(), [0, 1, 2, 3]);
+                assert_eq!(log.alive(), 0);
+            }
+
+            #[test]
+            fn clone_and_clone_from() {
+                let log = DropLog::new();
+                let src = filled(&log, 3);
+                let copy = src.clone();
+                assert_eq!((log.created(), log.dropped()), (6, 0));
+                drop(copy);
+                assert_eq!(log.order(), [0, 1, 2]);
+
+                let mut dst = <$vec>::new();
+                dst.push(log.track(10));
+                dst.push(log.track(11));
+                dst.clone_from(&src);
+                assert_eq!(log.order(), [0, 1, 2, 10, 11]);
+                assert_eq!(ids(&dst), [0, 1, 2]);
+
+                drop(src);
+                drop(dst);
+                assert_eq!(log.dropped(), 11);
+                assert_eq!(log.alive(), 0);
+            }
+        }
+    };
+}
+
+drop_matrix!(my_vec, MyVec<DropTracker>);
+drop_matrix!(inplace_vec, InplaceVec<8, DropTracker>);
+
+#[test]
+fn my_vec_truncate_drops_tail_in_order() {
+    let log = DropLog::new();
+    let mut vec: MyVec<DropTracker> = log.track_n(5).collect();
+    vec.truncate(2);
+    assert_eq!(log.order(), [2, 3, 4]);
+    vec.truncate(3);
+    assert_eq!(log.dropped(), 3);
+    drop(vec);
+    assert_eq!(log.order(), [2, 3, 4, 0, 1]);
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use std::num::Wrapping;
+use std::ops::{Add, Sub};
+
+use rust_practice::collection::fenwick::FenwickTree;
+use rust_practice::collection::rng::{RngCore, XorShift64};
+
+/// 随机的修改、追加和查询，每次查询都与朴素的求和比较。
+fn check_model<T, F>(rng: &mut XorShift64, mut random: F)
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Default + PartialEq + std::fmt::Debug,
+    F: FnMut(&mut XorShift64) -> T,
+{
+    let naive = |model: &[T], start: usize, end: usize| {
+        model[start..end]
+            .iter()
+            .fold(T::default(), |sum, &x| sum + x)
+    };
+
+    for len in [0, 1, 2, 7, 16, 33, 100] {
+        let mut model: Vec<T> = (0..len).map(|_| random(rng)).collect();
+        let mut tree = FenwickTree::from_slice(&model);
+        for _ in 0..300 {
+            let n = model.len();
+            match rng.next_u64() % 4 {
+                0 if n > 0 => {
+                    let i = (rng.next_u64() % n as u64) as usize;
+                    let delta = random(rng);
+                    tree.update(i, delta);
+                    model[i] = model[i] + delta;
+                }
+                1 => {
+                    let value = random(rng);
+                    tree.push(value);
+                    model.push(value);
+                }
+                _ => {
+                    let a = (rng.next_u64() % (n as u64 + 1)) as usize;
+                    let b = (rng.next_u64() % (n as u64 + 1)) as usize;
+                    let (a, b) = (a.min(b), a.max(b));
+                    assert_eq!(tree.prefix_sum(b), naive(&model, 0, b));
+                    assert_eq!(tree.range_sum(a..b), naive(&model, a, b));
+                    assert_eq!(tree.range_sum(a..a), T::default());
+                }
+            }
+            assert_eq!(tree.len(), model.len());
+            assert_eq!(tree.range_sum(..), naive(&model, 0, model.len()));
+        }
+        // 逐个`push`与一次性构造得到相同的节点
+        assert_eq!(tree, FenwickTree::from_slice(&model));
+    }
+}
+
+#[test]
+fn fenwick_model_i64() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    check_model(&mut rng, |rng| (rng.next_u64() % 2001) as i64 - 1000);
+}
+
+#[test]
+fn fenwick_model_wrapping_u32() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    check_model(&mut rng, |rng| Wrapping(rng.next_u64() as u32));
+}
+
+#[test]
+fn fenwick_with_len_and_get() {
+    let mut tree = FenwickTree::<i64>::with_len(10);
+    assert_eq!(tree.range_sum(..), 0);
+    for i in 0..10 {
+        tree.update(i, i as i64 * i as i64);
+    }
+    assert!((0..10).all(|i| tree.get(i) == i as i64 * i as i64));
+    assert_eq!(tree.range_sum(3..=5), 9 + 16 + 25);
+    assert_eq!(
+        format!("{:?}", tree),
+        format!("{:?}", [0, 1, 4, 9, 16, 25, 36, 49, 64, 81])
+    );
+
+    let collected: FenwickTree<i64> = (1..=4).collect();
+    assert_eq!(collected.prefix_sum(4), 10);
+    assert!(FenwickTree::<i64>::default().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "FenwickTree index out of bounds: the len is 3 but the index is 3")]
+fn fenwick_update_out_of_bounds() {
+    let mut tree = FenwickTree::from_slice(&[1, 2, 3]);
+    tree.update(3, 1);
+}
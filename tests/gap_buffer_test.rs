@@ -0,0 +1,124 @@
+mod common;
+
+use common::DropLog;
+use rust_practice::collection::gap_buffer::GapBuffer;
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+#[test]
+fn gap_buffer_fuzz_against_vec() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..30 {
+        let mut buf = GapBuffer::new();
+        let mut model: Vec<String> = Vec::new();
+        let mut cursor = 0;
+        for step in 0..800 {
+            let value = step.to_string();
+            match rng.next_u64() % 8 {
+                0 | 1 => {
+                    buf.insert(value.clone());
+                    model.insert(cursor, value);
+                    cursor += 1;
+                }
+                2 => {
+                    let values = vec![value; (rng.next_u64() % 5) as usize];
+                    buf.insert_slice(&values);
+                    let n = values.len();
+                    model.splice(cursor..cursor, values);
+                    cursor += n;
+                }
+                3 => {
+                    let expected = (cursor < model.len()).then(|| model.remove(cursor));
+                    assert_eq!(buf.remove(), expected);
+                }
+                4 => {
+                    let expected = (cursor > 0).then(|| {
+                        cursor -= 1;
+                        model.remove(cursor)
+                    });
+                    assert_eq!(buf.remove_before(), expected);
+                }
+                5 | 6 => {
+                    cursor = rng.next_u64() as usize % (model.len() + 1);
+                    buf.set_cursor(cursor);
+                }
+                _ => {
+                    if !model.is_empty() {
+                        let index = rng.next_u64() as usize % model.len();
+                        buf[index].push('*');
+                        model[index].push('*');
+                    }
+                }
+            }
+            assert_eq!(buf.cursor(), cursor);
+            assert_eq!(buf.len(), model.len());
+            let (front, back) = buf.as_slices();
+            assert_eq!(front, &model[..cursor]);
+            assert_eq!(back, &model[cursor..]);
+            let index = rng.next_u64() as usize % (model.len() + 2);
+            assert_eq!(buf.get(index), model.get(index));
+        }
+        assert!(buf.iter().eq(model.iter()));
+        assert!(buf.iter().rev().eq(model.iter().rev()));
+        assert_eq!(buf.clone(), *model.as_slice());
+        assert_eq!(MyVec::from(buf), model.as_slice());
+    }
+}
+
+#[test]
+fn gap_buffer_growth_keeps_gap() {
+    let mut buf = GapBuffer::with_capacity(4);
+    buf.extend([1, 2, 3, 4]);
+    buf.set_cursor(2);
+    // 扩容时光标之后的元素被移动到新缓冲区的末尾
+    buf.insert(10);
+    assert!(buf.capacity() >= 5);
+    assert_eq!(buf.cursor(), 3);
+    assert_eq!(buf, [1, 2, 10, 3, 4]);
+    buf.reserve(100);
+    assert_eq!(buf.as_slices(), (&[1, 2, 10][..], &[3, 4][..]));
+}
+
+#[test]
+fn gap_buffer_my_vec_roundtrip() {
+    // 只移动光标时不会重新分配内存
+    let vec = my_vec![1, 2, 3];
+    let ptr = vec.as_ptr();
+    let mut buf = GapBuffer::from(vec);
+    assert_eq!(buf.cursor(), 3);
+    buf.set_cursor(1);
+    let vec = MyVec::from(buf);
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, [1, 2, 3]);
+
+    let mut buf = GapBuffer::from(vec);
+    buf.set_cursor(1);
+    buf.insert(0);
+    assert_eq!(MyVec::from(buf), [1, 0, 2, 3]);
+}
+
+#[test]
+fn gap_buffer_drop_only_initialized() {
+    let log = DropLog::new();
+    let mut buf = GapBuffer::new();
+    for _ in 0..10 {
+        buf.insert(log.track(0));
+    }
+    buf.set_cursor(4);
+    drop(buf.remove());
+    drop(buf.remove_before());
+    assert_eq!(log.dropped(), 2);
+    buf.set_cursor(7);
+    drop(buf);
+    assert_eq!(log.dropped(), 10);
+
+    let mut buf = GapBuffer::new();
+    for _ in 0..10 {
+        buf.insert(());
+    }
+    buf.set_cursor(3);
+    assert_eq!(buf.remove(), Some(()));
+    assert_eq!(buf.len(), 9);
+    assert_eq!(buf.iter().count(), 9);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::DropLog;
+use rust_practice::collection::grid::Grid;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+/// 第`y`行第`x`列的元素为`10 * y + x`。
+fn numbered(width: usize, height: usize) -> Grid<usize> {
+    let data: MyVec<_> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| 10 * y + x))
+        .collect();
+    Grid::from_my_vec(width, height, data).unwrap()
+}
+
+#[test]
+fn grid_non_square() {
+    let mut grid = numbered(4, 2);
+    assert_eq!(grid.dimensions(), (4, 2));
+    assert_eq!(grid.get(3, 1), Some(&13));
+    assert_eq!(grid.get(4, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+    assert_eq!(grid.row(1), [10, 11, 12, 13]);
+    grid.row_mut(0)[1] = 99;
+    *grid.get_mut(2, 1).unwrap() += 100;
+    assert_eq!(format!("{grid:?}"), "[[0, 99, 2, 3], [10, 11, 112, 13]]");
+    assert_eq!(grid.rows().next_back(), Some(&[10, 11, 112, 13][..]));
+
+    let coords: Vec<_> = grid
+        .iter_with_coords()
+        .filter(|(_, v)| **v > 50)
+        .map(|(c, _)| c)
+        .collect();
+    assert_eq!(coords, [(1, 0), (2, 1)]);
+
+    let transposed = numbered(4, 2).transpose();
+    assert_eq!(transposed.dimensions(), (2, 4));
+    for (x, y) in [(0, 0), (1, 3), (0, 2)] {
+        assert_eq!(transposed[(x, y)], 10 * x + y);
+    }
+    assert_eq!(transposed.clone().transpose(), numbered(4, 2));
+
+    let grid = numbered(3, 2).map(|v| v.to_string());
+    assert_eq!(grid[(2, 1)], "12");
+}
+
+#[test]
+fn grid_resize() {
+    // 同时扩大两个方向
+    let mut grid = numbered(2, 2);
+    grid.resize(3, 4, 7);
+    assert_eq!(
+        format!("{grid:?}"),
+        "[[0, 1, 7], [10, 11, 7], [7, 7, 7], [7, 7, 7]]"
+    );
+    // 宽度缩小、高度扩大
+    let mut grid = numbered(4, 2);
+    grid.resize(2, 3, 0);
+    assert_eq!(grid.into_my_vec(), [0, 1, 10, 11, 0, 0]);
+    // 同时缩小两个方向
+    let mut grid = numbered(4, 3);
+    grid.resize(3, 2, 0);
+    assert_eq!(grid, numbered(3, 2));
+    grid.fill(5);
+    assert!(grid.iter().all(|&v| v == 5));
+
+    // 被裁掉的元素只`drop`一次
+    let log = DropLog::new();
+    let mut grid = Grid::new(3, 3, log.track(0));
+    assert_eq!(log.dropped(), 0);
+    // 裁掉7个元素，`fill`没有被使用，也会被`drop`
+    grid.resize(2, 1, log.track(0));
+    assert_eq!(log.dropped(), 7 + 1);
+    drop(grid);
+    assert_eq!(log.dropped(), 10);
+}
+
+#[test]
+fn grid_degenerate() {
+    let grid = Grid::new(0, 3, 'x');
+    assert!(grid.is_empty());
+    assert_eq!(grid.rows().len(), 3);
+    assert!(grid.rows().all(|row| row.is_empty()));
+    // 开启`rkyv`时，依赖的`rend`为`char`实现了与`char_le`等类型的
+    // `PartialEq`，因此需要标注空数组的元素类型
+    assert_eq!(grid.row(2), [] as [char; 0]);
+    assert_eq!(grid.get(0, 0), None);
+    assert_eq!(grid.iter_with_coords().count(), 0);
+    let t = grid.transpose();
+    assert_eq!((t.dimensions(), t.rows().count()), ((3, 0), 0));
+
+    let mut grid = Grid::new(5, 0, 1);
+    assert_eq!(grid.rows().next(), None);
+    grid.resize(2, 2, 3);
+    assert_eq!(grid, Grid::new(2, 2, 3));
+    grid.resize(0, 2, 3);
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [&[] as &[i32]; 2]);
+
+    let err = Grid::from_my_vec(2, 2, my_vec![1, 2, 3]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "grid of 2x2 does not match data of length 3"
+    );
+    assert_eq!(err.into_data(), [1, 2, 3]);
+    // `width * height`溢出不会被当作合法的尺寸
+    let err = Grid::from_my_vec(usize::MAX, 2, MyVec::<u8>::new()).unwrap_err();
+    assert_eq!(err.width(), usize::MAX);
+    assert!(Grid::<()>::from_my_vec(0, usize::MAX, MyVec::new()).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "grid dimensions overflow")]
+fn grid_new_overflow() {
+    let _ = Grid::new(usize::MAX, 2, ());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn grid_index_out_of_bounds() {
+    let grid = numbered(2, 3);
+    let _ = grid[(2, 0)];
+}
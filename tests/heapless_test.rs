@@ -0,0 +1,68 @@
+#![cfg(feature = "heapless")]
+
+mod common;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::inplace_vec;
+
+#[test]
+fn heapless_round_trip_moves() {
+    let log = DropLog::new();
+    let mut hv: heapless::Vec<DropTracker, 4> = heapless::Vec::new();
+    for tracker in log.track_n(3) {
+        assert!(hv.push(tracker).is_ok());
+    }
+
+    let vec: InplaceVec<4, DropTracker> = hv.into();
+    assert_eq!(log.dropped(), 0);
+    assert_eq!(
+        vec.iter().map(DropTracker::id).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+
+    let back: heapless::Vec<DropTracker, 4> = vec.into();
+    assert_eq!(log.dropped(), 0);
+    assert_eq!(back.len(), 3);
+    assert_eq!(back[2].id(), 2);
+
+    drop(back);
+    assert_eq!(log.dropped(), 3);
+}
+
+#[test]
+fn heapless_across_capacities() {
+    let log = DropLog::new();
+    let mut hv: heapless::Vec<DropTracker, 8, u8> = heapless::Vec::new();
+    for tracker in log.track_n(3) {
+        assert!(hv.push(tracker).is_ok());
+    }
+
+    // 容量不足时原样返回，不会`drop`任何元素
+    let hv = InplaceVec::<2, DropTracker>::try_from_heapless(hv).unwrap_err();
+    assert_eq!((hv.len(), log.dropped()), (3, 0));
+
+    let vec = InplaceVec::<3, DropTracker, u8>::try_from_heapless(hv).unwrap();
+    let vec = vec.try_into_heapless::<2, usize>().unwrap_err();
+    assert_eq!((vec.len(), log.dropped()), (3, 0));
+
+    let hv: heapless::Vec<DropTracker, 16> = vec.try_into_heapless().unwrap();
+    assert_eq!(
+        hv.iter().map(DropTracker::id).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+    drop(hv);
+    assert_eq!(log.dropped(), 3);
+}
+
+#[test]
+fn heapless_partial_eq() {
+    let vec: InplaceVec<4, i32> = inplace_vec![1, 2, 3];
+    let hv: heapless::Vec<i32, 8> = heapless::Vec::from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(vec, hv);
+    assert_eq!(hv, vec);
+
+    let shorter: heapless::Vec<i32, 2> = heapless::Vec::from_slice(&[1, 2]).unwrap();
+    assert_ne!(vec, shorter);
+    assert_ne!(shorter, vec);
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use std::collections::VecDeque;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::inplace_deque::InplaceDeque;
+use rust_practice::collection::rng::RngCore;
+
+fn assert_same<const N: usize, T>(deque: &InplaceDeque<N, T>, model: &VecDeque<T>)
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    assert_eq!(deque.len(), model.len());
+    assert!(deque.iter().eq(model.iter()));
+    assert!(deque.iter().rev().eq(model.iter().rev()));
+    let (a, b) = deque.as_slices();
+    assert!(a.iter().chain(b).eq(model.iter()));
+    assert_eq!(deque.front(), model.front());
+    assert_eq!(deque.back(), model.back());
+}
+
+#[test]
+fn inplace_deque_full() {
+    let mut deque = InplaceDeque::<3, i32>::new();
+    assert!(deque.is_empty());
+    assert_eq!(deque.pop_back(), None);
+    deque.push_back(1).unwrap();
+    deque.push_front(0).unwrap();
+    deque.push_back(2).unwrap();
+    assert!(deque.is_full());
+    assert_eq!(deque.push_back(3), Err(3));
+    assert_eq!(deque.push_front(-1), Err(-1));
+    assert_eq!(deque, [0, 1, 2]);
+    deque[0] = 10;
+    assert_eq!(deque.get(3), None);
+    assert_eq!(format!("{deque:?}"), "[10, 1, 2]");
+
+    let mut empty = InplaceDeque::<0, i32>::new();
+    assert_eq!(empty.push_back(1), Err(1));
+    assert_eq!(empty.push_front(1), Err(1));
+    assert_eq!(empty.pop_front(), None);
+    assert_eq!(empty.as_slices(), (&[][..], &[][..]));
+}
+
+#[test]
+fn inplace_deque_wrap_around() {
+    // 使用非2的幂的容量，反复跨越缓冲区的边界
+    let mut deque = InplaceDeque::<5, usize>::new();
+    let mut model = VecDeque::new();
+    for i in 0..100 {
+        if i % 3 == 0 {
+            deque.push_front(i).unwrap();
+            model.push_front(i);
+        } else {
+            deque.push_back(i).unwrap();
+            model.push_back(i);
+        }
+        if deque.is_full() {
+            assert_eq!(deque.pop_front(), model.pop_front());
+            assert_eq!(deque.pop_back(), model.pop_back());
+        }
+        assert_same(&deque, &model);
+    }
+
+    for x in deque.iter_mut() {
+        *x += 1;
+    }
+    for x in model.iter_mut() {
+        *x += 1;
+    }
+    assert_same(&deque, &model);
+}
+
+#[test]
+fn inplace_deque_model() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    let mut deque = InplaceDeque::<7, u64>::new();
+    let mut model = VecDeque::new();
+    for _ in 0..10000 {
+        let value = rng.next_u64();
+        match rng.next_u64() % 5 {
+            0 => {
+                let ret = deque.push_back(value);
+                if model.len() < 7 {
+                    assert_eq!(ret, Ok(()));
+                    model.push_back(value);
+                } else {
+                    assert_eq!(ret, Err(value));
+                }
+            }
+            1 => {
+                let ret = deque.push_front(value);
+                if model.len() < 7 {
+                    assert_eq!(ret, Ok(()));
+                    model.push_front(value);
+                } else {
+                    assert_eq!(ret, Err(value));
+                }
+            }
+            2 => assert_eq!(deque.pop_back(), model.pop_back()),
+            3 => assert_eq!(deque.pop_front(), model.pop_front()),
+            _ => {
+                let index = value as usize % 8;
+                assert_eq!(deque.get(index), model.get(index));
+                if value.is_multiple_of(50) {
+                    deque.clear();
+                    model.clear();
+                }
+            }
+        }
+        assert_same(&deque, &model);
+    }
+}
+
+#[test]
+fn inplace_deque_drop() {
+    let log = DropLog::new();
+    let mut deque = InplaceDeque::<4, DropTracker>::new();
+    for _ in 0..3 {
+        assert!(deque.push_back(log.track(0)).is_ok());
+    }
+    drop(deque.pop_front());
+    drop(deque.pop_front());
+    for _ in 0..3 {
+        assert!(deque.push_back(log.track(0)).is_ok());
+    }
+    assert!(!deque.as_slices().1.is_empty());
+    // 已满时被返还的元素由调用者`drop`
+    assert!(deque.push_front(log.track(0)).is_err());
+    assert_eq!(log.dropped(), 3);
+    drop(deque);
+    assert_eq!(log.dropped(), 7);
+
+    let log = DropLog::new();
+    let mut deque = InplaceDeque::<3, DropTracker>::new();
+    for _ in 0..3 {
+        assert!(deque.push_front(log.track(0)).is_ok());
+    }
+    deque.clear();
+    assert_eq!(log.dropped(), 3);
+    assert!(deque.is_empty());
+    drop(deque);
+    assert_eq!(log.dropped(), 3);
+}
@@ -0,0 +1,158 @@
+mod common;
+
+use std::cell::Cell;
+use std::cmp::{Ordering, Reverse};
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::inplace_heap::InplaceHeap;
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::collection::rng::RngCore;
+
+#[test]
+fn heap_pops_are_ordered() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..200 {
+        let mut heap = InplaceHeap::<16, u64>::new();
+        let mut model = Vec::new();
+        for _ in 0..64 {
+            if rng.next_u64().is_multiple_of(3) {
+                assert_eq!(heap.pop(), model.pop());
+            } else {
+                let value = rng.next_u64() % 20;
+                match heap.push(value) {
+                    Ok(()) => model.push(value),
+                    Err(v) => {
+                        assert_eq!(v, value);
+                        assert!(heap.is_full());
+                    }
+                }
+            }
+            model.sort();
+            assert_eq!(heap.len(), model.len());
+            assert_eq!(heap.peek(), model.last());
+        }
+
+        let mut popped = Vec::new();
+        while let Some(v) = heap.pop() {
+            popped.push(v);
+        }
+        assert!(popped.is_sorted_by(|a, b| a >= b));
+        model.reverse();
+        assert_eq!(popped, model);
+    }
+}
+
+#[test]
+fn heap_keep_best_n_matches_sort() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for len in 0..100 {
+        let input: Vec<u64> = (0..len).map(|_| rng.next_u64() % 50).collect();
+
+        let mut expected = input.clone();
+        expected.sort();
+        expected.truncate(8);
+        let heap: InplaceHeap<8, _> = input.iter().copied().collect();
+        assert_eq!(heap.into_sorted_inplace_vec(), expected);
+
+        // 使用`Reverse`保留最大的N个元素
+        let mut expected = input.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+        expected.truncate(8);
+        let heap: InplaceHeap<8, _> = input.iter().copied().map(Reverse).collect();
+        let got: Vec<_> = heap
+            .into_sorted_inplace_vec()
+            .into_iter()
+            .map(|Reverse(v)| v)
+            .collect();
+        assert_eq!(got, expected);
+    }
+}
+
+#[test]
+fn heap_push_pop_and_sorted() {
+    let mut heap = InplaceHeap::<4, _>::from(InplaceVec::from_array([3, 9, 1, 6]));
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(heap.push_pop(10), 10);
+    assert_eq!(heap.push_pop(2), 9);
+    assert_eq!(heap.push_pop(6), 6);
+    assert_eq!(heap.len(), 4);
+    assert_eq!(
+        format!("{:?}", heap.clone().into_sorted_inplace_vec()),
+        "[1, 2, 3, 6]"
+    );
+
+    let mut empty = InplaceHeap::<0, i32>::new();
+    assert_eq!(empty.push(1), Err(1));
+    assert_eq!(empty.push_pop(1), 1);
+    assert_eq!(empty.pop(), None);
+    let empty: InplaceHeap<0, i32> = (0..10).collect();
+    assert!(empty.is_empty());
+}
+
+/// 比较计数达到阈值时`panic`，通过[`DropTracker`]记录`drop`的次数。
+struct Bomb {
+    value: u32,
+    cmp_budget: Rc<Cell<usize>>,
+    _tracker: DropTracker,
+}
+
+impl PartialEq for Bomb {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Bomb {}
+
+impl PartialOrd for Bomb {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bomb {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let budget = self.cmp_budget.get();
+        if budget == 0 {
+            panic!("comparison budget exhausted");
+        }
+        self.cmp_budget.set(budget - 1);
+        self.value.cmp(&other.value)
+    }
+}
+
+#[test]
+fn heap_panic_in_cmp_keeps_elements() {
+    for budget in 0..20 {
+        let cmp_budget = Rc::new(Cell::new(usize::MAX));
+        let log = DropLog::new();
+        let mut heap = InplaceHeap::<8, Bomb>::new();
+        for value in [5, 1, 7, 3, 8, 2] {
+            let bomb = Bomb {
+                value,
+                cmp_budget: cmp_budget.clone(),
+                _tracker: log.track(value as usize),
+            };
+            heap.push(bomb).ok().unwrap();
+        }
+
+        cmp_budget.set(budget);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            heap.pop();
+            heap.pop();
+        }));
+        if result.is_ok() {
+            assert_eq!(log.dropped(), 2);
+        }
+        // 被弹出的元素已经被`drop`，其余元素没有丢失或重复
+        let popped = log.dropped();
+        let mut values: Vec<_> = heap.iter().map(|b| b.value).collect();
+        values.sort();
+        assert_eq!(values.len() + popped, 6);
+
+        drop(heap);
+        assert_eq!(log.dropped(), 6);
+    }
+}
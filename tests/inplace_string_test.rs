@@ -0,0 +1,88 @@
+use std::fmt::Write;
+
+use rust_practice::collection::inplace_string::InplaceString;
+
+#[test]
+fn inplace_string_emoji_boundary() {
+    let mut s = InplaceString::<6>::new();
+    s.push_str("ab");
+    // 剩余4个字节，恰好可以容纳一个emoji
+    assert!(s.try_push('🦀').is_ok());
+    assert_eq!(s.remaining_capacity(), 0);
+    assert_eq!(s, "ab🦀");
+
+    s.pop();
+    s.push('c');
+    // 剩余3个字节，emoji需要4个字节，不会写入任何字节
+    let err = s.try_push('🦀').unwrap_err();
+    assert_eq!(err.into_element(), '🦀');
+    assert_eq!(err.required(), 7);
+    assert_eq!(s, "abc");
+    assert!(s.try_push_str("中文").is_err());
+    assert_eq!(s.as_bytes(), b"abc");
+    assert!(s.try_push_str("中").is_ok());
+    assert_eq!(s, "abc中");
+    assert!(s.try_push_str("").is_ok());
+    assert!(s.try_push_str("d").is_err());
+}
+
+#[test]
+fn inplace_string_fmt_exact_fill() {
+    let (n, ch) = (42, 'x');
+    let mut s = InplaceString::<10>::new();
+    write!(s, "{n:>4}|{ch}🦀").unwrap();
+    assert_eq!(s, "  42|x🦀");
+    assert_eq!(s.len(), 10);
+    assert!(write!(s, "").is_ok());
+    assert!(write!(s, "{}", 1).is_err());
+    assert_eq!(s, "  42|x🦀");
+
+    let mut s = InplaceString::<4>::new();
+    let zh = "中";
+    assert!(write!(s, "{zh}").is_ok());
+    assert!(s.write_char('é').is_err());
+    assert!(s.write_char('e').is_ok());
+    assert_eq!(format!("{s}|{s:?}"), "中e|\"中e\"");
+}
+
+#[test]
+#[should_panic(expected = "InplaceVec overflow")]
+fn inplace_string_push_overflow() {
+    let mut s = InplaceString::<3>::new();
+    s.push('a');
+    s.push('中');
+}
+
+#[test]
+fn inplace_string_conversion() {
+    let s = InplaceString::<8>::try_from("你好").unwrap();
+    assert_eq!(s, "你好");
+    assert_eq!("你好", s);
+    assert!(InplaceString::<5>::try_from("你好").is_err());
+
+    let s: InplaceString<4> = "🦀".parse().unwrap();
+    assert_eq!(s.as_str(), "🦀");
+    assert!("🦀!".parse::<InplaceString<4>>().is_err());
+}
+
+#[test]
+fn inplace_string_pop_truncate_clear() {
+    let mut s = InplaceString::<16>::try_from("a中🦀é").unwrap();
+    assert_eq!(s.pop(), Some('é'));
+    assert_eq!(s.pop(), Some('🦀'));
+    s.truncate(1);
+    assert_eq!(s, "a");
+    s.make_ascii_uppercase();
+    assert_eq!(s, "A");
+    s.clear();
+    assert!(s.is_empty());
+    assert_eq!(s.pop(), None);
+    assert_eq!(s.capacity(), 16);
+}
+
+#[test]
+#[should_panic(expected = "is_char_boundary")]
+fn inplace_string_truncate_boundary() {
+    let mut s = InplaceString::<8>::try_from("中").unwrap();
+    s.truncate(1);
+}
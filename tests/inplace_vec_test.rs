@@ -0,0 +1,841 @@
+mod common;
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::iter;
+use std::rc::Rc;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::inplace_vec::{CapacityError, InplaceVec};
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::{inplace_vec, my_vec};
+
+#[test]
+fn inplace_vec_from_iter_truncated() {
+    let vec: InplaceVec<3, i32> = InplaceVec::from_iter_truncated([1, 2, 3]);
+    assert_eq!(vec, [1, 2, 3]);
+
+    let vec: InplaceVec<3, i32> = InplaceVec::from_iter_truncated([1, 2, 3, 4]);
+    assert_eq!(vec, [1, 2, 3]);
+
+    // 无限迭代器也不会`panic`，且只会消费`N`个元素
+    let mut source = iter::successors(Some(0), |x| Some(x + 1));
+    let vec: InplaceVec<4, i32> = InplaceVec::from_iter_truncated(source.by_ref());
+    assert_eq!(vec, [0, 1, 2, 3]);
+    assert_eq!(source.next(), Some(4));
+
+    let vec: InplaceVec<0, i32> = InplaceVec::from_iter_truncated(iter::repeat(1));
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn inplace_vec_try_from_iter() {
+    let vec = InplaceVec::<3, i32>::try_from_iter([1, 2, 3]).unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+
+    let vec = InplaceVec::<3, i32>::try_from_iter([1]).unwrap();
+    assert_eq!(vec, [1]);
+
+    let err = InplaceVec::<3, i32>::try_from_iter([1, 2, 3, 4]).unwrap_err();
+    assert_eq!(err, CapacityError::new((), 4, 3));
+
+    // 无限迭代器只会被消费`N + 1`个元素
+    let mut source = iter::successors(Some(0), |x| Some(x + 1));
+    assert!(InplaceVec::<4, i32>::try_from_iter(source.by_ref()).is_err());
+    assert_eq!(source.next(), Some(5));
+}
+
+#[test]
+fn inplace_vec_try_from_iter_drops_taken() {
+    let log = DropLog::new();
+    let items = log.track_n(5);
+    assert!(InplaceVec::<3, _>::try_from_iter(items).is_err());
+    // 取出的4个元素都被`drop`，剩余的1个从未被创建
+    assert_eq!(log.dropped(), 4);
+}
+
+#[test]
+fn inplace_vec_debug_format() {
+    let mut vec: InplaceVec<4, i32> = InplaceVec::new();
+    vec.push(1);
+    vec.push(2);
+    assert_eq!(format!("{vec:?}"), "[1, 2]");
+    assert_eq!(format!("{vec:#?}"), "[\n    1,\n    2,\n]");
+
+    let mut nested: InplaceVec<2, InplaceVec<2, u8>> = InplaceVec::new();
+    nested.push(InplaceVec::try_from(&[1, 2]).unwrap());
+    nested.push(InplaceVec::new());
+    assert_eq!(format!("{nested:?}"), "[[1, 2], []]");
+    assert_eq!(
+        format!("{nested:#?}"),
+        "[\n    [\n        1,\n        2,\n    ],\n    [],\n]"
+    );
+}
+
+#[test]
+fn inplace_vec_map() {
+    let vec: InplaceVec<4, i32> = InplaceVec::try_from(&[1, 2, 3]).unwrap();
+    let mapped: InplaceVec<4, String> = vec.map(|x| x.to_string());
+    assert_eq!(mapped, ["1".to_string(), "2".to_string(), "3".to_string()]);
+
+    let strings: InplaceVec<3, String> =
+        InplaceVec::try_from(&["a".to_string(), "bb".to_string()]).unwrap();
+    let lens = strings.map(|s| s.len());
+    assert_eq!(lens, [1, 2]);
+
+    let empty: InplaceVec<2, i32> = InplaceVec::new();
+    assert!(empty.map(|x| x * 2).is_empty());
+}
+
+#[test]
+fn inplace_vec_try_map() {
+    let vec: InplaceVec<4, &str> = InplaceVec::try_from(&["1", "2", "3"]).unwrap();
+    let parsed: Result<InplaceVec<4, i32>, _> = vec.try_map(str::parse::<i32>);
+    assert_eq!(parsed.unwrap(), [1, 2, 3]);
+
+    let vec: InplaceVec<4, &str> = InplaceVec::try_from(&["1", "x", "3"]).unwrap();
+    assert!(vec.try_map(str::parse::<i32>).is_err());
+}
+
+#[test]
+fn inplace_vec_try_map_drops_exactly_once() {
+    for fail_at in 0..5 {
+        let src_log = DropLog::new();
+        let dst_log = DropLog::new();
+
+        let mut vec: InplaceVec<5, DropTracker> = InplaceVec::new();
+        for _ in 0..5 {
+            vec.push(src_log.track(0));
+        }
+
+        let mut index = 0;
+        let result = vec.try_map(|elem| {
+            let current = index;
+            index += 1;
+            drop(elem);
+            if current == fail_at {
+                Err(current)
+            } else {
+                Ok(dst_log.track(0))
+            }
+        });
+
+        assert_eq!(result.unwrap_err(), fail_at);
+        assert_eq!(src_log.dropped(), 5);
+        assert_eq!(dst_log.dropped(), fail_at);
+    }
+}
+
+#[test]
+fn inplace_vec_map_panic_safety() {
+    let src_log = DropLog::new();
+    let dst_log = DropLog::new();
+
+    let mut vec: InplaceVec<4, DropTracker> = InplaceVec::new();
+    for _ in 0..4 {
+        vec.push(src_log.track(0));
+    }
+
+    let dst = dst_log.clone();
+    let mut index = 0;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+        vec.map(|elem| {
+            index += 1;
+            if index == 3 {
+                panic!("map panicked");
+            }
+            drop(elem);
+            dst.track(0)
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(src_log.dropped(), 4);
+    assert_eq!(dst_log.dropped(), 2);
+}
+
+#[test]
+fn inplace_vec_drain_as_slice_and_keep_rest() {
+    let mut vec: InplaceVec<8, i32> = InplaceVec::try_from(&[0, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+    let mut drain = vec.drain(1..6);
+    assert_eq!(drain.as_slice(), [1, 2, 3, 4, 5]);
+    assert_eq!(drain.next(), Some(1));
+    assert_eq!(drain.next_back(), Some(5));
+    assert_eq!(drain.as_slice(), [2, 3, 4]);
+    assert_eq!(drain.next(), Some(2));
+    assert_eq!(drain.as_slice(), [3, 4]);
+    drain.keep_rest();
+    assert_eq!(vec, [0, 3, 4, 6, 7]);
+
+    // 完全没有迭代时，`keep_rest`不改变任何内容
+    let mut vec: InplaceVec<4, i32> = InplaceVec::try_from(&[1, 2, 3]).unwrap();
+    vec.drain(..).keep_rest();
+    assert_eq!(vec, [1, 2, 3]);
+
+    // 迭代完所有元素时，等价于普通的drain
+    let mut vec: InplaceVec<4, i32> = InplaceVec::try_from(&[1, 2, 3, 4]).unwrap();
+    let mut drain = vec.drain(1..3);
+    assert_eq!(drain.next_back(), Some(3));
+    assert_eq!(drain.next_back(), Some(2));
+    assert_eq!(drain.next(), None);
+    assert_eq!(drain.next_back(), None);
+    assert!(drain.as_slice().is_empty());
+    drain.keep_rest();
+    assert_eq!(vec, [1, 4]);
+}
+
+#[test]
+fn inplace_vec_drain_keep_rest_drops() {
+    let log = DropLog::new();
+    let mut vec: InplaceVec<6, DropTracker> = InplaceVec::new();
+    for _ in 0..6 {
+        vec.push(log.track(0));
+    }
+    let mut drain = vec.drain(1..5);
+    drain.next();
+    drain.next_back();
+    drain.keep_rest();
+    assert_eq!(log.dropped(), 2);
+    assert_eq!(vec.len(), 4);
+    drop(vec);
+    assert_eq!(log.dropped(), 6);
+}
+
+/// 主要用于在`cargo miri test`下检查`Drain`没有产生别名问题。
+#[test]
+fn inplace_vec_drain_interleaved_then_reuse() {
+    let mut vec: InplaceVec<8, String> = InplaceVec::new();
+    for i in 0..8 {
+        vec.push(i.to_string());
+    }
+
+    let mut drain = vec.drain(2..7);
+    assert_eq!(drain.next().as_deref(), Some("2"));
+    assert_eq!(drain.next_back().as_deref(), Some("6"));
+    assert_eq!(drain.next().as_deref(), Some("3"));
+    assert_eq!(drain.as_slice(), ["4".to_string(), "5".to_string()]);
+    drop(drain);
+
+    assert_eq!(vec, ["0", "1", "7"].map(String::from));
+    vec.push("8".to_string());
+    vec.insert(0, "-1".to_string());
+    assert_eq!(vec.remove(1), "0");
+    assert_eq!(vec, ["-1", "1", "7", "8"].map(String::from));
+}
+
+#[test]
+fn inplace_vec_into_iter_as_slice() {
+    let vec: InplaceVec<5, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5]).unwrap();
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.as_slice(), [1, 2, 3, 4, 5]);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.as_slice(), [2, 3, 4]);
+    iter.as_mut_slice()[0] = 20;
+    assert_eq!(iter.next(), Some(20));
+    assert_eq!(format!("{iter:?}"), "IntoIter([3, 4])");
+}
+
+#[test]
+fn inplace_vec_into_iter_clone() {
+    let log = DropLog::new();
+    let mut vec: InplaceVec<6, (usize, DropTracker)> = InplaceVec::new();
+    for i in 0..6 {
+        vec.push((i, log.track(0)));
+    }
+
+    let mut iter = vec.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(log.dropped(), 2);
+
+    let mut cloned = iter.clone();
+    assert_eq!(cloned.len(), 4);
+    assert_eq!(
+        cloned.as_slice().iter().map(|x| x.0).collect::<Vec<_>>(),
+        [1, 2, 3, 4]
+    );
+
+    // 两个迭代器互不影响
+    assert_eq!(iter.next().map(|x| x.0), Some(1));
+    assert_eq!(cloned.next_back().map(|x| x.0), Some(4));
+    assert_eq!(iter.map(|x| x.0).collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(cloned.map(|x| x.0).collect::<Vec<_>>(), [1, 2, 3]);
+
+    // 原有的6个，加上克隆出来的4个
+    assert_eq!(log.dropped(), 10);
+}
+
+#[test]
+fn inplace_vec_extract_if() {
+    let mut vec: InplaceVec<8, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    let extracted: Vec<_> = vec.extract_if(|x| *x % 2 == 0).collect();
+    assert_eq!(extracted, [2, 4, 6, 8]);
+    assert_eq!(vec, [1, 3, 5, 7]);
+
+    let extracted: Vec<_> = vec.extract_if(|_| false).collect();
+    assert!(extracted.is_empty());
+    assert_eq!(vec, [1, 3, 5, 7]);
+
+    let extracted: Vec<_> = vec.extract_if(|_| true).collect();
+    assert_eq!(extracted, [1, 3, 5, 7]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn inplace_vec_extract_if_early_drop() {
+    let mut vec: InplaceVec<8, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    let mut iter = vec.extract_if(|x| *x % 2 == 0);
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(4));
+    drop(iter);
+    // 未检查的元素`5..=8`都被保留
+    assert_eq!(vec, [1, 3, 5, 6, 7, 8]);
+}
+
+#[test]
+fn inplace_vec_extract_if_forget() {
+    let log = DropLog::new();
+    let mut vec: InplaceVec<4, DropTracker> = InplaceVec::new();
+    for _ in 0..4 {
+        vec.push(log.track(0));
+    }
+    let mut iter = vec.extract_if(|_| true);
+    drop(iter.next());
+    std::mem::forget(iter);
+
+    // 剩余的元素被泄露，但不会被重复`drop`
+    assert!(vec.is_empty());
+    vec.push(log.track(0));
+    drop(vec);
+    assert_eq!(log.dropped(), 2);
+}
+
+#[test]
+fn inplace_vec_extract_if_panicking_pred() {
+    let mut vec: InplaceVec<6, String> = InplaceVec::new();
+    for i in 0..6 {
+        vec.push(i.to_string());
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.extract_if(|s| {
+            if s == "3" {
+                panic!("predicate panicked");
+            }
+            s == "1"
+        })
+        .for_each(drop);
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(vec, ["0", "2", "3", "4", "5"].map(String::from));
+}
+
+#[test]
+fn inplace_vec_splice() {
+    // 等长替换
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5]).unwrap();
+    let removed: Vec<_> = vec.splice(1..3, [20, 30]).collect();
+    assert_eq!(removed, [2, 3]);
+    assert_eq!(vec, [1, 20, 30, 4, 5]);
+
+    // 替换的元素更少
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5]).unwrap();
+    let removed: Vec<_> = vec.splice(1..4, [0]).collect();
+    assert_eq!(removed, [2, 3, 4]);
+    assert_eq!(vec, [1, 0, 5]);
+
+    // 替换的元素更多
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2, 3, 4]).unwrap();
+    let removed: Vec<_> = vec.splice(1..2, [7, 8, 9]).collect();
+    assert_eq!(removed, [2]);
+    assert_eq!(vec, [1, 7, 8, 9, 3, 4]);
+
+    // 空范围，即纯插入
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2]).unwrap();
+    assert_eq!(vec.splice(1..1, [5, 6]).count(), 0);
+    assert_eq!(vec, [1, 5, 6, 2]);
+    vec.splice(4.., [7]);
+    assert_eq!(vec, [1, 5, 6, 2, 7]);
+
+    // 不迭代直接`drop`
+    let mut vec: InplaceVec<4, i32> = InplaceVec::try_from(&[1, 2, 3, 4]).unwrap();
+    drop(vec.splice(.., iter::empty()));
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn inplace_vec_splice_overflow() {
+    let log = DropLog::new();
+    let mut vec: InplaceVec<4, DropTracker> = InplaceVec::new();
+    for _ in 0..3 {
+        vec.push(log.track(0));
+    }
+
+    let replacement: Vec<_> = (0..4).map(|_| log.track(0)).collect();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        drop(vec.splice(1..2, replacement));
+    }));
+
+    assert!(result.is_err());
+    // 被移除的1个以及放不下的2个替换元素被`drop`
+    assert_eq!(log.dropped(), 3);
+    assert_eq!(vec.len(), 4);
+    drop(vec);
+    assert_eq!(log.dropped(), 7);
+}
+
+#[test]
+fn inplace_vec_insert_from_slice() {
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 5]).unwrap();
+    vec.insert_from_slice(1, &[2, 3, 4]);
+    assert_eq!(vec, [1, 2, 3, 4, 5]);
+    vec.insert_from_slice(5, &[]);
+    vec.insert_from_slice(0, &[0]);
+    // 长度恰好为`N`
+    assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic(expected = "cannot insert 2 elements into a vector of length 5 with capacity 6")]
+fn inplace_vec_insert_from_slice_overflow() {
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2, 3, 4, 5]).unwrap();
+    vec.insert_from_slice(1, &[0, 0]);
+}
+
+#[test]
+fn inplace_vec_extend_from_within() {
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2, 3]).unwrap();
+    vec.extend_from_within(1..);
+    assert_eq!(vec, [1, 2, 3, 2, 3]);
+    vec.extend_from_within(..1);
+    // 长度恰好为`N`
+    assert_eq!(vec, [1, 2, 3, 2, 3, 1]);
+}
+
+#[test]
+#[should_panic(expected = "cannot extend 3 elements onto a vector of length 4 with capacity 6")]
+fn inplace_vec_extend_from_within_overflow() {
+    let mut vec: InplaceVec<6, i32> = InplaceVec::try_from(&[1, 2, 3, 4]).unwrap();
+    vec.extend_from_within(1..);
+}
+
+/// 在第`n`次克隆时`panic`。
+#[derive(Debug, PartialEq)]
+struct PanicOnClone(i32, Rc<Cell<usize>>);
+
+impl Clone for PanicOnClone {
+    fn clone(&self) -> Self {
+        if self.1.get() == 0 {
+            panic!("clone panicked");
+        }
+        self.1.set(self.1.get() - 1);
+        PanicOnClone(self.0, self.1.clone())
+    }
+}
+
+#[test]
+fn inplace_vec_insert_from_slice_clone_panic() {
+    let budget = Rc::new(Cell::new(usize::MAX));
+    let item = |x| PanicOnClone(x, budget.clone());
+
+    let mut vec: InplaceVec<8, PanicOnClone> = InplaceVec::new();
+    vec.push(item(1));
+    vec.push(item(9));
+    let slice = [item(2), item(3), item(4)];
+
+    budget.set(2);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.insert_from_slice(1, &slice);
+    }));
+    assert!(result.is_err());
+    // 已经克隆的元素被保留，尾部被移回到它们后面
+    assert_eq!(vec.iter().map(|x| x.0).collect::<Vec<_>>(), [1, 2, 3, 9]);
+
+    budget.set(1);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.extend_from_within(..3);
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec.iter().map(|x| x.0).collect::<Vec<_>>(), [1, 2, 3, 9, 1]);
+}
+
+#[test]
+fn inplace_vec_into_capacity() {
+    // 扩大容量
+    let vec: InplaceVec<2, String> =
+        InplaceVec::try_from(&["a".to_string(), "b".to_string()]).unwrap();
+    let mut grown: InplaceVec<4, String> = vec.into_capacity().unwrap();
+    grown.push("c".to_string());
+    assert_eq!(grown, ["a", "b", "c"].map(String::from));
+
+    // 缩小容量，且能容纳下
+    let shrunk: InplaceVec<3, String> = grown.into_capacity().unwrap();
+    assert_eq!(shrunk, ["a", "b", "c"].map(String::from));
+
+    // 缩小容量，但容纳不下
+    let err = shrunk.into_capacity::<2>().unwrap_err();
+    assert_eq!(err, ["a", "b", "c"].map(String::from));
+
+    let empty: InplaceVec<4, i32> = InplaceVec::new();
+    assert!(empty.into_capacity::<0>().unwrap().is_empty());
+}
+
+#[test]
+fn inplace_vec_grow_capacity() {
+    let log = DropLog::new();
+    let mut vec: InplaceVec<2, DropTracker> = InplaceVec::new();
+    vec.push(log.track(0));
+    vec.push(log.track(0));
+
+    let grown: InplaceVec<8, DropTracker> = vec.grow_capacity();
+    assert_eq!(grown.len(), 2);
+    assert_eq!(grown.capacity(), 8);
+    assert_eq!(log.dropped(), 0);
+    drop(grown);
+    assert_eq!(log.dropped(), 2);
+}
+
+#[test]
+fn inplace_vec_try_from_slice() {
+    // 恰好容纳
+    let vec = InplaceVec::<3, i32>::try_from(&[1, 2, 3][..]).unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+
+    // 少于容量
+    let vec = InplaceVec::<3, i32>::try_from(&mut [1, 2][..]).unwrap();
+    assert_eq!(vec, [1, 2]);
+
+    // 超过容量
+    let err = InplaceVec::<3, i32>::try_from(&[1, 2, 3, 4][..]).unwrap_err();
+    assert_eq!((err.required(), err.capacity()), (4, 3));
+
+    let vec = InplaceVec::<4, i32>::try_from(&[1, 2, 3]).unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+    let err = InplaceVec::<2, i32>::try_from(&mut [1, 2, 3]).unwrap_err();
+    assert_eq!(err, CapacityError::new((), 3, 2));
+    assert_eq!(
+        err.to_string(),
+        "InplaceVec overflow: required capacity 3, but only 2 available"
+    );
+}
+
+#[test]
+fn inplace_vec_macro() {
+    let vec: InplaceVec<4, i32> = inplace_vec![1, 2, 3];
+    assert_eq!(vec, [1, 2, 3]);
+
+    let vec: InplaceVec<3, i32> = inplace_vec![1, 2, 3,];
+    assert_eq!(vec, [1, 2, 3]);
+
+    let vec: InplaceVec<2, String> = inplace_vec![];
+    assert!(vec.is_empty());
+
+    let vec = inplace_vec![cap 8; 1u8, 2, 3];
+    assert_eq!(vec.capacity(), 8);
+    assert_eq!(vec, [1, 2, 3]);
+
+    let vec: InplaceVec<6, String> = inplace_vec!["a".to_string(); 3];
+    assert_eq!(vec, ["a", "a", "a"].map(String::from));
+
+    // 在函数参数中推断容量
+    fn sum(vec: InplaceVec<5, i32>) -> i32 {
+        vec.iter().sum()
+    }
+    assert_eq!(sum(inplace_vec![1, 2, 3]), 6);
+}
+
+#[test]
+fn inplace_vec_macro_const() {
+    const VEC: InplaceVec<4, u8> = inplace_vec![cap 4; 1, 2];
+    static STATIC_VEC: InplaceVec<3, i32> = inplace_vec![7, 8, 9];
+    assert_eq!(VEC, [1, 2]);
+    assert_eq!(STATIC_VEC, [7, 8, 9]);
+}
+
+#[test]
+fn inplace_vec_cross_type_eq() {
+    let inplace: InplaceVec<4, i32> = inplace_vec![1, 2, 3];
+    let my_vec = my_vec![1, 2, 3];
+    let std_vec = vec![1, 2, 3];
+
+    assert_eq!(inplace, my_vec);
+    assert_eq!(my_vec, inplace);
+    assert_eq!(inplace, std_vec);
+    assert_eq!(std_vec, inplace);
+
+    let other: InplaceVec<4, i32> = inplace_vec![1, 2];
+    assert_ne!(other, my_vec);
+    assert_ne!(my_vec, other);
+    assert_ne!(other, std_vec);
+    assert_ne!(std_vec, other);
+
+    // 不同元素类型之间的比较
+    let strings: InplaceVec<2, String> = inplace_vec!["a".to_string(), "b".to_string()];
+    assert_eq!(strings, vec!["a", "b"]);
+    assert_eq!(
+        vec!["a", "b"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>(),
+        strings
+    );
+    let strs: MyVec<&str> = my_vec!["a", "b"];
+    assert_eq!(strings, strs);
+}
+
+#[test]
+fn inplace_vec_partial_ord_slices() {
+    use Ordering::*;
+
+    let cases: [(&[i32], &[i32], Ordering); 7] = [
+        (&[], &[], Equal),
+        (&[], &[0], Less),
+        (&[2], &[1, 2, 3], Greater),
+        (&[4, 5], &[4, 5, 6], Less),
+        (&[4, 5, 6], &[4, 5], Greater),
+        (&[4, 5, 6], &[4, 6], Less),
+        (&[4, 5, 6], &[4, 5, 6], Equal),
+    ];
+    for (lhs, rhs, expected) in cases {
+        let vec = InplaceVec::<4, i32>::try_from(lhs).unwrap();
+        // `InplaceVec`与`[T]`、`&[T]`比较，以及反过来比较
+        assert_eq!(vec.partial_cmp(rhs), Some(expected), "{lhs:?} vs {rhs:?}");
+        assert_eq!(vec.partial_cmp(&rhs), Some(expected));
+        assert_eq!(rhs.partial_cmp(&vec), Some(expected.reverse()));
+        assert_eq!((*rhs).partial_cmp(&vec), Some(expected.reverse()));
+    }
+
+    // 与数组以及数组的引用比较
+    let vec: InplaceVec<4, i32> = inplace_vec![1, 2, 3];
+    assert!(vec < [1, 2, 4]);
+    assert!(vec > [1, 2]);
+    assert!(vec >= [1, 2, 3][..]);
+    assert!([1, 2] < vec);
+    assert!([1, 2, 3] == vec);
+    assert_eq!(vec.partial_cmp(&[1, 2, 3]), Some(Equal));
+    assert_eq!(vec.partial_cmp(&&[1, 2, 3]), Some(Equal));
+    assert_eq!(PartialOrd::partial_cmp(&&[2], &vec), Some(Greater));
+}
+
+#[test]
+fn inplace_vec_io_write() {
+    use std::io::{ErrorKind, Write};
+
+    // 恰好写满
+    let mut buf: InplaceVec<5, u8> = InplaceVec::new();
+    assert_eq!(buf.write(b"hello").unwrap(), 5);
+    assert_eq!(buf, *b"hello");
+    assert_eq!(buf.write(b"!").unwrap(), 0);
+    buf.flush().unwrap();
+
+    // 短写入
+    let mut buf: InplaceVec<4, u8> = InplaceVec::new();
+    assert_eq!(buf.write(b"ab").unwrap(), 2);
+    assert_eq!(buf.write(b"cdef").unwrap(), 2);
+    assert_eq!(buf, *b"abcd");
+
+    let mut buf: InplaceVec<4, u8> = InplaceVec::new();
+    let err = buf.write_all(b"abcdef").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(buf, *b"abcd");
+
+    let mut buf: InplaceVec<4, u8> = InplaceVec::new();
+    write!(buf, "{}-{}", 1, 2).unwrap();
+    assert_eq!(buf, *b"1-2");
+}
+
+#[test]
+fn inplace_vec_fmt_write() {
+    use std::fmt::Write;
+
+    let mut buf: InplaceVec<8, u8> = InplaceVec::new();
+    let number = 42;
+    let text = "ab";
+    write!(buf, "{number:>4}|{text}").unwrap();
+    assert_eq!(buf, *b"  42|ab");
+
+    // 超过容量时返回错误，且不会写入被截断的片段
+    let mut buf: InplaceVec<8, u8> = InplaceVec::new();
+    let (hello, world) = ("hello", "world");
+    assert!(write!(buf, "{hello}, {world}").is_err());
+    assert_eq!(buf, *b"hello, ");
+}
+
+#[test]
+fn inplace_vec_filled() {
+    let vec = InplaceVec::<64, u8>::filled(0);
+    assert_eq!(vec.len(), 64);
+    assert!(vec.iter().all(|&x| x == 0));
+
+    let vec = InplaceVec::<3, String>::filled("ab".to_string());
+    assert_eq!(vec, ["ab", "ab", "ab"].map(String::from));
+
+    let vec = InplaceVec::<0, String>::filled("ab".to_string());
+    assert!(vec.is_empty());
+
+    // 只克隆`N - 1`次
+    let budget = Rc::new(Cell::new(2));
+    let vec = InplaceVec::<3, _>::filled(PanicOnClone(7, budget.clone()));
+    assert_eq!(vec.len(), 3);
+    assert_eq!(budget.get(), 0);
+}
+
+#[test]
+fn inplace_vec_filled_clone_panic() {
+    let log = DropLog::new();
+    let budget = Rc::new(Cell::new(2));
+
+    #[derive(Debug)]
+    struct Item(DropTracker, PanicOnClone);
+
+    impl Clone for Item {
+        fn clone(&self) -> Self {
+            let inner = self.1.clone();
+            Item(self.0.clone(), inner)
+        }
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        InplaceVec::<5, _>::filled(Item(log.track(0), PanicOnClone(0, budget.clone())))
+    }));
+    assert!(result.is_err());
+    // 两个克隆以及原始的值都被`drop`
+    assert_eq!(log.dropped(), 3);
+}
+
+#[test]
+fn inplace_vec_full_with() {
+    let table = [10, 20, 30, 40];
+    let vec = InplaceVec::<4, i32>::full_with(|i| table[i]);
+    assert_eq!(vec, table);
+
+    let vec = InplaceVec::<3, String>::full_with(|i| i.to_string());
+    assert_eq!(vec, ["0", "1", "2"].map(String::from));
+
+    let log = DropLog::new();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        InplaceVec::<4, _>::full_with(|i| {
+            if i == 2 {
+                panic!("full_with panicked");
+            }
+            log.track(0)
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(log.dropped(), 2);
+}
+
+#[test]
+fn inplace_vec_append() {
+    let mut a: InplaceVec<5, String> = inplace_vec!["a".to_string(), "b".to_string()];
+    let mut b: InplaceVec<3, String> = inplace_vec!["c".to_string(), "d".to_string()];
+    a.append(&mut b);
+    assert_eq!(a, ["a", "b", "c", "d"].map(String::from));
+    assert!(b.is_empty());
+
+    // 恰好填满，长度的类型可以不同
+    let mut c: InplaceVec<1, String, u8> = inplace_vec!["e".to_string()];
+    a.try_append(&mut c).unwrap();
+    assert_eq!(a, ["a", "b", "c", "d", "e"].map(String::from));
+    assert!(c.is_empty());
+
+    // 追加空的`InplaceVec`总是成功的
+    let mut empty: InplaceVec<0, String> = InplaceVec::new();
+    a.append(&mut empty);
+    assert_eq!(a.len(), 5);
+}
+
+#[test]
+fn inplace_vec_try_append_overflow() {
+    let log = DropLog::new();
+    let mut a: InplaceVec<3, DropTracker> = InplaceVec::new();
+    let mut b: InplaceVec<3, DropTracker> = InplaceVec::new();
+    for _ in 0..2 {
+        a.push(log.track(0));
+        b.push(log.track(0));
+    }
+
+    let err = a.try_append(&mut b).unwrap_err();
+    assert_eq!(err, CapacityError::new((), 4, 3));
+    assert_eq!(a.len(), 2);
+    assert_eq!(b.len(), 2);
+    assert_eq!(log.dropped(), 0);
+
+    drop(a);
+    drop(b);
+    assert_eq!(log.dropped(), 4);
+}
+
+#[test]
+#[should_panic(expected = "required capacity 4, but only 3 available")]
+fn inplace_vec_append_overflow() {
+    let mut a: InplaceVec<3, i32> = inplace_vec![1, 2];
+    let mut b: InplaceVec<2, i32> = inplace_vec![3, 4];
+    a.append(&mut b);
+}
+
+#[test]
+fn inplace_vec_binary_insert_random() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..50 {
+        let mut vec: InplaceVec<32, u64> = InplaceVec::new();
+        for _ in 0..32 {
+            let value = rng.next_u64() % 16;
+            let index = vec.binary_insert(value).unwrap();
+            assert_eq!(vec[index], value);
+            assert!(vec.is_sorted());
+        }
+        assert_eq!(vec.binary_insert(0).unwrap_err().into_element(), 0);
+        assert_eq!(vec.len(), 32);
+    }
+}
+
+#[test]
+fn inplace_vec_binary_insert_stable() {
+    let mut vec: InplaceVec<8, (i32, &str)> = InplaceVec::new();
+    vec.binary_insert_by_key((2, "a"), |x| x.0).unwrap();
+    vec.binary_insert_by_key((1, "b"), |x| x.0).unwrap();
+    // 相等的键插入到已有元素的后面
+    assert_eq!(vec.binary_insert_by_key((2, "c"), |x| x.0), Ok(2));
+    assert_eq!(vec.binary_insert_by_key((1, "d"), |x| x.0), Ok(1));
+    assert_eq!(vec.binary_insert_by((2, "e"), |a, b| a.0.cmp(&b.0)), Ok(4));
+    assert_eq!(vec, [(1, "b"), (1, "d"), (2, "a"), (2, "c"), (2, "e")]);
+}
+
+#[test]
+fn inplace_vec_len_type_size() {
+    use std::mem::size_of;
+
+    assert_eq!(size_of::<InplaceVec<4, u8>>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<InplaceVec<4, u8, u8>>(), 5);
+    assert_eq!(size_of::<InplaceVec<255, u8, u8>>(), 256);
+    assert_eq!(size_of::<InplaceVec<256, u8, u16>>(), 258);
+    assert_eq!(size_of::<InplaceVec<3, u16, u16>>(), 8);
+    // 长度字段无法减小元素对齐带来的填充
+    assert_eq!(size_of::<InplaceVec<3, u32, u8>>(), 16);
+    assert_eq!(size_of::<InplaceVec<0, u64, u8>>(), 8);
+}
+
+#[test]
+fn inplace_vec_len_type_full() {
+    let mut vec: InplaceVec<255, u8, u8> = InplaceVec::new();
+    for i in 0..=254 {
+        vec.push(i);
+    }
+    assert_eq!(vec.len(), 255);
+    assert_eq!(vec.len(), vec.capacity());
+
+    let drained: Vec<_> = vec.drain(10..250).collect();
+    assert_eq!(drained.len(), 240);
+    assert_eq!(vec.len(), 15);
+    assert_eq!(vec.extract_if(|x| *x % 2 == 1).count(), 7);
+    assert_eq!(vec, [0, 2, 4, 6, 8, 250, 252, 254]);
+
+    let mut vec: InplaceVec<300, u16, u16> = (0..300).collect();
+    vec.splice(1..299, iter::empty());
+    assert_eq!(vec, [0, 299]);
+    assert_eq!(vec.into_iter().sum::<u16>(), 299);
+}
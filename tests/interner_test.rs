@@ -0,0 +1,96 @@
+mod common;
+
+use std::collections::HashMap;
+
+use rust_practice::collection::interner::{Interner, Symbol};
+use rust_practice::collection::rng::{RngCore, XorShift64};
+
+fn random_string(rng: &mut XorShift64) -> String {
+    let len = (rng.next_u64() % 6) as usize;
+    // 字母表较小以产生大量重复，同时包含多字节字符
+    let alphabet = ['a', 'b', 'c', 'é', '中'];
+    (0..len)
+        .map(|_| alphabet[(rng.next_u64() % alphabet.len() as u64) as usize])
+        .collect()
+}
+
+#[test]
+fn interner_random_strings() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    let mut interner = Interner::new();
+    let mut model: HashMap<String, Symbol> = HashMap::new();
+    let mut expected_bytes = 0;
+
+    for _ in 0..5000 {
+        let s = random_string(&mut rng);
+        let symbol = interner.intern(&s);
+        match model.get(&s) {
+            Some(&old) => assert_eq!(symbol, old),
+            None => {
+                assert_eq!(symbol.as_usize(), model.len());
+                expected_bytes += s.len();
+                model.insert(s, symbol);
+            }
+        }
+        assert_eq!(interner.byte_len(), expected_bytes);
+    }
+
+    // 缓冲区多次增长之后，所有符号仍然解析为原来的字符串
+    assert_eq!(interner.len(), model.len());
+    for (s, &symbol) in &model {
+        assert_eq!(interner.resolve(symbol), s);
+        assert_eq!(interner.get(s), Some(symbol));
+    }
+    assert_eq!(interner.get("zzz"), None);
+}
+
+#[test]
+fn interner_dedup_does_not_grow() {
+    let mut interner = Interner::new();
+    let a = interner.intern("hello");
+    let empty = interner.intern("");
+    let bytes = interner.byte_len();
+    for _ in 0..100 {
+        assert_eq!(interner.intern("hello"), a);
+        assert_eq!(interner.intern(&(String::from("hel") + "lo")), a);
+        assert_eq!(interner.intern(""), empty);
+    }
+    assert_eq!(interner.byte_len(), bytes);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.resolve(empty), "");
+}
+
+#[test]
+fn interner_symbol_semantics() {
+    let mut interner: Interner = ["b", "a", "c"].into_iter().collect();
+    let b = interner.get("b").unwrap();
+    let a = interner.get("a").unwrap();
+    let c = interner.intern("c");
+
+    // 符号按插入顺序编号，而不是按字典序
+    assert!(b < a && a < c);
+    assert_eq!((b.as_u32(), a.as_u32(), c.as_u32()), (0, 1, 2));
+    assert_ne!(a, b);
+    assert!(interner.contains("a"));
+
+    let other = Interner::new();
+    assert_eq!(other.try_resolve(a), None);
+    assert_eq!(interner.try_resolve(c), Some("c"));
+}
+
+#[test]
+fn interner_iter() {
+    let mut interner = Interner::new();
+    interner.extend(["x", "y", "x", "z"]);
+    let all: Vec<_> = interner.iter().map(|(_, s)| s).collect();
+    assert_eq!(all, ["x", "y", "z"]);
+
+    let mut iter = interner.iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some((interner.get("z").unwrap(), "z")));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(
+        format!("{interner:?}"),
+        r#"{Symbol(0): "x", Symbol(1): "y", Symbol(2): "z"}"#
+    );
+}
@@ -0,0 +1,162 @@
+//! 在`clone`或`drop`中注入`panic`，检查unwind之后容器仍然是合法的：
+//! 长度不超过容量，所有可访问的元素都可以正常使用，并且在容器被`drop`
+//! 之后，构造的次数与drop的次数相等。
+//!
+//! `resize`尚未实现，因此没有对应的用例。
+
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::collection::vec::MyVec;
+
+fn assert_panics<R>(f: impl FnOnce() -> R) {
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    assert!(result.is_err(), "expected a panic");
+}
+
+/// 检查容器的结构，并返回其中元素的编号。
+fn ids(slice: &[DropTracker], len: usize, cap: usize) -> Vec<usize> {
+    assert_eq!(slice.len(), len);
+    assert!(len <= cap);
+    slice.iter().map(DropTracker::id).collect()
+}
+
+fn my_vec(log: &DropLog, n: usize) -> MyVec<DropTracker> {
+    log.track_n(n).collect()
+}
+
+fn inplace_vec(log: &DropLog, n: usize) -> InplaceVec<8, DropTracker> {
+    log.track_n(n).collect()
+}
+
+#[test]
+fn clone_panics_during_extend_and_clone() {
+    let log = DropLog::new();
+    let src = my_vec(&log, 4);
+
+    let mut vec = my_vec(&log, 2);
+    log.panic_on_clone(3);
+    assert_panics(|| vec.extend_from_slice(&src));
+    assert_eq!(ids(&vec, vec.len(), vec.capacity()), [0, 1, 0, 1]);
+
+    log.panic_on_clone(2);
+    assert_panics(|| vec.extend(src.iter()));
+    assert_eq!(ids(&vec, vec.len(), vec.capacity()), [0, 1, 0, 1, 0]);
+
+    // 已经克隆出来的元素随着新的`MyVec`被`drop`，不会泄漏
+    log.panic_on_clone(3);
+    assert_panics(|| src.clone());
+    assert_eq!(log.alive(), 9);
+
+    log.panic_on_clone(2);
+    assert_panics(|| vec.clone_from(&src));
+    assert_eq!(ids(&vec, vec.len(), vec.capacity()), [0]);
+
+    drop((src, vec));
+    assert_eq!(log.alive(), 0);
+
+    let log = DropLog::new();
+    let src = inplace_vec(&log, 4);
+    let mut vec = inplace_vec(&log, 2);
+    log.panic_on_clone(3);
+    assert_panics(|| vec.extend_from_slice(&src));
+    assert_eq!(ids(&vec, vec.len(), 8), [0, 1, 0, 1]);
+
+    log.panic_on_clone(4);
+    assert_panics(|| src.clone());
+    log.panic_on_clone(1);
+    assert_panics(|| vec.clone_from(&src));
+    assert!(vec.is_empty());
+
+    drop((src, vec));
+    assert_eq!(log.alive(), 0);
+}
+
+#[test]
+fn insert_and_retain_unwind() {
+    // 插入失败时，传入的元素被`drop`，容器保持不变
+    let log = DropLog::new();
+    let mut vec = my_vec(&log, 3);
+    assert_panics(|| vec.insert(4, log.track(10)));
+    assert_eq!(log.order(), [10]);
+    assert_eq!(ids(&vec, 3, vec.capacity()), [0, 1, 2]);
+
+    let mut full = inplace_vec(&log, 8);
+    assert_panics(|| full.insert(0, log.track(11)));
+    assert_eq!(log.order(), [10, 11]);
+    assert_eq!(ids(&full, 8, 8), [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // `retain`的谓词`panic`
+    vec.extend(log.track_n(6).skip(3));
+    assert_panics(|| {
+        vec.retain(|t| {
+            assert_ne!(t.id(), 3);
+            t.id() % 2 == 0
+        })
+    });
+    assert_eq!(ids(&vec, vec.len(), vec.capacity()), [0, 2, 3, 4, 5]);
+
+    // 被移除的元素在`drop`时`panic`
+    log.panic_on_drop(1);
+    assert_panics(|| vec.retain(|t| t.id() != 2));
+    assert_eq!(ids(&vec, vec.len(), vec.capacity()), [0, 3, 4, 5]);
+
+    drop((vec, full));
+    assert_eq!(log.alive(), 0);
+}
+
+#[test]
+fn drain_with_panicking_destructor() {
+    let log = DropLog::new();
+    let mut vec = my_vec(&log, 6);
+    log.panic_on_drop(1);
+    assert_panics(|| drop(vec.drain(1..4)));
+    // 剩余的元素仍然被`drop`，尾部元素被移回来
+    assert_eq!(log.order(), [1, 2, 3]);
+    assert_eq!(ids(&vec, 3, vec.capacity()), [0, 4, 5]);
+
+    let mut drain = vec.drain(..);
+    drain.next();
+    log.panic_on_drop(1);
+    assert_panics(|| drop(drain));
+    assert_eq!(log.order(), [1, 2, 3, 0, 4, 5]);
+    assert!(vec.is_empty());
+
+    let log = DropLog::new();
+    let mut vec = inplace_vec(&log, 6);
+    log.panic_on_drop(2);
+    assert_panics(|| drop(vec.drain(1..4)));
+    assert_eq!(log.order(), [1, 2, 3]);
+    assert_eq!(ids(&vec, 3, 8), [0, 4, 5]);
+
+    drop(vec);
+    assert_eq!(log.alive(), 0);
+}
+
+#[test]
+fn into_iter_with_panicking_destructor() {
+    let log = DropLog::new();
+    let mut iter = my_vec(&log, 5).into_iter();
+    assert_eq!(iter.next().map(|t| t.id()), Some(0));
+    log.panic_on_drop(2);
+    assert_panics(|| drop(iter));
+    assert_eq!(log.order(), [0, 1, 2, 3, 4]);
+    assert_eq!(log.alive(), 0);
+
+    let log = DropLog::new();
+    let iter = inplace_vec(&log, 5).into_iter();
+    log.panic_on_drop(1);
+    assert_panics(|| drop(iter));
+    assert_eq!(log.order(), [0, 1, 2, 3, 4]);
+    assert_eq!(log.alive(), 0);
+
+    // `MyVec`本身被`drop`时同理
+    let log = DropLog::new();
+    let vec = my_vec(&log, 4);
+    log.panic_on_drop(3);
+    assert_panics(|| drop(vec));
+    assert_eq!(log.alive(), 0);
+}
@@ -0,0 +1,148 @@
+use std::ptr;
+
+use rust_practice::collection::raw::{
+    RawBuf, TryReserveError, amortized_capacity, checked_alloc_size,
+};
+use rust_practice::collection::vec::MyVec;
+
+#[test]
+fn raw_buf_reserve() {
+    let mut buf = RawBuf::<u32>::new();
+    assert_eq!(buf.cap(), 0);
+
+    // 均摊增长：至少翻倍
+    buf.reserve(0, 3);
+    assert_eq!(buf.cap(), 3);
+    buf.reserve(3, 1);
+    assert_eq!(buf.cap(), 6);
+    buf.reserve(6, 100);
+    assert_eq!(buf.cap(), 106);
+
+    // 容量已经足够时什么也不做
+    let ptr = buf.ptr();
+    buf.reserve(100, 6);
+    buf.reserve_exact(0, 106);
+    assert_eq!((buf.ptr(), buf.cap()), (ptr, 106));
+
+    buf.reserve_exact(106, 1);
+    assert_eq!(buf.cap(), 107);
+}
+
+#[test]
+fn raw_buf_keeps_contents_when_resizing() {
+    let mut buf = RawBuf::<String>::with_capacity(2);
+    unsafe {
+        ptr::write(buf.ptr().as_ptr(), "a".to_string());
+        ptr::write(buf.ptr().as_ptr().add(1), "b".to_string());
+    }
+
+    buf.reserve(2, 10);
+    assert!(buf.cap() >= 12);
+    buf.shrink(2);
+    assert_eq!(buf.cap(), 2);
+
+    let (ptr, cap) = buf.into_parts();
+    let buf = unsafe { RawBuf::from_parts(ptr, cap) };
+    unsafe {
+        assert_eq!(ptr::read(buf.ptr().as_ptr()), "a");
+        assert_eq!(ptr::read(buf.ptr().as_ptr().add(1)), "b");
+    }
+
+    let mut empty = buf;
+    empty.shrink(0);
+    assert_eq!(empty.cap(), 0);
+}
+
+#[test]
+fn raw_buf_capacity_limits() {
+    assert_eq!(
+        RawBuf::<u16>::try_with_capacity(isize::MAX as usize).unwrap_err(),
+        TryReserveError::CapacityOverflow
+    );
+    assert_eq!(RawBuf::<u16>::try_with_capacity(0).unwrap().cap(), 0);
+
+    // ZST从不分配内存
+    let mut zst = RawBuf::<()>::try_with_capacity(usize::MAX).unwrap();
+    assert_eq!(zst.cap(), isize::MAX as usize);
+    zst.reserve(1000, 1000);
+    zst.shrink(0);
+    assert_eq!(zst.cap(), isize::MAX as usize);
+
+    let err = std::panic::catch_unwind(|| RawBuf::<u8>::new().reserve(usize::MAX, 1));
+    assert!(err.is_err());
+}
+
+/// 32位平台上`isize::MAX`为`i32::MAX`，在64位平台上通过传入这一上限模拟。
+const MAX_BYTES_32: usize = i32::MAX as usize;
+
+#[test]
+fn capacity_helpers_with_simulated_32_bit_limit() {
+    for elem_size in [1, 2, 4, 12, 4096] {
+        let max_cap = MAX_BYTES_32 / elem_size;
+        assert_eq!(
+            checked_alloc_size(elem_size, max_cap, MAX_BYTES_32),
+            Some(max_cap * elem_size)
+        );
+        assert_eq!(
+            checked_alloc_size(elem_size, max_cap + 1, MAX_BYTES_32),
+            None
+        );
+
+        // 接近上限时`2 * cap`无法分配，但仍然可以增长到最大容量
+        let cap = max_cap / 2 + 1;
+        assert_eq!(
+            amortized_capacity(elem_size, cap, cap + 1, MAX_BYTES_32),
+            Some(max_cap)
+        );
+        assert_eq!(
+            amortized_capacity(elem_size, max_cap, max_cap + 1, MAX_BYTES_32),
+            None
+        );
+    }
+
+    // 乘法溢出，以及ZST的元素个数同样不能超过`isize::MAX`
+    assert_eq!(checked_alloc_size(usize::MAX, 2, usize::MAX), None);
+    assert_eq!(
+        checked_alloc_size(0, isize::MAX as usize, MAX_BYTES_32),
+        Some(0)
+    );
+    assert_eq!(
+        checked_alloc_size(0, isize::MAX as usize + 1, MAX_BYTES_32),
+        None
+    );
+    assert_eq!(amortized_capacity(0, 8, 9, MAX_BYTES_32), Some(16));
+}
+
+#[test]
+fn capacity_helpers_on_host() {
+    let max = isize::MAX as usize;
+    assert_eq!(checked_alloc_size(8, max / 8, max), Some(max / 8 * 8));
+    assert_eq!(checked_alloc_size(8, max / 8 + 1, max), None);
+    assert_eq!(amortized_capacity(8, 0, 1, max), Some(1));
+    assert_eq!(amortized_capacity(8, 5, 6, max), Some(10));
+    assert_eq!(amortized_capacity(8, 5, 100, max), Some(100));
+
+    assert_eq!(
+        RawBuf::<u64>::try_with_capacity(max / 8 + 1).unwrap_err(),
+        TryReserveError::CapacityOverflow
+    );
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn reserve_panics_when_bytes_overflow() {
+    // 元素个数不超过`isize::MAX`，但字节数超过了
+    MyVec::<u32>::new().reserve(isize::MAX as usize / 2);
+}
+
+#[cfg(target_pointer_width = "32")]
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn reserve_past_byte_limit_on_32_bit() {
+    let max_cap = isize::MAX as usize / 4;
+    assert_eq!(
+        RawBuf::<u32>::try_with_capacity(max_cap + 1).unwrap_err(),
+        TryReserveError::CapacityOverflow
+    );
+    RawBuf::<u32>::new().reserve(max_cap, 1);
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::DropLog;
+use rust_practice::collection::rc::MyRc;
+
+/// 如果`MyRc<T>`在`T`上不是协变的，则无法通过编译。
+#[allow(dead_code)]
+fn assert_covariant<'a>(rc: MyRc<&'static str>) -> MyRc<&'a str> {
+    rc
+}
+
+#[test]
+fn rc_clone_and_count() {
+    let a = MyRc::new(String::from("shared"));
+    let b = a.clone();
+    let c = MyRc::clone(&b);
+    assert_eq!(MyRc::strong_count(&a), 3);
+    assert!(MyRc::ptr_eq(&a, &c));
+    assert!(!MyRc::ptr_eq(&a, &MyRc::new(String::from("shared"))));
+    assert_eq!(a, MyRc::new(String::from("shared")));
+    assert_eq!(format!("{c} {c:?}"), "shared \"shared\"");
+    assert_eq!(c.len(), 6);
+
+    drop(b);
+    assert_eq!(MyRc::strong_count(&a), 2);
+    drop(c);
+    assert_eq!(MyRc::strong_count(&a), 1);
+}
+
+#[test]
+fn rc_get_mut_and_try_unwrap() {
+    let mut a = MyRc::new(vec![1, 2]);
+    MyRc::get_mut(&mut a).unwrap().push(3);
+
+    let b = a.clone();
+    assert!(MyRc::get_mut(&mut a).is_none());
+    let a = MyRc::try_unwrap(a).unwrap_err();
+    assert_eq!(MyRc::strong_count(&b), 2);
+
+    drop(b);
+    assert_eq!(MyRc::try_unwrap(a).unwrap(), [1, 2, 3]);
+
+    let mut c = MyRc::new(vec![1]);
+    let d = c.clone();
+    MyRc::make_mut(&mut c).push(2);
+    assert!(!MyRc::ptr_eq(&c, &d));
+    assert_eq!((&*c, &*d), (&vec![1, 2], &vec![1]));
+
+    let zst = MyRc::new(());
+    let zst2 = zst.clone();
+    assert_eq!(MyRc::strong_count(&zst2), 2);
+    drop(zst);
+    assert_eq!(MyRc::try_unwrap(zst2), Ok(()));
+}
+
+#[test]
+fn rc_drops_once() {
+    let log = DropLog::new();
+    let a = MyRc::new(log.track(0));
+    let clones: Vec<_> = (0..10).map(|_| a.clone()).collect();
+    drop(a);
+    assert_eq!(log.dropped(), 0);
+    drop(clones);
+    assert_eq!(log.dropped(), 1);
+
+    // `try_unwrap`移出值之后不会再次`drop`
+    let a = MyRc::new(log.track(0));
+    let value = MyRc::try_unwrap(a).ok().unwrap();
+    assert_eq!(log.dropped(), 1);
+    drop(value);
+    assert_eq!(log.dropped(), 2);
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+use rkyv::util::AlignedVec;
+use rkyv::vec::ArchivedVec;
+use rkyv::{Archived, rend::u32_le};
+
+use rust_practice::collection::inplace_vec::{ArchivedInplaceVec, InplaceVec};
+use rust_practice::collection::vec::MyVec;
+use rust_practice::{inplace_vec, my_vec};
+
+/// 将归档复制到一个新的对齐缓冲区中，模拟从文件或网络读取的数据。
+fn realign(bytes: &[u8]) -> AlignedVec {
+    let mut aligned = AlignedVec::new();
+    aligned.extend_from_slice(bytes);
+    aligned
+}
+
+#[test]
+fn my_vec_rkyv_round_trip() {
+    let vec: MyVec<String> = my_vec!["alpha".to_string(), String::new(), "γ".to_string()];
+    let bytes = realign(&rkyv::to_bytes::<Error>(&vec).unwrap());
+
+    let archived = rkyv::access::<ArchivedVec<Archived<String>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 3);
+    assert_eq!(archived[2], "γ");
+
+    let back: MyVec<String> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(back, vec);
+
+    // 与标准库的`Vec`使用相同的归档格式
+    let std_vec: Vec<String> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(std_vec, vec.as_slice());
+
+    let empty: MyVec<u64> = MyVec::new();
+    let bytes = rkyv::to_bytes::<Error>(&empty).unwrap();
+    let back: MyVec<u64> = rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+    assert!(back.is_empty());
+}
+
+#[test]
+fn my_vec_rkyv_access_without_deserialize() {
+    let vec: MyVec<u32> = (0..1000).map(|x| x * 3).collect();
+    let bytes = realign(&rkyv::to_bytes::<Error>(&vec).unwrap());
+
+    let archived = rkyv::access::<ArchivedVec<u32_le>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 1000);
+    assert_eq!(archived[999], 2997);
+    assert!(
+        archived
+            .iter()
+            .map(|x| x.to_native())
+            .eq(vec.iter().copied())
+    );
+
+    // 截断的归档无法通过校验
+    assert!(rkyv::access::<ArchivedVec<u32_le>, Error>(&bytes[..bytes.len() - 4]).is_err());
+}
+
+#[test]
+fn inplace_vec_rkyv_round_trip() {
+    let vec: InplaceVec<4, String> = inplace_vec!["a".to_string(), "bc".to_string()];
+    let bytes = realign(&rkyv::to_bytes::<Error>(&vec).unwrap());
+
+    let archived = rkyv::access::<ArchivedInplaceVec<4, Archived<String>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 2);
+    assert_eq!(archived[1], "bc");
+
+    let back: InplaceVec<4, String> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(back, vec);
+
+    let full: InplaceVec<3, u16, u8> = inplace_vec![7, 8, 9];
+    let bytes = rkyv::to_bytes::<Error>(&full).unwrap();
+    let back: InplaceVec<3, u16, u8> = rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+    assert_eq!(back, [7, 8, 9]);
+}
+
+#[test]
+fn inplace_vec_rkyv_rejects_overlong_archive() {
+    // 容量更大的`InplaceVec`或者`MyVec`的归档格式相同，但长度超过了`N`
+    let long: MyVec<u32> = my_vec![1, 2, 3, 4, 5];
+    let bytes = realign(&rkyv::to_bytes::<Error>(&long).unwrap());
+
+    let err = rkyv::access::<ArchivedInplaceVec<4, u32_le>, Error>(&bytes).unwrap_err();
+    // rancor只在开启debug assertions时保留错误信息
+    if cfg!(debug_assertions) {
+        assert!(err.to_string().contains("required capacity 5"), "{err}");
+    }
+    assert!(rkyv::from_bytes::<InplaceVec<4, u32>, Error>(&bytes).is_err());
+
+    let fits = rkyv::from_bytes::<InplaceVec<5, u32>, Error>(&bytes).unwrap();
+    assert_eq!(fits, [1, 2, 3, 4, 5]);
+}
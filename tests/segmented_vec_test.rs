@@ -0,0 +1,105 @@
+mod common;
+
+use common::DropLog;
+use rust_practice::collection::segmented_vec::SegmentedVec;
+
+/// 保存早期元素的裸指针，在大量`push`之后解引用，验证元素没有被移动。
+#[test]
+fn segmented_vec_stable_addresses() {
+    let mut vec = SegmentedVec::with_chunk_size(8);
+    let mut ptrs = Vec::new();
+    for i in 0..20 {
+        let p: *const String = vec.push(i.to_string());
+        ptrs.push(p);
+    }
+    for i in 20..5000 {
+        vec.push(i.to_string());
+    }
+
+    for (i, &p) in ptrs.iter().enumerate() {
+        assert_eq!(unsafe { &*p }, &i.to_string());
+        assert!(std::ptr::eq(p, &vec[i]));
+    }
+    assert_eq!(vec.len(), 5000);
+    assert_eq!(vec.chunk_count(), 625);
+    assert!(vec.chunks().all(|c| c.len() == 8));
+}
+
+#[test]
+fn segmented_vec_get_and_iter() {
+    let mut vec: SegmentedVec<usize> = (0..100).collect();
+    assert_eq!(vec.chunk_size(), 64);
+    assert_eq!(vec.chunk_count(), 2);
+    assert_eq!(vec.get(63), Some(&63));
+    assert_eq!(vec.get(64), Some(&64));
+    assert_eq!(vec.get(100), None);
+    assert_eq!(vec.first(), Some(&0));
+    assert_eq!(vec.last(), Some(&99));
+
+    for x in &mut vec {
+        *x *= 2;
+    }
+    *vec.get_mut(1).unwrap() = 7;
+    vec[2] += 1;
+
+    let mut iter = vec.iter();
+    assert_eq!(iter.len(), 100);
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&198));
+    assert_eq!(iter.len(), 98);
+    assert_eq!(iter.clone().copied().take(2).collect::<Vec<_>>(), [7, 5]);
+    // 前后两端在分段边界处相遇
+    assert_eq!(iter.rev().nth(34), Some(&128));
+
+    let back: Vec<_> = vec.clone().into_iter().rev().take(3).collect();
+    assert_eq!(back, [198, 196, 194]);
+    assert_eq!(vec, vec.clone());
+}
+
+#[test]
+fn segmented_vec_pop_and_truncate() {
+    let mut vec = SegmentedVec::with_chunk_size(4);
+    let mut model = Vec::new();
+    for i in 0..30 {
+        vec.push(i);
+        model.push(i);
+    }
+
+    for len in [30, 29, 16, 13, 12, 4, 1, 0] {
+        vec.truncate(len);
+        model.truncate(len);
+        assert_eq!(vec.iter().copied().collect::<Vec<_>>(), model);
+        assert_eq!(vec.chunk_count(), len.div_ceil(4));
+        vec.push(100);
+        model.push(100);
+        assert_eq!(vec.pop(), model.pop());
+    }
+    assert_eq!(vec.pop(), None);
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 0);
+}
+
+#[test]
+fn segmented_vec_drops() {
+    let log = DropLog::new();
+    let mut vec = SegmentedVec::with_chunk_size(2);
+    for _ in 0..9 {
+        vec.push(log.track(0));
+    }
+
+    vec.truncate(5);
+    assert_eq!(log.dropped(), 4);
+    drop(vec.pop());
+    assert_eq!(log.dropped(), 5);
+
+    let mut iter = vec.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(log.dropped(), 7);
+    drop(iter);
+    assert_eq!(log.dropped(), 9);
+
+    let zst: SegmentedVec<()> = std::iter::repeat_n((), 10).collect();
+    assert_eq!(zst.len(), 10);
+    assert_eq!(zst.iter().count(), 10);
+}
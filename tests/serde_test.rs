@@ -0,0 +1,41 @@
+#![cfg(feature = "serde")]
+
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::inplace_vec;
+
+#[test]
+fn inplace_vec_json_round_trip() {
+    let vec: InplaceVec<4, String> = inplace_vec!["a".to_string(), "b".to_string()];
+    let json = serde_json::to_string(&vec).unwrap();
+    assert_eq!(json, r#"["a","b"]"#);
+
+    let back: InplaceVec<4, String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, vec);
+
+    // 恰好填满
+    let full: InplaceVec<3, i32> = serde_json::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(full, [1, 2, 3]);
+
+    let empty: InplaceVec<0, i32> = serde_json::from_str("[]").unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn inplace_vec_json_overflow() {
+    let err = serde_json::from_str::<InplaceVec<3, i32>>("[1, 2, 3, 4]").unwrap_err();
+    assert!(
+        err.to_string().contains("a sequence of at most 3 elements"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn inplace_vec_postcard_round_trip() {
+    let vec: InplaceVec<8, u32> = inplace_vec![1, 20, 300, 4000];
+    let bytes = postcard::to_allocvec(&vec).unwrap();
+    let back: InplaceVec<8, u32> = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(back, vec);
+
+    // postcard会提供长度提示，过长的序列会被提前拒绝
+    assert!(postcard::from_bytes::<InplaceVec<3, u32>>(&bytes).is_err());
+}
@@ -0,0 +1,113 @@
+use std::thread;
+
+use rust_practice::collection::shared_vec::SharedVec;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+#[test]
+fn shared_vec_copy_on_write() {
+    let a: SharedVec<String> = ["x", "y"].into_iter().map(String::from).collect();
+    let mut b = a.clone();
+    assert_eq!(a.ref_count(), 2);
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_eq!(a, b);
+
+    // 被共享时修改，缓冲区被复制
+    b.as_mut_slice()[0].push('!');
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert!(a.is_unique() && b.is_unique());
+    assert_eq!(*a, ["x", "y"].map(String::from));
+    assert_eq!(*b, ["x!", "y"].map(String::from));
+
+    let mut c = b.clone();
+    assert!(c.get_mut().is_none());
+    c.insert(0, String::from("w"));
+    assert_eq!(c.remove(1), "x!");
+    assert_eq!(b.len(), 2);
+    assert_eq!(*c, ["w", "y"].map(String::from));
+}
+
+#[test]
+fn shared_vec_unique_does_not_copy() {
+    let mut vec = MyVec::with_capacity(8);
+    vec.extend([1, 2, 3]);
+    let ptr = vec.as_ptr();
+
+    let mut shared = SharedVec::from(vec);
+    assert_eq!(shared.as_ptr(), ptr);
+    shared.push(4);
+    shared.as_mut_slice()[0] = 10;
+    assert_eq!(shared.pop(), Some(4));
+    assert_eq!(shared.as_ptr(), ptr);
+
+    // 其他克隆被`drop`之后重新成为唯一的引用
+    let other = shared.clone();
+    drop(other);
+    shared.extend([5]);
+    assert_eq!(shared.as_ptr(), ptr);
+
+    let vec = shared.into_my_vec();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, [10, 2, 3, 5]);
+}
+
+#[test]
+fn shared_vec_into_and_clear() {
+    let a = SharedVec::from(my_vec![1, 2, 3]);
+    let b = a.clone();
+    // 被共享时`into_my_vec`会复制
+    let vec = b.into_my_vec();
+    assert_ne!(vec.as_ptr(), a.as_ptr());
+    assert_eq!(vec, [1, 2, 3]);
+
+    let mut c = a.clone();
+    c.clear();
+    assert!(c.is_empty());
+    assert_eq!(*a, [1, 2, 3]);
+    c.truncate(0);
+    assert_eq!(a.clone().into_iter().sum::<i32>(), 6);
+    assert_eq!(format!("{a:?}"), "[1, 2, 3]");
+}
+
+#[test]
+fn shared_vec_eq_compares_elements() {
+    let a = SharedVec::from(my_vec![f64::NAN]);
+    let b = a.clone();
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    // 即使共享同一个缓冲区，`NaN`也不等于自身
+    assert_ne!(a, b);
+
+    let c = SharedVec::from(my_vec![1.0, 2.0]);
+    assert_eq!(c, c.clone());
+    assert_eq!(c, SharedVec::from(my_vec![1.0, 2.0]));
+}
+
+#[test]
+fn shared_vec_threads_stress() {
+    let table: SharedVec<u64> = (0..10_000).collect();
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            let local = table.clone();
+            thread::spawn(move || {
+                let mut sum = 0;
+                for _ in 0..100 {
+                    let copy = local.clone();
+                    sum += copy[t * 100];
+                }
+                // 修改自己的克隆，不影响其他线程
+                let mut mine = local.clone();
+                mine.push(t as u64);
+                assert_eq!(mine.len(), 10_001);
+                assert_eq!(local.len(), 10_000);
+                (sum, local.iter().sum::<u64>())
+            })
+        })
+        .collect();
+
+    for (t, h) in handles.into_iter().enumerate() {
+        let (sum, total) = h.join().unwrap();
+        assert_eq!(sum, 100 * (t as u64 * 100));
+        assert_eq!(total, 10_000 * 9_999 / 2);
+    }
+    assert!(table.is_unique());
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use std::collections::{BTreeMap, HashSet};
+
+use common::DropLog;
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::slab::Slab;
+
+/// 检查空闲链表：依次插入`slots - len`个元素，它们必须恰好复用所有
+/// 空槽位，之后的插入必须追加新的槽位。
+fn check_free_list(slab: &mut Slab<u64>, model: &BTreeMap<usize, u64>, slots: usize) {
+    let mut reused = HashSet::new();
+    for _ in 0..slots - model.len() {
+        let key = slab.insert(0);
+        assert!(
+            key < slots && !model.contains_key(&key),
+            "bad free slot {key}"
+        );
+        assert!(reused.insert(key), "slot {key} handed out twice");
+    }
+    assert_eq!(slab.insert(0), slots);
+    assert_eq!(slab.len(), slots + 1);
+}
+
+#[test]
+fn slab_model_interleaved() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..30 {
+        let mut slab = Slab::new();
+        let mut model = BTreeMap::new();
+        // 曾经分配过的槽位数，键总是紧凑地分配在`0..slots`中
+        let mut slots = 0;
+        for _ in 0..600 {
+            let value = rng.next_u64();
+            let key = rng.next_u64() as usize % (slots + 2);
+            match rng.next_u64() % 6 {
+                0..=2 => {
+                    let expected = slab.vacant_key();
+                    let key = slab.insert(value);
+                    assert_eq!(key, expected);
+                    assert!(
+                        model.insert(key, value).is_none(),
+                        "key {key} reused while live"
+                    );
+                    slots = slots.max(key + 1);
+                }
+                3 => assert_eq!(slab.remove(key), model.remove(&key)),
+                4 => {
+                    slab.retain(|_, v| !v.is_multiple_of(7));
+                    model.retain(|_, v| !v.is_multiple_of(7));
+                }
+                _ => {
+                    if let Some(v) = slab.get_mut(key) {
+                        *v = value;
+                    }
+                    if let Some(v) = model.get_mut(&key) {
+                        *v = value;
+                    }
+                }
+            }
+            assert_eq!(slab.get(key), model.get(&key));
+            assert_eq!(slab.contains(key), model.contains_key(&key));
+            assert_eq!(slab.len(), model.len());
+        }
+        assert!(
+            slab.iter()
+                .map(|(k, v)| (k, *v))
+                .eq(model.iter().map(|(k, v)| (*k, *v)))
+        );
+        check_free_list(&mut slab, &model, slots);
+    }
+}
+
+#[test]
+fn slab_drop_only_occupied() {
+    let log = DropLog::new();
+    let mut slab = Slab::new();
+    let keys: Vec<_> = (0..10).map(|_| slab.insert(log.track(0))).collect();
+    for &key in keys.iter().step_by(3) {
+        drop(slab.remove(key));
+    }
+    assert_eq!(log.dropped(), 4);
+    // 重复移除不会重复`drop`
+    assert!(slab.remove(keys[0]).is_none());
+    slab.retain(|key, _| key != keys[1]);
+    assert_eq!(log.dropped(), 5);
+    drop(slab);
+    assert_eq!(log.dropped(), 10);
+}
+
+#[test]
+fn slab_iter_and_index() {
+    let mut slab = Slab::with_capacity(4);
+    let keys: Vec<_> = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|s| slab.insert(s.to_string()))
+        .collect();
+    slab.remove(keys[1]);
+    slab[keys[2]].push('!');
+    for (_, value) in &mut slab {
+        value.push('?');
+    }
+    assert_eq!(format!("{slab:?}"), r#"{0: "a?", 2: "c!?", 3: "d?"}"#);
+    let mut iter = slab.iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().map(|(k, _)| k), Some(3));
+    assert_eq!(iter.next().map(|(k, _)| k), Some(0));
+    assert_eq!(iter.len(), 1);
+
+    // 被移除的键在复用前一直返回`None`
+    assert!(slab.get(keys[1]).is_none());
+    assert!(slab.get(100).is_none());
+    slab.clear();
+    assert!(slab.is_empty());
+    assert_eq!(slab.insert(String::new()), 0);
+}
+
+#[test]
+#[should_panic(expected = "invalid slab key 1")]
+fn slab_index_vacant() {
+    let mut slab = Slab::new();
+    slab.insert(0);
+    let key = slab.insert(1);
+    slab.remove(key);
+    let _ = slab[key];
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use std::cmp::Ordering;
+
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::slice;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::inplace_vec;
+
+/// `Ok`时只要求找到的元素等于目标，`Err`时插入位置必须与std相同。
+fn assert_same_result<T: Ord>(values: &[T], target: &T, result: Result<usize, usize>) {
+    match (result, values.binary_search(target)) {
+        (Ok(i), Ok(_)) => assert!(values[i] == *target),
+        (Err(i), Err(j)) => assert_eq!(i, j),
+        (mine, std) => panic!("binary_search mismatch: {mine:?} vs {std:?}"),
+    }
+}
+
+#[test]
+fn slice_binary_search_exhaustive() {
+    // 长度不超过8的有序数组，目标为每个元素以及所有的间隙
+    for len in 0..=8 {
+        let values: Vec<i32> = (0..len).map(|x| 2 * x + 1).collect();
+        for target in 0..=2 * len {
+            let expected = if target % 2 == 1 {
+                Ok(target as usize / 2)
+            } else {
+                Err(target as usize / 2)
+            };
+            assert_eq!(slice::binary_search(&values, &target), expected);
+            assert_eq!(
+                slice::binary_search_by(&values, |x| x.cmp(&target)),
+                expected
+            );
+            assert_eq!(
+                slice::binary_search_by_key(&values, &(target * 10), |x| x * 10),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn slice_partition_point_exhaustive() {
+    for len in 0..=8 {
+        for split in 0..=len {
+            let values: Vec<bool> = (0..len).map(|i| i < split).collect();
+            assert_eq!(slice::partition_point(&values, |&x| x), split);
+            assert_eq!(values.partition_point(|&x| x), split);
+        }
+    }
+
+    let values = [1, 2, 2, 2, 3];
+    assert_eq!(slice::partition_point(&values, |&x| x < 2), 1);
+    assert_eq!(slice::partition_point(&values, |&x| x <= 2), 4);
+}
+
+#[test]
+fn slice_binary_search_matches_std() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..500 {
+        let len = (rng.next_u64() % 64) as usize;
+        let max = 1 + rng.next_u64() % 40;
+        let mut values: Vec<u64> = (0..len).map(|_| rng.next_u64() % max).collect();
+        values.sort();
+
+        for _ in 0..20 {
+            let target = rng.next_u64() % (max + 2);
+            assert_same_result(&values, &target, slice::binary_search(&values, &target));
+            assert_eq!(
+                slice::partition_point(&values, |&x| x < target),
+                values.partition_point(|&x| x < target)
+            );
+            assert_eq!(
+                slice::partition_point(&values, |&x| x <= target),
+                values.partition_point(|&x| x <= target)
+            );
+        }
+    }
+}
+
+#[test]
+fn slice_binary_search_huge_zst() {
+    // ZST的切片长度可以达到`usize::MAX`，`(lo + hi) / 2`会溢出
+    let values = vec![(); usize::MAX];
+    let mut steps = 0;
+    let result = slice::binary_search_by(&values, |_| {
+        steps += 1;
+        Ordering::Less
+    });
+    assert_eq!(result, Err(usize::MAX));
+    assert_eq!(steps, usize::BITS);
+    assert_eq!(slice::partition_point(&values, |_| false), 0);
+}
+
+#[test]
+fn slice_rotate_matches_std() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for len in 0..40 {
+        for mid in 0..=len {
+            let mut values: Vec<usize> = (0..len).collect();
+            let mut expected = values.clone();
+            slice::rotate_left(&mut values, mid);
+            expected.rotate_left(mid);
+            assert_eq!(values, expected);
+
+            slice::rotate_right(&mut values, mid);
+            expected.rotate_right(mid);
+            assert_eq!(values, expected);
+        }
+    }
+
+    // 不可克隆的元素、ZST以及`MyVec`、`InplaceVec`上的方法
+    for _ in 0..200 {
+        let len = (rng.next_u64() % 100) as usize;
+        let mid = (rng.next_u64() % (len as u64 + 1)) as usize;
+        let mut vec: MyVec<Box<usize>> = (0..len).map(Box::new).collect();
+        vec.my_rotate_left(mid);
+        assert!(vec.iter().map(|x| **x).eq((mid..len).chain(0..mid)));
+        vec.my_rotate_right(mid);
+        assert!(vec.iter().map(|x| **x).eq(0..len));
+
+        let mut zst = vec![(); len];
+        slice::rotate_left(&mut zst, mid);
+        assert_eq!(zst.len(), len);
+    }
+
+    let mut inplace: InplaceVec<8, i32> = inplace_vec![1, 2, 3, 4, 5];
+    inplace.my_rotate_right(1);
+    assert_eq!(inplace, [5, 1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "rotate_left mid 4 out of range for slice of length 3")]
+fn slice_rotate_out_of_bounds() {
+    slice::rotate_left(&mut [1, 2, 3], 4);
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use std::collections::BTreeSet;
+use std::ops::Bound;
+
+use rust_practice::collection::rng::{RngCore, XorShift64};
+use rust_practice::collection::sorted_vec::SortedVec;
+use rust_practice::my_vec;
+
+fn random_set(rng: &mut XorShift64, len: usize, max: u64) -> (SortedVec<u64>, BTreeSet<u64>) {
+    let values: Vec<u64> = (0..len).map(|_| rng.next_u64() % max).collect();
+    let sorted = SortedVec::from_my_vec_dedup(values.iter().copied().collect());
+    (sorted, values.into_iter().collect())
+}
+
+#[test]
+fn sorted_vec_model_btree_set() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..20 {
+        let mut set = SortedVec::new();
+        let mut model = BTreeSet::new();
+        for _ in 0..500 {
+            let value = rng.next_u64() % 64;
+            match rng.next_u64() % 5 {
+                0 | 1 => assert_eq!(set.insert_unique(value).is_ok(), model.insert(value)),
+                2 => assert_eq!(set.remove(&value), model.take(&value)),
+                3 => assert_eq!(set.pop_first(), model.pop_first()),
+                _ => assert_eq!(set.contains(&value), model.contains(&value)),
+            }
+            assert_eq!(set.get(&value), model.get(&value));
+            assert!(set.iter().eq(model.iter()));
+        }
+
+        let (lo, hi) = (rng.next_u64() % 64, rng.next_u64() % 64);
+        let (lo, hi) = (lo.min(hi), lo.max(hi));
+        assert!(set.range(lo..hi).eq(model.range(lo..hi)));
+        assert!(set.range(lo..=hi).eq(model.range(lo..=hi)));
+        assert!(set.range(..hi).eq(model.range(..hi)));
+        assert!(set.range(lo..).eq(model.range(lo..)));
+        let bounds = (Bound::Excluded(lo), Bound::Included(hi));
+        assert!(set.range(bounds).eq(model.range(bounds)));
+    }
+}
+
+#[test]
+fn sorted_vec_set_operations() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..50 {
+        let len_a = (rng.next_u64() % 40) as usize;
+        let len_b = (rng.next_u64() % 40) as usize;
+        let (a, model_a) = random_set(&mut rng, len_a, 48);
+        let (b, model_b) = random_set(&mut rng, len_b, 48);
+
+        assert!(a.union(&b).iter().eq(model_a.union(&model_b)));
+        assert!(a.intersection(&b).iter().eq(model_a.intersection(&model_b)));
+        assert!(a.difference(&b).iter().eq(model_a.difference(&model_b)));
+        assert!(
+            a.symmetric_difference(&b)
+                .iter()
+                .eq(model_a.symmetric_difference(&model_b))
+        );
+    }
+}
+
+#[test]
+fn sorted_vec_multiset() {
+    let mut bag: SortedVec<_> = [3, 1, 3, 2].into_iter().collect();
+    assert_eq!(bag, [1, 2, 3, 3]);
+    assert_eq!(bag.insert(3), 4);
+    assert_eq!(bag.insert(0), 0);
+    assert_eq!(bag.range(3..).count(), 3);
+    assert_eq!(bag.remove(&3), Some(3));
+    assert_eq!(bag, [0, 1, 2, 3, 3]);
+
+    let other: SortedVec<_> = [1, 3, 3, 3, 4].into_iter().collect();
+    assert_eq!(bag.union(&other), [0, 1, 2, 3, 3, 3, 4]);
+    assert_eq!(bag.intersection(&other), [1, 3, 3]);
+    assert_eq!(other.difference(&bag), [3, 4]);
+
+    // 相等的元素按照插入顺序排列
+    #[derive(Debug)]
+    struct Key(u8, char);
+    impl PartialEq for Key {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for Key {}
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Key {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+    let mut keys = SortedVec::new();
+    for (k, tag) in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (2, 'e')] {
+        keys.insert(Key(k, tag));
+    }
+    let tags: String = keys.iter().map(|k| k.1).collect();
+    assert_eq!(tags, "bdace");
+    assert_eq!(keys.get(&Key(2, '?')).map(|k| k.1), Some('a'));
+    assert_eq!(keys.remove(&Key(1, '?')).map(|k| k.1), Some('b'));
+}
+
+#[test]
+fn sorted_vec_from_my_vec() {
+    let set = SortedVec::from_my_vec_dedup(my_vec![5, 3, 5, 1, 3]);
+    assert_eq!(set, [1, 3, 5]);
+    let bag = SortedVec::from(my_vec![5, 3, 5, 1, 3]);
+    assert_eq!(bag, [1, 3, 3, 5, 5]);
+    assert_eq!(bag.into_my_vec(), [1, 3, 3, 5, 5]);
+
+    let mut set = SortedVec::from_my_vec_dedup(my_vec!["b", "a"]);
+    assert_eq!(set.replace("a"), Some("a"));
+    assert_eq!(set.replace("c"), None);
+    assert_eq!(format!("{set:?}"), r#"["a", "b", "c"]"#);
+    assert!(set.range("z".."a").next().is_none());
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+}
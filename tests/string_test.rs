@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::panic;
+
+use rust_practice::collection::string::MyString;
+use rust_practice::my_vec;
+
+#[test]
+fn string_push_pop() {
+    let mut s = MyString::new();
+    assert_eq!(s.pop(), None);
+    for ch in ['a', 'é', '中', '🦀'] {
+        s.push(ch);
+    }
+    assert_eq!(s, "aé中🦀");
+    assert_eq!(s.len(), 1 + 2 + 3 + 4);
+    assert_eq!(s.pop(), Some('🦀'));
+    assert_eq!(s.pop(), Some('中'));
+    assert_eq!(s.pop(), Some('é'));
+    assert_eq!(s.pop(), Some('a'));
+    assert_eq!(s.pop(), None);
+    assert!(s.is_empty());
+
+    s.push_str("héllo");
+    s += ", 世界";
+    let s = s + "!";
+    assert_eq!(s, "héllo, 世界!");
+}
+
+#[test]
+fn string_insert() {
+    let mut s = MyString::from("中文");
+    s.insert(3, 'a');
+    s.insert(0, '🦀');
+    s.insert(s.len(), 'é');
+    assert_eq!(s, "🦀中a文é");
+    s.insert_str(4, "ß字");
+    assert_eq!(s, "🦀ß字中a文é");
+    assert_eq!(s.remove(4), 'ß');
+    assert_eq!(s.remove(0), '🦀');
+    assert_eq!(s, "字中a文é");
+}
+
+#[test]
+fn string_truncate() {
+    let mut s = MyString::from("a中🦀");
+    s.truncate(100);
+    assert_eq!(s, "a中🦀");
+    s.truncate(4);
+    assert_eq!(s, "a中");
+    s.truncate(1);
+    assert_eq!(s, "a");
+    s.truncate(0);
+    assert_eq!(s, "");
+}
+
+fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+    let err = panic::catch_unwind(f).unwrap_err();
+    match err.downcast::<String>() {
+        Ok(s) => *s,
+        Err(err) => err.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn string_boundary_panics() {
+    // 与`String`的信息保持一致
+    for idx in [1, 2, 5] {
+        let ours = panic_message(move || MyString::from("中🦀").insert(idx, 'a'));
+        let std = panic_message(move || String::from("中🦀").insert(idx, 'a'));
+        assert_eq!(ours, std);
+
+        let ours = panic_message(move || MyString::from("中🦀").insert_str(idx, "ab"));
+        let std = panic_message(move || String::from("中🦀").insert_str(idx, "ab"));
+        assert_eq!(ours, std);
+
+        let ours = panic_message(move || MyString::from("中🦀").truncate(idx));
+        let std = panic_message(move || String::from("中🦀").truncate(idx));
+        assert_eq!(ours, std);
+    }
+
+    let ours = panic_message(|| MyString::from("中").insert(4, 'a'));
+    let std = panic_message(|| String::from("中").insert(4, 'a'));
+    assert_eq!(ours, std);
+
+    let ours = panic_message(|| {
+        MyString::from("中").remove(3);
+    });
+    let std = panic_message(|| {
+        String::from("中").remove(3);
+    });
+    assert_eq!(ours, std);
+}
+
+#[test]
+fn string_from_utf8() {
+    let s = MyString::from_utf8(my_vec![b'h', 0xc3, 0xa9]).unwrap();
+    assert_eq!(s, "hé");
+    assert_eq!(s.into_bytes(), [b'h', 0xc3, 0xa9]);
+
+    let err = MyString::from_utf8(my_vec![0xf0, 0x9f, 0xa6]).unwrap_err();
+    let std_err = String::from_utf8(vec![0xf0, 0x9f, 0xa6]).unwrap_err();
+    assert_eq!(err.to_string(), std_err.to_string());
+    assert_eq!(err.utf8_error(), std_err.utf8_error());
+    assert_eq!(err.as_bytes(), [0xf0, 0x9f, 0xa6]);
+
+    let s = unsafe { MyString::from_utf8_unchecked(my_vec![b'o', b'k']) };
+    assert_eq!(s, "ok");
+}
+
+#[test]
+fn string_conversion() {
+    let std = String::from("所有权🦀");
+    let ptr = std.as_ptr();
+    let s = MyString::from(std);
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s, "所有权🦀");
+    let std: String = s.into();
+    assert_eq!(std.as_ptr(), ptr);
+    assert_eq!(std, "所有权🦀");
+
+    let s: MyString = "a中🦀".chars().rev().collect();
+    assert_eq!(s, "🦀中a");
+    let s: MyString = ["ab", "中", "🦀"].into_iter().collect();
+    assert_eq!(s, String::from("ab中🦀"));
+}
+
+#[test]
+fn string_fmt() {
+    let mut s = MyString::new();
+    write!(s, "{}-{:>4}-{:?}", 1, '中', "🦀").unwrap();
+    assert_eq!(s, "1-   中-\"🦀\"");
+    assert_eq!(format!("{s}"), "1-   中-\"🦀\"");
+    assert_eq!(format!("{s:?}"), "\"1-   中-\\\"🦀\\\"\"");
+
+    let mut s = MyString::from("Hello");
+    s.make_ascii_uppercase();
+    assert_eq!(s.as_mut_str(), "HELLO");
+    assert!(s.starts_with("HE"));
+
+    let set: HashSet<MyString> = ["a", "中", "a"].into_iter().map(MyString::from).collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("中"));
+    let (a, b) = (MyString::from("a"), MyString::from("b"));
+    assert!(a < b);
+}
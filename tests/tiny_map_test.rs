@@ -0,0 +1,102 @@
+mod common;
+
+use std::collections::HashMap;
+
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::tiny_map::TinyMap;
+
+#[test]
+fn tiny_map_model_hash_map() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..30 {
+        let mut map = TinyMap::<8, u64, u64>::new();
+        let mut model = HashMap::new();
+        for _ in 0..500 {
+            // 键的范围大于容量，以覆盖容量已满的情形
+            let key = rng.next_u64() % 12;
+            let value = rng.next_u64();
+            match rng.next_u64() % 6 {
+                0 | 1 => match map.insert(key, value) {
+                    Ok(old) => assert_eq!(old, model.insert(key, value)),
+                    Err(pair) => {
+                        assert_eq!(pair, (key, value));
+                        assert!(map.is_full() && !model.contains_key(&key));
+                    }
+                },
+                2 => assert_eq!(map.remove(&key), model.remove(&key)),
+                3 => {
+                    if let Some(v) = map.get_mut(&key) {
+                        *v = value;
+                    }
+                    if let Some(v) = model.get_mut(&key) {
+                        *v = value;
+                    }
+                }
+                4 => {
+                    map.retain(|k, v| (k ^ *v) % 3 != 0);
+                    model.retain(|k, v| (k ^ *v) % 3 != 0);
+                }
+                _ => assert_eq!(map.contains_key(&key), model.contains_key(&key)),
+            }
+            assert_eq!(map.get(&key), model.get(&key));
+            assert_eq!(map.len(), model.len());
+        }
+
+        let mut pairs: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        pairs.sort_unstable();
+        let mut expected: Vec<_> = model.into_iter().collect();
+        expected.sort_unstable();
+        assert_eq!(pairs, expected);
+        assert_eq!(map.keys().len(), map.values().len());
+    }
+}
+
+#[test]
+fn tiny_map_borrowed_keys() {
+    let mut map: TinyMap<4, String, Vec<i32>> = TinyMap::new();
+    map.insert("a".to_string(), vec![1]).unwrap();
+    map.insert("b".to_string(), vec![]).unwrap();
+    map["b"].push(2);
+    map.get_mut("a").unwrap().push(3);
+    assert!(map.contains_key("a"));
+    assert_eq!(map.get_key_value("b"), Some((&"b".to_string(), &vec![2])));
+    assert_eq!(map.remove_entry("a"), Some(("a".to_string(), vec![1, 3])));
+    assert_eq!(map.remove("a"), None);
+    for (_, v) in &mut map {
+        v.push(0);
+    }
+    assert_eq!(format!("{map:?}"), r#"{"b": [2, 0]}"#);
+}
+
+#[test]
+fn tiny_map_swap_remove_order() {
+    let mut map: TinyMap<4, _, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+    map.remove(&1);
+    // 最后一个键值对被移动到了第一个位置
+    assert_eq!(map.as_slice(), [(3, 'c'), (2, 'b')]);
+    // 比较时不考虑顺序
+    let other: TinyMap<8, _, _> = [(2, 'b'), (3, 'c')].into_iter().collect();
+    assert!(map == other);
+    assert_eq!(map.into_iter().map(|(k, _)| k).sum::<i32>(), 5);
+
+    let mut vec = InplaceVec::<4, _>::from_array([1, 2, 3]);
+    assert_eq!(vec.swap_remove(2), 3);
+    assert_eq!(vec.swap_remove(0), 1);
+    assert_eq!(vec, [2]);
+}
+
+#[test]
+fn tiny_map_extend_updates_existing() {
+    let mut map: TinyMap<2, _, _> = [(1, 1), (2, 2)].into_iter().collect();
+    // 容量已满，但已存在的键可以更新
+    map.extend([(1, 10), (2, 20), (1, 100)]);
+    assert_eq!(map[&1], 100);
+    assert_eq!(map[&2], 20);
+}
+
+#[test]
+#[should_panic(expected = "TinyMap overflow")]
+fn tiny_map_extend_overflow() {
+    let _: TinyMap<2, _, _> = [(1, 1), (2, 2), (3, 3)].into_iter().collect();
+}
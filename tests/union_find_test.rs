@@ -0,0 +1,101 @@
+mod common;
+
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::union_find::UnionFind;
+
+/// 按边集合计算连通分量的标号，使用深度优先搜索。
+fn components(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut adj = vec![Vec::new(); n];
+    for &(a, b) in edges {
+        adj[a].push(b);
+        adj[b].push(a);
+    }
+    let mut label = vec![usize::MAX; n];
+    for start in 0..n {
+        if label[start] != usize::MAX {
+            continue;
+        }
+        let mut stack = vec![start];
+        label[start] = start;
+        while let Some(x) = stack.pop() {
+            for &y in &adj[x] {
+                if label[y] == usize::MAX {
+                    label[y] = start;
+                    stack.push(y);
+                }
+            }
+        }
+    }
+    label
+}
+
+#[test]
+fn union_find_model_reachability() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for round in 0..20 {
+        let n = 1 + round * 3;
+        let mut sets = UnionFind::new(n);
+        let mut edges = Vec::new();
+        for _ in 0..2 * n {
+            let a = (rng.next_u64() % n as u64) as usize;
+            let b = (rng.next_u64() % n as u64) as usize;
+            let before = components(n, &edges);
+            assert_eq!(sets.union(a, b), before[a] != before[b]);
+            edges.push((a, b));
+
+            let label = components(n, &edges);
+            for x in 0..n {
+                for y in 0..n {
+                    assert_eq!(sets.connected(x, y), label[x] == label[y]);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn union_find_set_count() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    let n = 200;
+    let mut sets = UnionFind::new(n);
+    let mut expected = n;
+    while expected > 1 {
+        let a = (rng.next_u64() % n as u64) as usize;
+        let b = (rng.next_u64() % n as u64) as usize;
+        if sets.union(a, b) {
+            expected -= 1;
+        }
+        assert_eq!(sets.set_count(), expected);
+    }
+
+    // 所有元素的代表元素都相同
+    let root = sets.find_immutable(0);
+    assert!((0..n).all(|x| sets.find(x) == root));
+
+    sets.reset();
+    assert_eq!(sets.set_count(), n);
+    assert!((0..n).all(|x| sets.find_immutable(x) == x));
+}
+
+#[test]
+fn union_find_make_set() {
+    let mut sets = UnionFind::default();
+    assert!(sets.is_empty());
+    let a = sets.make_set();
+    let b = sets.make_set();
+    let c = sets.make_set();
+    assert_eq!((a, b, c), (0, 1, 2));
+    assert_eq!(sets.set_count(), 3);
+
+    assert!(sets.union(a, c));
+    assert_eq!(sets.len(), 3);
+    assert_eq!(sets.set_count(), 2);
+    assert_eq!(format!("{sets:?}"), "[0, 1, 0]");
+}
+
+#[test]
+#[should_panic(expected = "UnionFind index out of bounds: the len is 3 but the index is 3")]
+fn union_find_out_of_bounds() {
+    let mut sets = UnionFind::new(3);
+    sets.union(0, 3);
+}
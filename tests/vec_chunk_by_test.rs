@@ -0,0 +1,89 @@
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+#[test]
+fn vec_into_chunk_by_matches_slice_chunk_by() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for len in 0..100 {
+        let data: Vec<u64> = (0..len).map(|_| rng.next_u64() % 3).collect();
+        let expected: Vec<&[u64]> = data.chunk_by(|a, b| a <= b).collect();
+        let groups: Vec<MyVec<u64>> = MyVec::from(data.clone())
+            .into_chunk_by(|a, b| a <= b)
+            .collect();
+
+        assert_eq!(groups.len(), expected.len());
+        for (group, slice) in groups.iter().zip(&expected) {
+            assert_eq!(**group, **slice);
+            assert_eq!(group.capacity(), group.len());
+        }
+    }
+
+    assert_eq!(MyVec::<i32>::new().into_chunk_by(|_, _| true).count(), 0);
+    let single: Vec<_> = my_vec![1, 2, 3].into_chunk_by(|_, _| true).collect();
+    assert_eq!(single, [my_vec![1, 2, 3]]);
+    let zst: Vec<_> = MyVec::from(vec![(); 5])
+        .into_chunk_by(|_, _| false)
+        .collect();
+    assert_eq!(zst.len(), 5);
+}
+
+#[test]
+fn vec_into_chunk_by_abandoned_halfway() {
+    let log = DropLog::new();
+    let vec: MyVec<DropTracker> = (0..30).map(|i| log.track(i / 4)).collect();
+
+    let mut iter = vec.into_chunk_by(|a, b| a.id() == b.id());
+    let first = iter.next().unwrap();
+    let second = iter.next().unwrap();
+    assert_eq!((first.len(), second.len()), (4, 4));
+    assert_eq!(log.dropped(), 0);
+
+    // 丢弃迭代器只释放尚未产出的22个元素
+    drop(iter);
+    assert_eq!(log.dropped(), 22);
+    drop((first, second));
+    assert_eq!(log.dropped(), 30);
+}
+
+#[test]
+fn vec_into_chunk_by_panicking_pred() {
+    let log = DropLog::new();
+    let vec: MyVec<DropTracker> = log.track_n(10).collect();
+
+    let mut groups = Vec::new();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        for group in vec.into_chunk_by(|a, _| {
+            assert!(a.id() < 6, "predicate panicked");
+            a.id() % 3 != 2
+        }) {
+            groups.push(group);
+        }
+    }));
+    assert!(result.is_err());
+    // 已经产出的两组共6个元素仍然有效，其余4个元素被迭代器释放
+    assert_eq!(groups.iter().map(|g| g.len()).sum::<usize>(), 6);
+    assert_eq!(log.dropped(), 4);
+    drop(groups);
+    assert_eq!(log.dropped(), 10);
+}
+
+#[test]
+fn vec_into_chunk_by_send_to_threads() {
+    let vec: MyVec<String> = ["a", "a", "b", "c", "c"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let handles: Vec<_> = vec
+        .into_chunk_by(|a, b| a == b)
+        .map(|group| thread::spawn(move || group.concat()))
+        .collect();
+    let joined: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(joined, ["aa", "b", "cc"]);
+}
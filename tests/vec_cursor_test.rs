@@ -0,0 +1,119 @@
+mod common;
+
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+#[test]
+fn cursor_matches_vec_model() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    let mut vec: MyVec<String> = MyVec::new();
+    let mut model: Vec<String> = Vec::new();
+
+    for round in 0..50 {
+        let at = rng.next_u64() as usize % (model.len() + 1);
+        let mut index = at;
+        let mut cursor = vec.cursor_mut(at);
+        for step in 0..40 {
+            let value = format!("{round}-{step}");
+            match rng.next_u64() % 6 {
+                0 => cursor.move_next(),
+                1 => cursor.move_prev(),
+                2 => {
+                    cursor.insert_before(value.clone());
+                    model.insert(index, value);
+                }
+                3 => {
+                    cursor.insert_after(value.clone());
+                    let after = if index == model.len() {
+                        index
+                    } else {
+                        index + 1
+                    };
+                    model.insert(after, value);
+                }
+                4 => {
+                    let expected = (index < model.len()).then(|| model.remove(index));
+                    assert_eq!(cursor.remove_current(), expected);
+                }
+                _ => {
+                    let slice = [value.clone(), value.clone() + "!"];
+                    cursor.splice_before(&slice);
+                    model.splice(index..index, slice);
+                }
+            }
+            index = cursor.index();
+            assert_eq!(cursor.len(), model.len());
+            let (before, after) = cursor.as_slices();
+            assert_eq!(before, &model[..index]);
+            assert_eq!(after, &model[index..]);
+        }
+        drop(cursor);
+        assert_eq!(vec, model[..]);
+    }
+}
+
+#[test]
+fn cursor_moves_and_insert_after() {
+    let mut vec = my_vec![1, 2, 3];
+    let mut cursor = vec.cursor_mut(0);
+    cursor.move_prev();
+    assert_eq!(cursor.index(), 0);
+    assert_eq!(cursor.current(), Some(&mut 1));
+
+    cursor.insert_after(10);
+    assert_eq!(cursor.current(), Some(&mut 1));
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 10));
+
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.index(), 4);
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.remove_current(), None);
+
+    // 位于末尾时，插入的元素成为当前元素
+    cursor.insert_after(20);
+    assert_eq!(cursor.current(), Some(&mut 20));
+    *cursor.current().unwrap() += 1;
+    drop(cursor);
+    assert_eq!(vec, [1, 10, 2, 3, 21]);
+}
+
+#[test]
+fn forgotten_cursor_leaves_vec_valid() {
+    let mut vec: MyVec<String> = ["a", "b", "c", "d"].into_iter().map(String::from).collect();
+    let mut cursor = vec.cursor_mut(1);
+    cursor.insert_before("x".to_string());
+    cursor.move_next();
+    mem::forget(cursor);
+
+    // 光标之后的元素被泄漏，但之前的元素仍然可用
+    assert_eq!(vec.as_slice(), ["a", "x", "b"]);
+    vec.push("e".to_string());
+    assert_eq!(vec.as_slice(), ["a", "x", "b", "e"]);
+}
+
+#[test]
+fn cursor_splice_panic_keeps_vec_valid() {
+    let log = DropLog::new();
+    let mut vec = my_vec![log.track(10), log.track(20)];
+    let slice = [log.track(1), log.track(2), log.track(3), log.track(4)];
+    log.panic_on_clone(3);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut cursor = vec.cursor_mut(1);
+        cursor.splice_before(&slice);
+    }));
+    assert!(result.is_err());
+    let ids: Vec<_> = vec.iter().map(DropTracker::id).collect();
+    assert_eq!(ids, [10, 1, 2, 20]);
+
+    drop((vec, slice));
+    assert_eq!(log.alive(), 0);
+}
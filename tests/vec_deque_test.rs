@@ -0,0 +1,193 @@
+mod common;
+
+use std::collections::VecDeque;
+
+use common::DropLog;
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec_deque::MyVecDeque;
+
+fn assert_same<T: PartialEq + std::fmt::Debug>(deque: &MyVecDeque<T>, model: &VecDeque<T>) {
+    assert_eq!(deque.len(), model.len());
+    assert!(deque.iter().eq(model.iter()));
+    assert!(deque.iter().rev().eq(model.iter().rev()));
+    let (a, b) = deque.as_slices();
+    assert_eq!(a.len() + b.len(), model.len());
+    assert!(a.iter().chain(b).eq(model.iter()));
+    assert_eq!(deque.front(), model.front());
+    assert_eq!(deque.back(), model.back());
+}
+
+#[test]
+fn vec_deque_push_pop() {
+    let mut deque = MyVecDeque::new();
+    assert_eq!(deque.capacity(), 0);
+    assert_eq!(deque.pop_front(), None::<i32>);
+    assert_eq!(deque.pop_back(), None);
+
+    deque.push_back(1);
+    deque.push_back(2);
+    deque.push_front(0);
+    deque.push_front(-1);
+    assert_eq!(deque, [-1, 0, 1, 2]);
+    assert_eq!(deque[1], 0);
+    deque[1] = 10;
+    assert_eq!(deque.get(4), None);
+    assert_eq!(deque.pop_back(), Some(2));
+    assert_eq!(deque.pop_front(), Some(-1));
+    assert_eq!(deque, [10, 1]);
+}
+
+#[test]
+fn vec_deque_grow_wrapped() {
+    // 构造一个绕回的布局，然后触发扩容
+    for cap in 1..10 {
+        for shift in 0..cap {
+            let mut deque = MyVecDeque::with_capacity(cap);
+            let mut model = VecDeque::new();
+            for i in 0..shift {
+                deque.push_back(i);
+                deque.pop_front();
+            }
+            for i in 0..cap * 3 {
+                deque.push_back(i);
+                model.push_back(i);
+                assert_same(&deque, &model);
+            }
+        }
+    }
+}
+
+#[test]
+fn vec_deque_make_contiguous() {
+    let mut deque = MyVecDeque::with_capacity(8);
+    for i in 0..6 {
+        deque.push_back(i);
+    }
+    for _ in 0..4 {
+        deque.pop_front();
+    }
+    for i in 6..12 {
+        deque.push_back(i);
+    }
+    assert!(!deque.as_slices().1.is_empty());
+    assert_eq!(deque.make_contiguous(), [4, 5, 6, 7, 8, 9, 10, 11]);
+    assert_eq!(
+        deque.as_slices(),
+        (&[4, 5, 6, 7, 8, 9, 10, 11][..], &[][..])
+    );
+}
+
+#[test]
+fn vec_deque_iter_mut() {
+    let mut deque: MyVecDeque<_> = (0..5).collect();
+    deque.push_front(-1);
+    for x in deque.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(deque, [-2, 0, 2, 4, 6, 8]);
+    let mut iter = deque.iter_mut();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next_back(), Some(&mut 8));
+    assert_eq!(iter.next(), Some(&mut -2));
+    assert_eq!(iter.len(), 4);
+}
+
+#[test]
+fn vec_deque_into_iter() {
+    let mut deque = MyVecDeque::with_capacity(4);
+    deque.push_back(String::from("b"));
+    deque.push_front(String::from("a"));
+    deque.push_back(String::from("c"));
+    let mut iter = deque.clone().into_iter();
+    assert_eq!(iter.next_back().as_deref(), Some("c"));
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.len(), 1);
+    drop(iter);
+    let v: Vec<_> = deque.into_iter().collect();
+    assert_eq!(v, ["a", "b", "c"]);
+}
+
+#[test]
+fn vec_deque_zst() {
+    let mut deque = MyVecDeque::new();
+    assert_eq!(deque.capacity(), isize::MAX as usize);
+    for _ in 0..100 {
+        deque.push_back(());
+        deque.push_front(());
+    }
+    assert_eq!(deque.len(), 200);
+    assert_eq!(deque.iter().count(), 200);
+    let (a, b) = deque.as_slices();
+    assert_eq!(a.len() + b.len(), 200);
+    assert_eq!(deque.make_contiguous().len(), 200);
+    for _ in 0..150 {
+        assert_eq!(deque.pop_front(), Some(()));
+    }
+    assert_eq!(deque.into_iter().count(), 50);
+}
+
+#[test]
+fn vec_deque_drop_wrapped() {
+    let log = DropLog::new();
+    let mut deque = MyVecDeque::with_capacity(4);
+    for _ in 0..3 {
+        deque.push_back(log.track(0));
+    }
+    drop(deque.pop_front());
+    drop(deque.pop_front());
+    for _ in 0..3 {
+        deque.push_back(log.track(0));
+    }
+    assert!(!deque.as_slices().1.is_empty());
+    assert_eq!(log.dropped(), 2);
+    drop(deque);
+    assert_eq!(log.dropped(), 6);
+
+    let log = DropLog::new();
+    let mut deque: MyVecDeque<_> = log.track_n(5).collect();
+    deque.push_front(log.track(0));
+    let mut iter = deque.into_iter();
+    drop(iter.next());
+    drop(iter);
+    assert_eq!(log.dropped(), 6);
+}
+
+#[test]
+fn vec_deque_model() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..20 {
+        let mut deque = MyVecDeque::new();
+        let mut model = VecDeque::new();
+        for _ in 0..1000 {
+            let value = rng.next_u64();
+            match rng.next_u64() % 7 {
+                0 | 1 => {
+                    deque.push_back(value);
+                    model.push_back(value);
+                }
+                2 | 3 => {
+                    deque.push_front(value);
+                    model.push_front(value);
+                }
+                4 => assert_eq!(deque.pop_back(), model.pop_back()),
+                5 => assert_eq!(deque.pop_front(), model.pop_front()),
+                _ => {
+                    if !model.is_empty() {
+                        let index = value as usize % model.len();
+                        assert_eq!(deque.get(index), model.get(index));
+                        deque[index] = value;
+                        model[index] = value;
+                    }
+                    if value.is_multiple_of(8) {
+                        assert_eq!(deque.make_contiguous(), model.make_contiguous());
+                    } else if value.is_multiple_of(5) {
+                        // `extend`会通过`reserve`扩容
+                        deque.extend(0..value % 13);
+                        model.extend(0..value % 13);
+                    }
+                }
+            }
+            assert_same(&deque, &model);
+        }
+    }
+}
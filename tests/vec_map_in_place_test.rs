@@ -0,0 +1,78 @@
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(u64);
+
+#[test]
+fn vec_map_in_place_unwrap_newtype() {
+    let vec: MyVec<Meters> = (1..=100).map(Meters).collect();
+    let (ptr, cap) = (vec.as_ptr(), vec.capacity());
+
+    let raw = vec.map_in_place(|Meters(m)| m * 10);
+    assert!(raw.iter().copied().eq((1..=100).map(|m| m * 10)));
+    assert_eq!(raw.as_ptr().cast::<Meters>(), ptr);
+    assert_eq!(raw.capacity(), cap);
+
+    let empty: MyVec<Meters> = MyVec::new();
+    assert!(empty.map_in_place(|m| m.0).is_empty());
+}
+
+#[test]
+fn vec_map_in_place_u32_to_f32() {
+    let mut vec = my_vec![0u32, 1, 2, 3];
+    vec.reserve(10);
+    let (ptr, cap) = (vec.as_ptr(), vec.capacity());
+
+    let mut floats = vec.map_in_place(|x| x as f32 * 0.5);
+    assert_eq!(floats, [0.0, 0.5, 1.0, 1.5]);
+    assert_eq!(floats.as_ptr().cast::<u32>(), ptr);
+    assert_eq!(floats.capacity(), cap);
+
+    // 转换后的`MyVec`可以正常使用原来的容量
+    floats.push(2.0);
+    assert_eq!(floats.as_ptr().cast::<u32>(), ptr);
+
+    let bits = floats.map_in_place(f32::to_bits);
+    assert_eq!(bits[1], 0.5f32.to_bits());
+}
+
+#[test]
+fn vec_map_in_place_panic_drops_both_halves() {
+    let (t_log, u_log) = (DropLog::new(), DropLog::new());
+    let vec: MyVec<DropTracker> = t_log.track_n(10).collect();
+
+    let mut calls = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        vec.map_in_place(|t| {
+            calls += 1;
+            if calls == 4 {
+                panic!("map_in_place panic");
+            }
+            drop(t);
+            u_log.track(0)
+        })
+    }));
+    assert!(result.is_err());
+
+    // 前3个元素被`f`消耗后转换为`U`，第4个元素在`f`中随`panic`被`drop`，
+    // 剩下的6个元素仍然是`T`
+    assert_eq!(u_log.dropped(), 3);
+    assert_eq!(t_log.dropped(), 3 + 1 + 6);
+}
+
+#[test]
+fn vec_map_in_place_drops_once() {
+    let (t_log, u_log) = (DropLog::new(), DropLog::new());
+    let vec: MyVec<DropTracker> = t_log.track_n(5).collect();
+
+    let mapped = vec.map_in_place(|_| u_log.track(0));
+    assert_eq!((t_log.dropped(), u_log.dropped()), (5, 0));
+    drop(mapped);
+    assert_eq!((t_log.dropped(), u_log.dropped()), (5, 5));
+}
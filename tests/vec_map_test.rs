@@ -0,0 +1,169 @@
+mod common;
+
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+
+use common::DropLog;
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec_map::{Entry, VecMap};
+use rust_practice::my_vec;
+
+#[test]
+fn vec_map_model_btree_map() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for round in 0..30 {
+        // 键的范围有大有小，以覆盖命中率不同的情形
+        let max = [8, 32, 256][round % 3];
+        let mut map = VecMap::new();
+        let mut model = BTreeMap::new();
+        for _ in 0..500 {
+            let key = rng.next_u64() % max;
+            let value = rng.next_u64();
+            match rng.next_u64() % 8 {
+                0 | 1 => assert_eq!(map.insert(key, value), model.insert(key, value)),
+                2 => assert_eq!(map.remove(&key), model.remove(&key)),
+                3 => {
+                    *map.entry(key).or_insert_with(|| value) += 1;
+                    *model.entry(key).or_insert_with(|| value) += 1;
+                }
+                4 => {
+                    if let Some(v) = map.get_mut(&key) {
+                        *v = value;
+                    }
+                    if let Some(v) = model.get_mut(&key) {
+                        *v = value;
+                    }
+                }
+                5 => {
+                    map.retain(|k, v| (k ^ *v) % 5 != 0);
+                    model.retain(|k, v| (k ^ *v) % 5 != 0);
+                }
+                6 => {
+                    // 少量的键值对逐个插入，较多时排序后合并
+                    let pairs: Vec<_> = (0..rng.next_u64() % 24)
+                        .map(|_| (rng.next_u64() % max, rng.next_u64()))
+                        .collect();
+                    map.extend(pairs.iter().copied());
+                    model.extend(pairs);
+                }
+                _ => assert_eq!(map.contains_key(&key), model.contains_key(&key)),
+            }
+            assert_eq!(map.get(&key), model.get(&key));
+            assert_eq!(map.len(), model.len());
+        }
+        assert!(map.iter().eq(model.iter()));
+        assert!(map.keys().eq(model.keys()));
+        assert!(map.values().rev().eq(model.values().rev()));
+        assert!(map.into_iter().eq(model));
+    }
+}
+
+#[test]
+fn vec_map_from_iter_keeps_last() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..50 {
+        let pairs: Vec<_> = (0..(rng.next_u64() % 64))
+            .map(|_| (rng.next_u64() % 16, rng.next_u64()))
+            .collect();
+        let map: VecMap<_, _> = pairs.iter().copied().collect();
+        let model: BTreeMap<_, _> = pairs.into_iter().collect();
+        assert!(map.iter().eq(model.iter()));
+    }
+
+    let map = VecMap::from_my_vec(my_vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (2, 'e')]);
+    assert_eq!(map.as_slice(), [(1, 'd'), (2, 'e')]);
+
+    // 被覆盖的值都会被`drop`，且只`drop`一次
+    let log = DropLog::new();
+    let map: VecMap<_, _> = (0..10).map(|i| (i % 3, log.track(0))).collect();
+    assert_eq!(map.len(), 3);
+    assert_eq!(log.dropped(), 7);
+    drop(map);
+    assert_eq!(log.dropped(), 10);
+}
+
+#[test]
+fn vec_map_extend_merge_and_panic() {
+    // 追加的键与原有的键交错，需要合并；重复的键取最后出现的值
+    let mut map: VecMap<_, _> = (0..20).map(|k| (k * 2, 'a')).collect();
+    map.extend(
+        (0..30)
+            .rev()
+            .map(|k| (k, 'b'))
+            .chain([(10, 'c'), (45, 'c')]),
+    );
+    let mut model: BTreeMap<_, _> = (0..20).map(|k| (k * 2, 'a')).collect();
+    model.extend(
+        (0..30)
+            .rev()
+            .map(|k| (k, 'b'))
+            .chain([(10, 'c'), (45, 'c')]),
+    );
+    assert!(map.iter().eq(model.iter()));
+
+    // 迭代器`panic`时只丢弃已经追加的键值对
+    let log = DropLog::new();
+    let mut map: VecMap<_, _> = (0..5).map(|k| (k, log.track(k))).collect();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        map.extend((5..20).map(|k| {
+            assert!(k < 15, "iterator panicked");
+            (k, log.track(k))
+        }));
+    }));
+    assert!(result.is_err());
+    assert!(map.keys().copied().eq(0..5));
+    assert_eq!(log.dropped(), 10);
+    drop(map);
+    assert_eq!(log.alive(), 0);
+}
+
+#[test]
+fn vec_map_entry() {
+    let mut map = VecMap::from([("a", 1), ("c", 3)]);
+    match map.entry("b") {
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.key(), &"b");
+            *entry.insert(2) *= 10;
+        }
+        Entry::Occupied(_) => unreachable!(),
+    }
+    match map.entry("c") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.insert(30), 3);
+            assert_eq!(entry.remove_entry(), ("c", 30));
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    map.entry("a").and_modify(|v| *v += 1).or_insert(0);
+    map.entry("d").and_modify(|v| *v += 1).or_insert(0);
+    *map.entry("e").or_default() += 5;
+    let len = map.entry("f").or_insert_with_key(|k| k.len() as i32);
+    assert_eq!(*len, 1);
+    assert_eq!(
+        format!("{map:?}"),
+        r#"{"a": 2, "b": 20, "d": 0, "e": 5, "f": 1}"#
+    );
+}
+
+#[test]
+fn vec_map_index_and_iter_mut() {
+    let mut map: VecMap<String, i32> = [("x".to_string(), 1), ("y".to_string(), 2)].into();
+    // 可以使用`&str`查找`String`的键
+    assert_eq!(map["x"], 1);
+    map["y"] += 40;
+    for (_, v) in &mut map {
+        *v *= 2;
+    }
+    map.values_mut().for_each(|v| *v += 1);
+    assert_eq!(map.get_key_value("y"), Some((&"y".to_string(), &85)));
+    assert_eq!(map.first_key_value().map(|(_, v)| *v), Some(3));
+    assert_eq!(map.remove_entry("x"), Some(("x".to_string(), 3)));
+    assert_eq!(map.iter().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "key not found")]
+fn vec_map_index_missing() {
+    let map = VecMap::from([(1, 1)]);
+    let _ = map[&2];
+}
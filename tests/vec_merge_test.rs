@@ -0,0 +1,130 @@
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::rng::{RngCore, XorShift64};
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+/// 带有`String`的元素，通过[`DropTracker`]记录被`drop`的次数。
+struct Payload {
+    key: u64,
+    text: String,
+    _tracker: DropTracker,
+}
+
+fn sorted_keys(rng: &mut XorShift64, len: usize, max: u64) -> Vec<u64> {
+    let mut keys: Vec<u64> = (0..len).map(|_| rng.next_u64() % max).collect();
+    keys.sort();
+    keys
+}
+
+#[test]
+fn vec_merge_sorted_matches_concat_then_sort() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..300 {
+        let (n, m) = (
+            (rng.next_u64() % 40) as usize,
+            (rng.next_u64() % 40) as usize,
+        );
+        // 键的范围很小时有大量重复
+        let max = [2, 10, 1000][(rng.next_u64() % 3) as usize];
+        let left: Vec<(u64, char)> = sorted_keys(&mut rng, n, max)
+            .into_iter()
+            .map(|k| (k, 'l'))
+            .collect();
+        let right: Vec<(u64, char)> = sorted_keys(&mut rng, m, max)
+            .into_iter()
+            .map(|k| (k, 'r'))
+            .collect();
+
+        let mut expected: Vec<(u64, char)> = left.iter().chain(&right).copied().collect();
+        expected.sort_by_key(|&(k, _)| k);
+
+        let merged =
+            MyVec::from(left.clone()).merge_sorted_by_key(MyVec::from(right.clone()), |&(k, _)| k);
+        assert_eq!(*merged, *expected);
+        assert_eq!(merged.capacity(), n + m);
+
+        let merged = MyVec::from(left).merge_sorted(MyVec::from(right));
+        expected.sort();
+        assert_eq!(*merged, *expected);
+    }
+}
+
+#[test]
+fn vec_merge_sorted_empty() {
+    let empty: MyVec<i32> = MyVec::new();
+    assert_eq!(empty.clone().merge_sorted(MyVec::new()), []);
+    assert_eq!(empty.clone().merge_sorted(my_vec![1, 2]), [1, 2]);
+    assert_eq!(my_vec![1, 2].merge_sorted(empty), [1, 2]);
+
+    let zst = MyVec::from(vec![(); 3]).merge_sorted(MyVec::from(vec![(); 4]));
+    assert_eq!(zst.len(), 7);
+}
+
+#[test]
+fn vec_merge_sorted_moves_strings() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    let log = DropLog::new();
+    let make = |rng: &mut XorShift64, len: usize, side: &str| -> MyVec<Payload> {
+        sorted_keys(rng, len, 5)
+            .into_iter()
+            .enumerate()
+            .map(|(i, key)| Payload {
+                key,
+                text: format!("{side}{i}"),
+                _tracker: log.track(i),
+            })
+            .collect()
+    };
+    let left = make(&mut rng, 50, "l");
+    let right = make(&mut rng, 70, "r");
+
+    let merged = left.merge_sorted_by_key(right, |p| p.key);
+    // 元素只被移动，没有被`drop`
+    assert_eq!(log.dropped(), 0);
+    assert!(merged.windows(2).all(|w| w[0].key <= w[1].key));
+    // 相等的键中，左边的元素在前，且各自保持原来的顺序
+    for w in merged.windows(2).filter(|w| w[0].key == w[1].key) {
+        let (a, b) = (&w[0].text, &w[1].text);
+        if a[..1] == b[..1] {
+            assert!(a[1..].parse::<usize>().unwrap() < b[1..].parse::<usize>().unwrap());
+        } else {
+            assert!(a.starts_with('l'));
+        }
+    }
+
+    drop(merged);
+    assert_eq!(log.dropped(), 120);
+}
+
+#[test]
+fn vec_merge_sorted_panic_safety() {
+    let log = DropLog::new();
+    let make = |keys: &[u64]| -> MyVec<Payload> {
+        keys.iter()
+            .map(|&key| Payload {
+                key,
+                text: key.to_string(),
+                _tracker: log.track(key as usize),
+            })
+            .collect()
+    };
+    let (left, right) = (make(&[1, 3, 5, 7]), make(&[2, 4, 6]));
+
+    let mut comparisons = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        left.merge_sorted_by(right, |a, b| {
+            comparisons += 1;
+            if comparisons == 4 {
+                panic!("comparator panicked");
+            }
+            a.key.cmp(&b.key)
+        })
+    }));
+    assert!(result.is_err());
+    // 已经合并的、尚未合并的元素都恰好被`drop`一次
+    assert_eq!(log.dropped(), 7);
+}
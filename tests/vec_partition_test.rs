@@ -0,0 +1,99 @@
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec::MyVec;
+
+#[test]
+fn vec_partition_in_place_split() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for len in 0..200 {
+        let data: Vec<u64> = (0..len).map(|_| rng.next_u64() % 100).collect();
+        let threshold = rng.next_u64() % 100;
+        let mut vec = MyVec::from(data.clone());
+
+        let mut calls = 0;
+        let mid = vec.partition_in_place(|&x| {
+            calls += 1;
+            x < threshold
+        });
+        assert!(calls <= len);
+        assert_eq!(mid, data.iter().filter(|&&x| x < threshold).count());
+        assert!(vec[..mid].iter().all(|&x| x < threshold));
+        assert!(vec[mid..].iter().all(|&x| x >= threshold));
+
+        let (mut sorted, mut expected) = (Vec::from(vec), data);
+        sorted.sort();
+        expected.sort();
+        assert_eq!(sorted, expected);
+    }
+}
+
+#[test]
+fn vec_partition_consuming_is_stable() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for len in [0, 1, 2, 17, 300] {
+        let data: Vec<u64> = (0..len).map(|_| rng.next_u64() % 10).collect();
+        let (left, right) = MyVec::from(data.clone()).partition(|&x| x % 3 == 0);
+        let (expected_left, expected_right): (Vec<u64>, Vec<u64>) =
+            data.into_iter().partition(|&x| x % 3 == 0);
+        assert_eq!(*left, *expected_left);
+        assert_eq!(*right, *expected_right);
+        assert_eq!(left.capacity(), left.len());
+        assert_eq!(right.capacity(), right.len());
+    }
+}
+
+#[test]
+fn vec_partition_in_place_panic_safety() {
+    for len in [1, 10, 100] {
+        for panic_at in [0, len / 2, len - 1] {
+            let log = DropLog::new();
+            let mut vec: MyVec<DropTracker> = log.track_n(len).collect();
+
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                vec.partition_in_place(|x| {
+                    calls += 1;
+                    if calls > panic_at {
+                        panic!("predicate panicked");
+                    }
+                    x.id() % 2 == 0
+                })
+            }));
+            assert!(result.is_err());
+
+            assert_eq!(log.dropped(), 0);
+            let mut ids: Vec<usize> = vec.iter().map(|x| x.id()).collect();
+            ids.sort();
+            assert!(ids.into_iter().eq(0..len));
+            drop(vec);
+            assert_eq!(log.dropped(), len);
+        }
+    }
+}
+
+#[test]
+fn vec_partition_consuming_drops_once() {
+    let log = DropLog::new();
+    let make = || -> MyVec<DropTracker> { log.track_n(50).collect() };
+
+    let (left, right) = make().partition(|x| x.id() < 20);
+    assert_eq!(log.dropped(), 0);
+    assert!(left.iter().map(|x| x.id()).eq(0..20));
+    assert!(right.iter().map(|x| x.id()).eq(20..50));
+    drop((left, right));
+    assert_eq!(log.dropped(), 50);
+
+    let vec = make();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        vec.partition(|x| {
+            assert!(x.id() < 30, "predicate panicked");
+            true
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(log.dropped(), 100);
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use rust_practice::collection::rng::{RngCore, XorShift64};
+use rust_practice::collection::vec::MyVec;
+use rust_practice::my_vec;
+
+#[test]
+fn vec_shuffle_is_deterministic() {
+    for seed in [0, 1, 42, u64::MAX] {
+        let mut a: MyVec<u32> = (0..50).collect();
+        let mut b = a.clone();
+        a.shuffle(&mut XorShift64::seed_from_u64(seed));
+        b.shuffle(&mut XorShift64::seed_from_u64(seed));
+        assert_eq!(a, b);
+
+        let mut sorted = a.clone();
+        sorted.sort_stable();
+        assert!(sorted.iter().copied().eq(0..50));
+    }
+
+    // 不同的种子几乎不可能得到相同的排列
+    let mut a: MyVec<u32> = (0..50).collect();
+    let mut b = a.clone();
+    a.shuffle(&mut XorShift64::seed_from_u64(1));
+    b.shuffle(&mut XorShift64::seed_from_u64(2));
+    assert_ne!(a, b);
+}
+
+#[test]
+fn vec_shuffle_is_roughly_uniform() {
+    // 4个元素共有24种排列，每种排列期望出现`N / 24`次
+    const N: usize = 48_000;
+    let mut rng = XorShift64::seed_from_u64(0x9e37_79b9);
+    let mut counts: HashMap<[u8; 4], usize> = HashMap::new();
+    for _ in 0..N {
+        let mut vec = my_vec![0u8, 1, 2, 3];
+        vec.shuffle(&mut rng);
+        *counts.entry([vec[0], vec[1], vec[2], vec[3]]).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 24);
+
+    let expected = (N / 24) as f64;
+    let chi_square: f64 = counts
+        .values()
+        .map(|&c| (c as f64 - expected).powi(2) / expected)
+        .sum();
+    // 自由度为23，`p = 0.001`时的临界值约为49.7
+    assert!(chi_square < 49.7, "chi-square {chi_square} too large");
+}
+
+#[test]
+fn vec_partial_shuffle() {
+    let mut rng = XorShift64::seed_from_u64(3);
+    let mut vec: MyVec<u32> = (0..20).collect();
+    let (chosen, rest) = vec.partial_shuffle(&mut rng, 5);
+    assert_eq!((chosen.len(), rest.len()), (5, 15));
+    let mut all: Vec<u32> = chosen.iter().chain(rest.iter()).copied().collect();
+    all.sort();
+    assert!(all.into_iter().eq(0..20));
+
+    // 每个元素被选中的概率相同
+    let mut hits = [0usize; 10];
+    for _ in 0..10_000 {
+        let mut vec: MyVec<usize> = (0..10).collect();
+        let (chosen, _) = vec.partial_shuffle(&mut rng, 1);
+        hits[chosen[0]] += 1;
+    }
+    assert!(hits.iter().all(|&h| (800..1200).contains(&h)), "{hits:?}");
+
+    let (chosen, rest) = vec.partial_shuffle(&mut rng, 100);
+    assert_eq!((chosen.len(), rest.len()), (20, 0));
+}
+
+#[test]
+fn vec_shuffle_degenerate() {
+    let mut rng = XorShift64::seed_from_u64(0);
+    let mut empty: MyVec<i32> = MyVec::new();
+    empty.shuffle(&mut rng);
+    assert!(empty.is_empty());
+    assert_eq!(empty.partial_shuffle(&mut rng, 3).0.len(), 0);
+
+    let mut one = my_vec![7];
+    one.shuffle(&mut rng);
+    assert_eq!(one, [7]);
+
+    assert!((0..1000).all(|_| rng.next_below(3) < 3));
+    assert_eq!(rng.next_below(1), 0);
+    // 通过`&mut R`使用
+    let by_ref = &mut rng;
+    assert!(by_ref.next_below(u64::MAX) < u64::MAX);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn vec_shuffle_rand_compat() {
+    use rust_practice::collection::rng::RandCompat;
+
+    struct Counter(u64);
+
+    impl rand_core::RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dst);
+        }
+    }
+
+    let mut vec: MyVec<u32> = (0..10).collect();
+    vec.shuffle(&mut RandCompat(Counter(0)));
+    let mut sorted = vec.clone();
+    sorted.sort_stable();
+    assert!(sorted.iter().copied().eq(0..10));
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use std::cmp::Ordering;
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::rng::{RngCore, XorShift64};
+use rust_practice::collection::vec::MyVec;
+
+/// 随机、有序、逆序、大量重复以及“锯齿”形状的输入。
+fn patterns(rng: &mut XorShift64, len: usize) -> Vec<Vec<u64>> {
+    let random: Vec<u64> = (0..len).map(|_| rng.next_u64() % 1000).collect();
+    let sorted: Vec<u64> = (0..len as u64).collect();
+    let reversed: Vec<u64> = sorted.iter().rev().copied().collect();
+    let duplicates: Vec<u64> = (0..len).map(|_| rng.next_u64() % 3).collect();
+    let saw: Vec<u64> = (0..len as u64).map(|x| x % 17).collect();
+    vec![random, sorted, reversed, duplicates, saw]
+}
+
+#[test]
+fn vec_sort_stable_matches_std() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for len in [0, 1, 2, 3, 19, 20, 21, 40, 41, 100, 257, 1000] {
+        for data in patterns(&mut rng, len) {
+            let mut vec: MyVec<u64> = data.iter().copied().collect();
+            let mut expected = data.clone();
+            vec.sort_stable();
+            expected.sort();
+            assert_eq!(*vec, *expected);
+
+            let mut vec: MyVec<u64> = data.iter().copied().collect();
+            vec.sort_stable_by(|a, b| b.cmp(a));
+            expected.reverse();
+            assert_eq!(*vec, *expected);
+        }
+    }
+
+    let mut zst: MyVec<()> = std::iter::repeat_n((), 100).collect();
+    zst.sort_stable();
+    assert_eq!(zst.len(), 100);
+}
+
+#[test]
+fn vec_sort_stable_is_stable() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for len in [10, 50, 333, 2000] {
+        // 键的范围很小，每个键对应许多元素，第二个分量记录原来的位置
+        let data: Vec<(u64, usize)> = (0..len).map(|i| (rng.next_u64() % 8, i)).collect();
+        let mut vec: MyVec<(u64, usize)> = data.iter().copied().collect();
+        vec.sort_stable_by_key(|&(key, _)| key);
+
+        let mut expected = data.clone();
+        expected.sort_by_key(|&(key, _)| key);
+        assert_eq!(*vec, *expected);
+        assert!(
+            vec.windows(2)
+                .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1))
+        );
+    }
+}
+
+#[test]
+fn vec_sort_stable_panic_safety() {
+    let mut rng = common::rng(0xdead_beef_cafe_f00d);
+    for len in [5, 20, 64, 500] {
+        for panic_at in [0, 1, len / 2, len - 2] {
+            let log = DropLog::new();
+            let mut vec: MyVec<(u64, DropTracker)> = log
+                .track_n(len)
+                .map(|tracker| (rng.next_u64() % 50, tracker))
+                .collect();
+
+            let mut comparisons = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                vec.sort_stable_by(|a, b| {
+                    comparisons += 1;
+                    if comparisons > panic_at {
+                        panic!("comparator panicked");
+                    }
+                    a.0.cmp(&b.0)
+                });
+            }));
+            assert!(result.is_err());
+
+            // 没有元素被`drop`、丢失或者重复
+            assert_eq!(log.dropped(), 0);
+            let mut ids: Vec<usize> = vec.iter().map(|(_, x)| x.id()).collect();
+            ids.sort();
+            assert!(ids.into_iter().eq(0..len));
+
+            drop(vec);
+            assert_eq!(log.dropped(), len);
+        }
+    }
+}
+
+#[test]
+fn vec_sort_stable_inconsistent_comparator() {
+    // 不满足全序的比较函数不会导致未定义行为，元素仍然是原来的那些
+    let mut rng = common::rng(0x1234_5678_9abc_def0);
+    let mut vec: MyVec<u64> = (0..300).collect();
+    vec.sort_stable_by(|_, _| match rng.next_u64() % 3 {
+        0 => Ordering::Less,
+        1 => Ordering::Equal,
+        _ => Ordering::Greater,
+    });
+    let mut values = vec.into_iter().collect::<Vec<_>>();
+    values.sort();
+    assert!(values.into_iter().eq(0..300));
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::rng::{RngCore, XorShift64};
+use rust_practice::collection::vec::MyVec;
+
+/// 随机、有序、逆序、全部相等、少量不同的值、“管风琴”形状以及针对三数
+/// 取中构造的输入。
+fn patterns(rng: &mut XorShift64, len: usize) -> Vec<Vec<u64>> {
+    let n = len as u64;
+    vec![
+        (0..len).map(|_| rng.next_u64() % 1000).collect(),
+        (0..n).collect(),
+        (0..n).rev().collect(),
+        vec![7; len],
+        (0..len).map(|_| rng.next_u64() % 3).collect(),
+        (0..n).map(|x| x.min(n - x)).collect(),
+        median_of_three_killer(len),
+    ]
+}
+
+/// 每次分区时，首、中、尾的中位数都是第二小的元素，使分区极不均衡。
+fn median_of_three_killer(len: usize) -> Vec<u64> {
+    let mut v: Vec<u64> = (0..len as u64).collect();
+    for i in (0..len).rev() {
+        let mid = (len - i) / 2 + i;
+        if mid < len {
+            v.swap(i, mid);
+        }
+    }
+    v
+}
+
+#[test]
+fn vec_sort_unstable_matches_std() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for len in [0, 1, 2, 3, 20, 21, 50, 128, 1000, 5000] {
+        for data in patterns(&mut rng, len) {
+            let mut vec: MyVec<u64> = data.iter().copied().collect();
+            let mut expected = data.clone();
+            vec.sort_unstable_impl();
+            expected.sort_unstable();
+            assert_eq!(*vec, *expected);
+
+            let mut vec: MyVec<u64> = data.iter().copied().collect();
+            vec.sort_unstable_impl_by(|a, b| b.cmp(a));
+            expected.reverse();
+            assert_eq!(*vec, *expected);
+        }
+    }
+}
+
+#[test]
+fn vec_select_nth_unstable_matches_std() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    for len in [1, 2, 5, 21, 100, 1000] {
+        for data in patterns(&mut rng, len) {
+            let mut sorted = data.clone();
+            sorted.sort_unstable();
+            let indices = [
+                0,
+                len / 3,
+                len / 2,
+                len - 1,
+                (rng.next_u64() % len as u64) as usize,
+            ];
+            for index in indices {
+                let mut vec: MyVec<u64> = data.iter().copied().collect();
+                let (left, nth, right) = vec.select_nth_unstable_impl(index);
+                assert_eq!(*nth, sorted[index]);
+                assert_eq!(left.len(), index);
+                assert!(left.iter().all(|x| x <= nth));
+                assert!(right.iter().all(|x| x >= nth));
+
+                let mut expected = data.clone();
+                let (_, expected_nth, _) = expected.select_nth_unstable(index);
+                assert_eq!(nth, expected_nth);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "select_nth_unstable index 3 out of range for length 3")]
+fn vec_select_nth_unstable_out_of_bounds() {
+    let mut vec: MyVec<i32> = [1, 2, 3].into_iter().collect();
+    vec.select_nth_unstable_impl(3);
+}
+
+#[test]
+fn vec_sort_unstable_panic_safety() {
+    let mut rng = common::rng(0xdead_beef_cafe_f00d);
+    for len in [5, 20, 64, 500] {
+        for panic_at in [0, 1, len / 2, len - 2] {
+            for select in [false, true] {
+                let log = DropLog::new();
+                let mut vec: MyVec<(u64, DropTracker)> = log
+                    .track_n(len)
+                    .map(|tracker| (rng.next_u64() % 50, tracker))
+                    .collect();
+
+                let mut comparisons = 0;
+                let compare = |a: &(u64, DropTracker), b: &(u64, DropTracker)| {
+                    comparisons += 1;
+                    if comparisons > panic_at {
+                        panic!("comparator panicked");
+                    }
+                    a.0.cmp(&b.0)
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    if select {
+                        vec.select_nth_unstable_by(len / 2, compare);
+                    } else {
+                        vec.sort_unstable_impl_by(compare);
+                    }
+                }));
+                assert!(result.is_err());
+
+                // 没有元素被`drop`、丢失或者重复
+                assert_eq!(log.dropped(), 0);
+                let mut ids: Vec<usize> = vec.iter().map(|(_, x)| x.id()).collect();
+                ids.sort();
+                assert!(ids.into_iter().eq(0..len));
+
+                drop(vec);
+                assert_eq!(log.dropped(), len);
+            }
+        }
+    }
+}
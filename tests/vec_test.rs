@@ -1,5 +1,9 @@
+mod common;
+
 use std::iter;
+use std::panic::{self, AssertUnwindSafe};
 
+use common::{DropLog, DropTracker};
 use rust_practice::{collection::vec::MyVec, my_vec};
 
 #[test]
@@ -201,3 +205,183 @@ fn vec_remove_various_positions() {
     assert_eq!(v.remove(2), 50); // remove from end
     assert_eq!(v, [20, 40]);
 }
+
+#[test]
+fn vec_extend_from_slice_partial_capacity() {
+    let mut vec = MyVec::with_capacity(5);
+    vec.extend_from_slice(&[1, 2, 3]);
+    // 剩余容量为2，但需要4个位置
+    vec.extend_from_slice(&[4, 5, 6, 7]);
+    assert!(vec.capacity() >= 7);
+    assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn vec_dedup_and_truncate() {
+    let mut vec = my_vec![1, 1, 2, 3, 3, 3, 1, 4, 4];
+    vec.dedup();
+    assert_eq!(vec, [1, 2, 3, 1, 4]);
+    vec.truncate(10);
+    assert_eq!(vec.len(), 5);
+    vec.truncate(2);
+    assert_eq!(vec, [1, 2]);
+
+    let mut vec = my_vec!["a", "A", "b", "B", "c"];
+    vec.dedup_by_key(|s| s.to_ascii_lowercase());
+    assert_eq!(vec, ["a", "b", "c"]);
+
+    // `same_bucket`的第二个参数是保留下来的元素
+    let mut vec = my_vec![(1, 1), (1, 2), (2, 3), (2, 4)];
+    vec.dedup_by(|a, b| {
+        if a.0 == b.0 {
+            b.1 += a.1;
+            true
+        } else {
+            false
+        }
+    });
+    assert_eq!(vec, [(1, 3), (2, 7)]);
+}
+
+#[test]
+fn vec_dedup_drops_and_panic() {
+    let log = DropLog::new();
+    let ids = [0, 0, 1, 1, 1, 2];
+    let mut vec: MyVec<_> = ids.into_iter().map(|id| log.track(id)).collect();
+    vec.dedup();
+    // 被移除的重复元素各被`drop`一次
+    assert_eq!(log.order(), [0, 1, 1]);
+    assert!(vec.iter().map(DropTracker::id).eq([0, 1, 2]));
+
+    // `same_bucket`发生`panic`时，尚未检查的元素被保留，且没有重复
+    let mut calls = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        vec.dedup_by(|_, _| {
+            calls += 1;
+            assert!(calls < 2, "same_bucket panicked");
+            true
+        });
+    }));
+    assert!(result.is_err());
+    assert!(vec.iter().map(DropTracker::id).eq([0, 2]));
+    drop(vec);
+    assert_eq!(log.alive(), 0);
+}
+
+#[test]
+fn vec_retain() {
+    let mut vec: MyVec<_> = (0..10).collect();
+    vec.retain(|x| x % 3 != 0);
+    assert_eq!(vec, [1, 2, 4, 5, 7, 8]);
+    vec.retain_mut(|x| {
+        *x *= 10;
+        *x > 40
+    });
+    assert_eq!(vec, [50, 70, 80]);
+    vec.retain(|_| false);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn vec_shrink_to_fit() {
+    let mut vec = MyVec::with_capacity(10);
+    vec.extend([1, 2, 3]);
+    vec.shrink_to_fit();
+    assert_eq!(vec.capacity(), 3);
+    assert_eq!(vec, [1, 2, 3]);
+    vec.push(4);
+    assert_eq!(vec, [1, 2, 3, 4]);
+
+    vec.clear();
+    vec.shrink_to_fit();
+    assert_eq!(vec.capacity(), 0);
+    vec.push(5);
+    assert_eq!(vec, [5]);
+
+    let mut zst = my_vec![(), ()];
+    zst.shrink_to_fit();
+    assert_eq!(zst.capacity(), isize::MAX as usize);
+}
+
+/// 克隆时`panic`，用于确认元素只被移动。
+#[derive(Debug, PartialEq)]
+struct NoClone(i32);
+
+impl Clone for NoClone {
+    fn clone(&self) -> Self {
+        panic!("NoClone::clone called");
+    }
+}
+
+#[test]
+fn vec_add_concat() {
+    let a = my_vec![1, 2];
+    let b = my_vec![3];
+    let c = my_vec![4, 5];
+    assert_eq!(a + b + c, [1, 2, 3, 4, 5]);
+
+    let mut a = my_vec![1];
+    a += my_vec![2, 3];
+    a += &[4, 5][..];
+    assert_eq!(a + &[6][..], [1, 2, 3, 4, 5, 6]);
+
+    let empty = MyVec::<i32>::new;
+    assert_eq!(empty() + empty(), []);
+    assert_eq!(empty() + my_vec![1], [1]);
+    assert_eq!(my_vec![1] + empty(), [1]);
+    let mut a = empty();
+    a += &[][..];
+    assert!(a.is_empty());
+
+    let mut a = my_vec![1, 2];
+    let mut b = my_vec![3, 4];
+    b.reserve(10);
+    let cap = b.capacity();
+    a.append(&mut b);
+    assert_eq!(a, [1, 2, 3, 4]);
+    assert!(b.is_empty());
+    assert_eq!(b.capacity(), cap);
+}
+
+#[test]
+fn vec_add_moves_without_clone() {
+    let a: MyVec<NoClone> = (0..3).map(NoClone).collect();
+    let b: MyVec<NoClone> = (3..10).map(NoClone).collect();
+    let mut c = a + b;
+    c += (10..12).map(NoClone).collect::<MyVec<_>>();
+    assert!(c.iter().map(|x| x.0).eq(0..12));
+
+    let mut strings = my_vec![String::from("a")];
+    strings += my_vec![String::from("b"), String::from("c")];
+    assert_eq!(strings.concat(), "abc");
+}
+
+#[test]
+fn vec_partial_ord_with_slices() {
+    use std::cmp::Ordering::{self, *};
+
+    let cases: [(&[i32], &[i32], Ordering); 7] = [
+        (&[], &[], Equal),
+        (&[], &[1], Less),
+        (&[1, 2], &[1, 2, 3], Less),
+        (&[1, 2, 3], &[1, 2], Greater),
+        (&[1, 2, 3], &[1, 2, 4], Less),
+        (&[2], &[1, 9, 9], Greater),
+        (&[1, 2, 3], &[1, 2, 3], Equal),
+    ];
+    for (lhs, rhs, expected) in cases {
+        let vec: MyVec<i32> = lhs.iter().copied().collect();
+        assert_eq!(vec.partial_cmp(rhs), Some(expected), "{lhs:?} vs {rhs:?}");
+        assert_eq!(vec.partial_cmp(&rhs), Some(expected));
+        assert_eq!(rhs.partial_cmp(&vec), Some(expected.reverse()));
+        assert_eq!((*rhs).partial_cmp(&vec), Some(expected.reverse()));
+    }
+
+    let vec = my_vec![1, 2, 3];
+    assert!(vec < [1, 2, 4]);
+    assert_eq!(vec.partial_cmp(&&[1, 2]), Some(Greater));
+    assert!([1, 2] < vec);
+    assert_eq!(PartialOrd::partial_cmp(&&[1, 3], &vec), Some(Greater));
+    assert!([1, 2, 3] == vec && vec <= [1, 2, 3]);
+    assert_eq!(my_vec![f64::NAN].partial_cmp(&[1.0]), None);
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::{DropLog, DropTracker};
+use rust_practice::collection::rng::RngCore;
+use rust_practice::collection::vec::MyVec;
+
+#[test]
+fn vec_top_k_matches_sort_then_truncate() {
+    let mut rng = common::rng(0x9e37_79b9_7f4a_7c15);
+    for len in [0usize, 1, 2, 10, 21, 100, 1000] {
+        for max in [3, 1000] {
+            let data: Vec<u64> = (0..len).map(|_| rng.next_u64() % max).collect();
+            let mut expected = data.clone();
+            expected.sort_by(|a, b| b.cmp(a));
+
+            for k in [0, 1, 5, len / 2, len.saturating_sub(1), len, len + 10] {
+                let mut vec = MyVec::from(data.clone());
+                vec.top_k(k);
+                let k = k.min(len);
+                assert_eq!(vec[..k], expected[..k]);
+
+                // 其余的元素仍然都在
+                let mut all = Vec::from(vec);
+                all.sort_by(|a, b| b.cmp(a));
+                assert_eq!(all, expected);
+
+                let top = MyVec::from(data.clone()).into_top_k(k);
+                assert_eq!(*top, expected[..k]);
+            }
+        }
+    }
+}
+
+#[test]
+fn vec_top_k_by_custom_order() {
+    let mut rng = common::rng(0x2545_f491_4f6c_dd1d);
+    let data: Vec<i64> = (0..500)
+        .map(|_| (rng.next_u64() % 2001) as i64 - 1000)
+        .collect();
+
+    // 按照绝对值从小到大，“最大”的是绝对值最小的元素
+    let mut vec = MyVec::from(data.clone());
+    vec.top_k_by(20, |a, b| b.abs().cmp(&a.abs()));
+    let mut expected: Vec<i64> = data.iter().map(|x| x.abs()).collect();
+    expected.sort();
+    assert!(
+        vec[..20]
+            .iter()
+            .map(|x| x.abs())
+            .eq(expected[..20].iter().copied())
+    );
+}
+
+#[test]
+fn vec_into_top_k_drops_rest_once() {
+    let log = DropLog::new();
+    let vec: MyVec<DropTracker> = (0..100).map(|i| log.track(i * 37 % 100)).collect();
+
+    let top = vec.into_top_k_by(10, |a, b| a.id().cmp(&b.id()));
+    assert_eq!(log.dropped(), 90);
+    assert!(top.iter().map(|x| x.id()).eq((90..100).rev()));
+    drop(top);
+    assert_eq!(log.dropped(), 100);
+}
@@ -0,0 +1,149 @@
+//! 零大小类型以及极端容量下的边界情况。
+
+use std::cell::Cell;
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::panic::{self, AssertUnwindSafe};
+
+use rust_practice::collection::inplace_vec::InplaceVec;
+use rust_practice::collection::vec::MyVec;
+
+fn hash_of<T: Hash + ?Sized>(state: &RandomState, value: &T) -> u64 {
+    state.hash_one(value)
+}
+
+thread_local! {
+    static ALIGNED_DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// 对齐要求大于1且需要`drop`的ZST，用于检查不对齐的指针不会被用于`drop`。
+#[repr(align(8))]
+#[derive(Clone, Debug, PartialEq)]
+struct AlignedZst;
+
+impl Drop for AlignedZst {
+    fn drop(&mut self) {
+        ALIGNED_DROPS.set(ALIGNED_DROPS.get() + 1);
+    }
+}
+
+#[test]
+fn my_vec_of_zst() {
+    let mut vec: MyVec<()> = MyVec::new();
+    assert_eq!(vec.capacity(), isize::MAX as usize);
+    assert_eq!(vec.pop(), None);
+
+    for _ in 0..10 {
+        vec.push(());
+    }
+    vec.insert(0, ());
+    vec.insert(11, ());
+    assert_eq!(vec.remove(11), ());
+    assert_eq!(vec.remove(0), ());
+    assert_eq!(vec.len(), 10);
+
+    // 空的、中间的以及全部的范围
+    assert_eq!(vec.drain(3..3).count(), 0);
+    assert_eq!(vec.drain(2..5).count(), 3);
+    assert_eq!(vec.len(), 7);
+    let mut drain = vec.drain(..);
+    assert_eq!(
+        (drain.next(), drain.next_back(), drain.len()),
+        (Some(()), Some(()), 5)
+    );
+    drop(drain);
+    assert!(vec.is_empty());
+
+    vec.extend([(); 6]);
+    let mut iter = vec.clone().into_iter();
+    assert_eq!(iter.next_back(), Some(()));
+    assert_eq!(iter.next(), Some(()));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.rev().count(), 4);
+
+    let arrays: MyVec<[u8; 0]> = (0..4).map(|_| []).collect();
+    assert_eq!(arrays, [[]; 4]);
+    assert_eq!(arrays.clone().into_iter().count(), 4);
+}
+
+#[test]
+fn zst_with_drop_and_alignment() {
+    ALIGNED_DROPS.set(0);
+    let mut vec: MyVec<AlignedZst> = (0..8).map(|_| AlignedZst).collect();
+    let mut drain = vec.drain(1..6);
+    drop(drain.next());
+    drop(drain.next_back());
+    drop(drain);
+    assert_eq!((ALIGNED_DROPS.get(), vec.len()), (5, 3));
+
+    let mut iter = vec.into_iter();
+    drop(iter.next());
+    drop(iter);
+    assert_eq!(ALIGNED_DROPS.get(), 8);
+
+    let mut inplace: InplaceVec<4, AlignedZst> = InplaceVec::new();
+    inplace.extend([AlignedZst, AlignedZst, AlignedZst]);
+    drop(inplace.drain(1..));
+    drop(inplace.clone().into_iter());
+    drop(inplace);
+    assert_eq!(ALIGNED_DROPS.get(), 12);
+}
+
+#[test]
+fn inplace_vec_zero_capacity() {
+    let mut vec: InplaceVec<0, String> = InplaceVec::new();
+    assert_eq!((vec.len(), vec.capacity()), (0, 0));
+    assert_eq!(vec.pop(), None);
+    assert!(panic::catch_unwind(|| InplaceVec::<0, i32>::new().push(1)).is_err());
+    assert!(panic::catch_unwind(|| InplaceVec::<0, i32>::new().insert(0, 1)).is_err());
+
+    assert_eq!(vec.drain(..).count(), 0);
+    assert_eq!(vec.clone().into_iter().next_back(), None);
+    vec.extend(std::iter::empty::<String>());
+    assert_eq!(vec, InplaceVec::<0, String>::new());
+    assert_eq!(vec.as_slice(), [] as [String; 0]);
+
+    let state = RandomState::new();
+    assert_eq!(hash_of(&state, &vec), hash_of(&state, &[] as &[String]));
+}
+
+#[test]
+fn inplace_vec_of_zst_and_huge_capacity() {
+    let mut units: InplaceVec<5, ()> = InplaceVec::new();
+    units.extend([(); 4]);
+    units.insert(4, ());
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| units.push(()))).is_err());
+    assert_eq!(units.remove(0), ());
+    assert_eq!(units.drain(1..3).count(), 2);
+    let mut iter = units.clone().into_iter();
+    assert_eq!(
+        (iter.next(), iter.next_back(), iter.next()),
+        (Some(()), Some(()), None)
+    );
+
+    let mut big: Box<InplaceVec<100_000, u8>> = Box::new(InplaceVec::new());
+    big.extend((0..100_000).map(|i| i as u8));
+    assert_eq!(big.len(), big.capacity());
+    assert_eq!(big.remove(99_999), 159);
+    big.insert(0, 7);
+    assert_eq!(big.drain(1..99_999).count(), 99_998);
+    assert_eq!(big.as_slice(), [7, 158]);
+    assert_eq!(*big.clone(), *big);
+    assert_eq!(big.clone().into_iter().rev().collect::<Vec<_>>(), [158, 7]);
+}
+
+#[test]
+fn zst_equality_and_hash() {
+    let state = RandomState::new();
+    let three: MyVec<()> = [(); 3].into_iter().collect();
+    let five: MyVec<()> = [(); 5].into_iter().collect();
+    assert_ne!(three, five);
+    assert_eq!(three, [(); 3]);
+
+    // `Borrow<[T]>`要求哈希值与切片相同，长度不同的ZST容器哈希值也应该不同
+    assert_eq!(hash_of(&state, &three), hash_of(&state, &[(); 3][..]));
+    assert_ne!(hash_of(&state, &three), hash_of(&state, &five));
+
+    let inplace: InplaceVec<8, ()> = InplaceVec::from_array([(); 3]);
+    assert_eq!(inplace, [(); 3]);
+    assert_eq!(hash_of(&state, &inplace), hash_of(&state, &three));
+}